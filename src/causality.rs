@@ -0,0 +1,167 @@
+use std::cmp::Ordering;
+
+use crate::time_domain::{fft, interpolate_linear, linear_dc_value};
+use crate::{validate_port_indexes, Complex, FrequencyDeviation, Network, TouchstoneError};
+
+impl Network {
+    /// Return the causality error of S(j,k) at every frequency.
+    ///
+    /// A causal network has no impulse response before `t = 0`, which ties the real and
+    /// imaginary parts of its frequency response together through the Kramers-Kronig relations.
+    /// This test extrapolates the data to DC like
+    /// [`TimeDomainMode::LowPass`](crate::TimeDomainMode::LowPass), transforms it to the time
+    /// domain with the frequency grid's alias-free period, removes the response at negative
+    /// times, and reports the magnitude of what was removed: `|S(j,k) − S_causal(j,k)|`.
+    ///
+    /// Causal data give values near zero. Truncating the spectrum at the highest frequency adds
+    /// some error, mostly close to the band edge, so data should start near DC and extend past
+    /// the band of interest.
+    ///
+    /// Port indices `j` and `k` are 1-indexed. At least two frequency points are required, and
+    /// frequencies must not be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let errors = net.causality_error(2, 1)?;
+    /// let worst = errors.iter().map(|point| point.deviation).fold(0.0, f64::max);
+    ///
+    /// println!("worst S21 causality error: {worst}");
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "kramers_kronig")]
+    #[doc(alias = "hilbert")]
+    pub fn causality_error(
+        &self,
+        j: usize,
+        k: usize,
+    ) -> Result<Vec<FrequencyDeviation>, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+        self.validate_frequency_data()?;
+        validate_port_indexes(j, k, self.rank as usize)?;
+
+        let count = self.f.len();
+        if count < 2 {
+            return Err(TouchstoneError::InsufficientFrequencyPoints {
+                operation: "causality check".to_string(),
+                required: 2,
+                actual: count,
+            });
+        }
+        if self.f[0] < 0.0 {
+            return Err(TouchstoneError::InvalidFrequency {
+                point_index: 0,
+                frequency: self.f[0],
+            });
+        }
+
+        let values = self
+            .s
+            .iter()
+            .map(|data_line| {
+                let value = data_line.s_ri.get(j, k);
+                Complex {
+                    re: value.0,
+                    im: value.1,
+                }
+            })
+            .collect::<Vec<_>>();
+        let causal = causal_part(&self.f, &values);
+
+        Ok(self
+            .f
+            .iter()
+            .zip(values.iter().zip(&causal))
+            .map(|(frequency, (value, causal))| FrequencyDeviation {
+                frequency: *frequency,
+                deviation: (*value - *causal).magnitude(),
+            })
+            .collect())
+    }
+
+    /// Return whether every S-parameter has a causality error of at most `tolerance` at every
+    /// frequency.
+    ///
+    /// See [`causality_error`](Self::causality_error) for the test and its band-edge behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    ///
+    /// if !net.is_causal(0.05)? {
+    ///     println!("{} is not causal; refit it before saving", net.name);
+    /// }
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn is_causal(&self, tolerance: f64) -> Result<bool, TouchstoneError> {
+        let rank = self.rank as usize;
+        for j in 1..=rank {
+            for k in 1..=rank {
+                if self
+                    .causality_error(j, k)?
+                    .iter()
+                    .any(|point| point.deviation > tolerance)
+                {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Return the causal part of the response sampled by `values`, at the same frequencies.
+///
+/// `frequencies` must be sorted, non-negative, and contain at least two points.
+fn causal_part(frequencies: &[f64], values: &[Complex]) -> Vec<Complex> {
+    let mut grid_frequencies = frequencies.to_vec();
+    let mut grid_values = values.to_vec();
+    if grid_frequencies[0] == 0.0 {
+        grid_values[0].im = 0.0;
+    } else {
+        let dc = linear_dc_value(&grid_frequencies, &grid_values);
+        grid_frequencies.insert(0, 0.0);
+        grid_values.insert(0, Complex { re: dc, im: 0.0 });
+    }
+
+    // Harmonics 0..=half sample [0, f_max]; negative frequencies are the conjugate mirror.
+    let half = (grid_frequencies.len() - 1).next_power_of_two();
+    let size = 2 * half;
+    let df = grid_frequencies[grid_frequencies.len() - 1] / half as f64;
+    let grid = (0..=half)
+        .map(|index| index as f64 * df)
+        .collect::<Vec<_>>();
+    let mut buffer = vec![Complex::zero(); size];
+    for (index, frequency) in grid.iter().enumerate() {
+        let value = interpolate_linear(&grid_frequencies, &grid_values, *frequency);
+        buffer[index] = value;
+        if index > 0 && index < half {
+            buffer[size - index] = value.conj();
+        }
+    }
+
+    // The upper half of the impulse response holds negative times; the sample at half a period
+    // is shared between both halves.
+    fft(&mut buffer, true);
+    for (index, value) in buffer.iter_mut().enumerate() {
+        let scale = match index.cmp(&half) {
+            Ordering::Less => 1.0,
+            Ordering::Equal => 0.5,
+            Ordering::Greater => 0.0,
+        };
+        *value = *value * (scale / size as f64);
+    }
+    fft(&mut buffer, false);
+
+    buffer.truncate(half + 1);
+    frequencies
+        .iter()
+        .map(|frequency| interpolate_linear(&grid, &buffer, *frequency))
+        .collect()
+}
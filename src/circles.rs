@@ -0,0 +1,308 @@
+use crate::stability::{stability_factors, two_port_entries};
+use crate::{Complex, Network, TouchstoneError};
+
+/// Circle in the reflection-coefficient plane at a single frequency.
+///
+/// Circles that do not exist for the requested value, such as a gain above MAG or a noise figure
+/// below Fmin, have a `NaN` radius.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::Network;
+///
+/// let net = Network::new("files/fet.s2p").unwrap();
+/// let circles = net.available_gain_circles(10.0).unwrap();
+/// let circle = &circles[0];
+/// println!(
+///     "f = {} Hz, center = {:?}, radius = {}",
+///     circle.frequency, circle.center, circle.radius
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReflectionCircle {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Circle center as a reflection coefficient.
+    pub center: Complex,
+    /// Circle radius in reflection-coefficient units.
+    pub radius: f64,
+}
+
+/// Stability circle in the source or load reflection plane at a single frequency.
+///
+/// The circle is the boundary where the reflection looking into the opposite port has unit
+/// magnitude. When `S11·S22 − S12·S21` and the port's own S-parameter have equal magnitude the
+/// circle degenerates to a line and the center and radius are infinite.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StabilityCircle {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Circle center as a reflection coefficient.
+    pub center: Complex,
+    /// Circle radius in reflection-coefficient units.
+    pub radius: f64,
+    /// `true` when terminations inside the circle are stable, `false` when those outside are.
+    pub stable_inside: bool,
+}
+
+impl StabilityCircle {
+    /// Return whether termination `gamma` lies in the stable region of this circle.
+    #[must_use]
+    pub fn is_stable(&self, gamma: Complex) -> bool {
+        let inside = (gamma - self.center).magnitude() < self.radius;
+        inside == self.stable_inside
+    }
+}
+
+/// Two-port noise parameters at a single frequency.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::{Complex, NoiseParameters};
+///
+/// let noise = NoiseParameters {
+///     frequency: 2.0e9,
+///     min_noise_figure_db: 0.8,
+///     gamma_opt: Complex { re: 0.4, im: 0.3 },
+///     noise_resistance: 12.0,
+/// };
+///
+/// assert!(noise.noise_resistance > 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseParameters {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Minimum noise figure Fmin in dB.
+    pub min_noise_figure_db: f64,
+    /// Source reflection coefficient that achieves Fmin.
+    pub gamma_opt: Complex,
+    /// Equivalent noise resistance Rn in ohms.
+    pub noise_resistance: f64,
+}
+
+impl Network {
+    /// Return the input (source-plane) stability circle at every frequency.
+    ///
+    /// Source reflections on the circle make `|Γout| = 1`. The network must be a two-port; other
+    /// ranks return [`TouchstoneError::InvalidNetworkRank`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Complex, Network};
+    ///
+    /// let net = Network::new("files/fet.s2p")?;
+    /// let circles = net.input_stability_circles()?;
+    ///
+    /// // A 50 ohm source is stable wherever |S22| < 1.
+    /// assert!(circles[0].is_stable(Complex { re: 0.0, im: 0.0 }));
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "source_stability_circles")]
+    pub fn input_stability_circles(&self) -> Result<Vec<StabilityCircle>, TouchstoneError> {
+        self.stability_circles(Plane::Source)
+    }
+
+    /// Return the output (load-plane) stability circle at every frequency.
+    ///
+    /// Load reflections on the circle make `|Γin| = 1`. The network must be a two-port; other
+    /// ranks return [`TouchstoneError::InvalidNetworkRank`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/fet.s2p")?;
+    /// let circles = net.output_stability_circles()?;
+    ///
+    /// assert_eq!(circles.len(), net.f.len());
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "load_stability_circles")]
+    pub fn output_stability_circles(&self) -> Result<Vec<StabilityCircle>, TouchstoneError> {
+        self.stability_circles(Plane::Load)
+    }
+
+    /// Return source-plane circles of constant available gain `gain_db` at every frequency.
+    ///
+    /// Every source reflection on the circle gives available gain `G_A = gain_db`. Gains above MAG
+    /// have no circle and return a `NaN` radius. The network must be a two-port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/fet.s2p")?;
+    /// let circles = net.available_gain_circles(10.0)?;
+    ///
+    /// assert_eq!(circles.len(), net.f.len());
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn available_gain_circles(
+        &self,
+        gain_db: f64,
+    ) -> Result<Vec<ReflectionCircle>, TouchstoneError> {
+        self.gain_circles(gain_db, Plane::Source)
+    }
+
+    /// Return load-plane circles of constant operating (power) gain `gain_db` at every frequency.
+    ///
+    /// Every load reflection on the circle gives operating gain `G_P = gain_db`. Gains above MAG
+    /// have no circle and return a `NaN` radius. The network must be a two-port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/fet.s2p")?;
+    /// let circles = net.operating_gain_circles(10.0)?;
+    ///
+    /// assert_eq!(circles.len(), net.f.len());
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "power_gain_circles")]
+    pub fn operating_gain_circles(
+        &self,
+        gain_db: f64,
+    ) -> Result<Vec<ReflectionCircle>, TouchstoneError> {
+        self.gain_circles(gain_db, Plane::Load)
+    }
+
+    /// Return source-plane circles of constant noise figure `noise_figure_db`.
+    ///
+    /// One circle is returned per entry of `noise`, at that entry's frequency. The noise
+    /// resistance is normalized to the port 1 reference impedance, which must be real. Noise
+    /// figures below Fmin have no circle and return a `NaN` radius. The network must be a
+    /// two-port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Complex, Network, NoiseParameters};
+    ///
+    /// let net = Network::new("files/fet.s2p")?;
+    /// let noise = [NoiseParameters {
+    ///     frequency: 1.0e9,
+    ///     min_noise_figure_db: 0.5,
+    ///     gamma_opt: Complex { re: 0.3, im: 0.2 },
+    ///     noise_resistance: 5.8,
+    /// }];
+    /// let circles = net.noise_circles(&noise, 1.0)?;
+    ///
+    /// assert!(circles[0].radius > 0.0);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "noise_figure_circles")]
+    pub fn noise_circles(
+        &self,
+        noise: &[NoiseParameters],
+        noise_figure_db: f64,
+    ) -> Result<Vec<ReflectionCircle>, TouchstoneError> {
+        if self.rank != 2 {
+            return Err(TouchstoneError::InvalidNetworkRank {
+                rank: self.rank as usize,
+            });
+        }
+        let z0 = self.real_port_reference_impedance(1)?;
+        let noise_factor = db_to_power_ratio(noise_figure_db);
+
+        Ok(noise
+            .iter()
+            .map(|parameters| {
+                let gamma_opt = parameters.gamma_opt;
+                let n = (noise_factor - db_to_power_ratio(parameters.min_noise_figure_db))
+                    / (4.0 * parameters.noise_resistance / z0)
+                    * (Complex::one() + gamma_opt).magnitude().powi(2);
+
+                ReflectionCircle {
+                    frequency: parameters.frequency,
+                    center: gamma_opt / (n + 1.0),
+                    radius: (n * (n + 1.0 - gamma_opt.magnitude().powi(2))).sqrt() / (n + 1.0),
+                }
+            })
+            .collect())
+    }
+
+    fn stability_circles(&self, plane: Plane) -> Result<Vec<StabilityCircle>, TouchstoneError> {
+        Ok(self
+            .two_port_points()?
+            .iter()
+            .map(|point| {
+                let [s11, s12, s21, s22] = two_port_entries(&point.s);
+                let delta = s11 * s22 - s12 * s21;
+                let (own, opposite) = plane.entries(s11, s22);
+                let denominator = own.magnitude().powi(2) - delta.magnitude().powi(2);
+                let center = (own - delta * opposite.conj()).conj() / denominator;
+                let radius = (s12 * s21).magnitude() / denominator.abs();
+                // A matched termination is stable exactly when |S_oo| < 1.
+                let origin_inside = center.magnitude() < radius;
+
+                StabilityCircle {
+                    frequency: point.frequency,
+                    center,
+                    radius,
+                    stable_inside: origin_inside == (opposite.magnitude() < 1.0),
+                }
+            })
+            .collect())
+    }
+
+    fn gain_circles(
+        &self,
+        gain_db: f64,
+        plane: Plane,
+    ) -> Result<Vec<ReflectionCircle>, TouchstoneError> {
+        let gain = db_to_power_ratio(gain_db);
+
+        Ok(self
+            .two_port_points()?
+            .iter()
+            .map(|point| {
+                let [s11, s12, s21, s22] = two_port_entries(&point.s);
+                let k = stability_factors(point.frequency, &point.s).k;
+                let delta = s11 * s22 - s12 * s21;
+                let (own, opposite) = plane.entries(s11, s22);
+                let g = gain / s21.magnitude().powi(2);
+                let loop_gain = (s12 * s21).magnitude();
+                let denominator = 1.0 + g * (own.magnitude().powi(2) - delta.magnitude().powi(2));
+
+                ReflectionCircle {
+                    frequency: point.frequency,
+                    center: (own - delta * opposite.conj()).conj() * g / denominator,
+                    radius: (1.0 - 2.0 * k * loop_gain * g + (loop_gain * g).powi(2)).sqrt()
+                        / denominator.abs(),
+                }
+            })
+            .collect())
+    }
+}
+
+/// Termination plane a circle is drawn in.
+#[derive(Clone, Copy)]
+enum Plane {
+    /// Source reflection at port 1.
+    Source,
+    /// Load reflection at port 2.
+    Load,
+}
+
+impl Plane {
+    /// Return `(S_ii, S_oo)`: the reflection of the port terminated in this plane, then the
+    /// opposite port.
+    fn entries(self, s11: Complex, s22: Complex) -> (Complex, Complex) {
+        match self {
+            Self::Source => (s11, s22),
+            Self::Load => (s22, s11),
+        }
+    }
+}
+
+fn db_to_power_ratio(value_db: f64) -> f64 {
+    10.0_f64.powf(value_db / 10.0)
+}
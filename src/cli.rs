@@ -6,7 +6,7 @@ use std::process;
 use crate::file_operations;
 use crate::open;
 use crate::plot;
-use crate::Network;
+use crate::{FrequencyAlignment, Interpolation, Network, TouchstoneError};
 
 /// Command-line interface configuration and entry point.
 pub struct Config {}
@@ -33,8 +33,9 @@ impl Config {
                 process::exit(0);
             }
             "cascade" => {
-                // Parse arguments for --name or -n
+                // Parse arguments for --name or -n, and --align or -a
                 let mut output_name: Option<String> = None;
+                let mut alignment = FrequencyAlignment::Exact;
                 let mut file_paths = Vec::new();
 
                 let mut i = 2;
@@ -48,6 +49,18 @@ impl Config {
                                 return Err("missing argument for --name");
                             }
                         }
+                        "--align" | "-a" => {
+                            alignment = match args.get(i + 1).map(String::as_str) {
+                                Some("exact") => FrequencyAlignment::Exact,
+                                Some("intersection") => FrequencyAlignment::Intersection,
+                                Some("union") => FrequencyAlignment::Union(Interpolation::Linear),
+                                Some(_) => return Err(
+                                    "--align must be one of `exact`, `intersection`, or `union`",
+                                ),
+                                None => return Err("missing argument for --align"),
+                            };
+                            i += 2;
+                        }
                         _ => {
                             file_paths.push(args[i].clone());
                             i += 1;
@@ -73,7 +86,23 @@ impl Config {
                 let mut result = networks[0].clone();
                 // Cascade remaining networks
                 for network in networks.iter().skip(1) {
-                    result = result * network.clone();
+                    result = result
+                        .cascade_aligned(network, alignment)
+                        .map_err(|error| match error {
+                            TouchstoneError::FrequencyGridMismatch { .. }
+                            | TouchstoneError::InvalidFrequencyBand { .. } => {
+                                tracing::error!(
+                                    "Failed to align frequency grids with {}: {}",
+                                    network.name,
+                                    error
+                                );
+                                "Failed to align frequency grids; try `--align union` or `--align intersection`"
+                            }
+                            error => {
+                                tracing::error!("Failed to cascade {}: {}", network.name, error);
+                                "Failed to cascade networks"
+                            }
+                        })?;
                 }
 
                 // Determine output path
@@ -167,7 +196,7 @@ pub fn print_help() {
     println!("    {} touchstone <FILE_PATH>{}", GREEN, RESET);
     println!("    {} touchstone <DIRECTORY_PATH>{}", GREEN, RESET);
     println!(
-        "    {} touchstone cascade <FILE_1> <FILE_2> ... [--name <OUTPUT_NAME>]{}",
+        "    {} touchstone cascade <FILE_1> <FILE_2> ... [--name <OUTPUT_NAME>] [--align <MODE>]{}",
         GREEN, RESET
     );
     println!();
@@ -186,6 +215,10 @@ pub fn print_help() {
         "    {}  -h, --help{}{}       Print help information",
         GREEN, RESET, RESET
     );
+    println!(
+        "    {}  -a, --align{}{}      Cascade frequency grids: exact (default), intersection, union",
+        GREEN, RESET, RESET
+    );
     println!();
     println!("{}{}EXAMPLES:{}", BOLD, YELLOW, RESET);
     println!("    {} # Single file (Relative path){}", CYAN, RESET);
@@ -209,6 +242,15 @@ pub fn print_help() {
         GREEN, RESET
     );
     println!();
+    println!(
+        "    {} # Cascade networks measured on different frequency grids{}",
+        CYAN, RESET
+    );
+    println!(
+        "    {} touchstone cascade ntwk1.s2p ntwk2.s2p --align union{}",
+        GREEN, RESET
+    );
+    println!();
     println!("    {} # Bare filename{}", CYAN, RESET);
     println!("    {} touchstone measurement.s2p{}", GREEN, RESET);
     println!();
@@ -245,28 +287,18 @@ fn parse_plot_open_in_browser(file_path: String) -> Result<(), &'static str> {
         tracing::info!("Directory detected: {}", file_path);
         tracing::debug!("Plotting all valid network files in directory");
         // Iterate over files in directory
-        if let Ok(entries) = std::fs::read_dir(path) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-                if path.is_file() {
-                    if let Some(extension) = path.extension() {
-                        let ext_str = extension.to_string_lossy().to_lowercase();
-                        // Check for s2p, s1p, etc. (s*p)
-                        if ext_str.starts_with('s') && ext_str.ends_with('p') && ext_str.len() == 3
-                        {
-                            tracing::debug!("Found network file: {:?}", path);
-                            let network = Network::new(&path).map_err(|error| {
-                                tracing::error!(
-                                    "Failed to parse Touchstone file {}: {}",
-                                    path.display(),
-                                    error
-                                );
-                                "Failed to parse Touchstone file"
-                            })?;
-                            networks.push(network);
-                        }
-                    }
-                }
+        if let Ok(paths) = file_operations::touchstone_files_in_directory(path) {
+            for path in paths {
+                tracing::debug!("Found network file: {:?}", path);
+                let network = Network::new(&path).map_err(|error| {
+                    tracing::error!(
+                        "Failed to parse Touchstone file {}: {}",
+                        path.display(),
+                        error
+                    );
+                    "Failed to parse Touchstone file"
+                })?;
+                networks.push(network);
             }
         }
         if networks.is_empty() {
@@ -535,6 +567,74 @@ mod tests {
         assert_eq!(result.err(), Some("missing argument for --name"));
     }
 
+    #[test]
+    fn test_cascade_non_2port_reports_error() {
+        let test_dir = setup_test_dir("test_cascade_non_2port_reports_error");
+        let s1p = test_dir.join("ntwk1_port1.s1p");
+        Network::new("files/ntwk1.s2p")
+            .unwrap()
+            .subnetwork(&[1])
+            .unwrap()
+            .save(s1p.to_str().unwrap())
+            .unwrap();
+
+        let args = vec![
+            String::from("program_name"),
+            String::from("cascade"),
+            String::from("files/ntwk1.s2p"),
+            s1p.to_str().unwrap().to_string(),
+        ];
+        let result = Config::run(&args);
+        assert_eq!(result.err(), Some("Failed to cascade networks"));
+    }
+
+    #[test]
+    fn test_cascade_align_command() {
+        let test_dir = setup_test_dir("test_cascade_align_command");
+        let s2p1 = test_dir.join("ntwk1.s2p");
+        let coarse = test_dir.join("coarse.s2p");
+        fs::copy("files/ntwk1.s2p", &s2p1).unwrap();
+        Network::new("files/ntwk2.s2p")
+            .unwrap()
+            .resample(
+                [2.0e9, 4.25e9, 6.0e9],
+                Interpolation::Linear,
+                crate::Extrapolation::Error,
+            )
+            .unwrap()
+            .save(coarse.to_str().unwrap())
+            .unwrap();
+
+        let args = |align: &[&str]| {
+            let mut args = vec![
+                String::from("program_name"),
+                String::from("cascade"),
+                s2p1.to_str().unwrap().to_string(),
+                coarse.to_str().unwrap().to_string(),
+            ];
+            args.extend(align.iter().map(|arg| arg.to_string()));
+            args
+        };
+
+        assert_eq!(
+            Config::run(&args(&[])).err(),
+            Some("Failed to align frequency grids; try `--align union` or `--align intersection`")
+        );
+        assert_eq!(
+            Config::run(&args(&["--align", "nearest"])).err(),
+            Some("--align must be one of `exact`, `intersection`, or `union`")
+        );
+        assert_eq!(
+            Config::run(&args(&["--align"])).err(),
+            Some("missing argument for --align")
+        );
+
+        Config::run(&args(&["-a", "union"])).unwrap();
+        let cascaded =
+            Network::new(test_dir.join("cascaded_result.s2p").to_str().unwrap()).unwrap();
+        assert_eq!(cascaded.f.len(), 42);
+    }
+
     #[test]
     fn test_directory_plot() {
         let test_dir = setup_test_dir("test_directory_plot");
@@ -0,0 +1,120 @@
+use crate::network_builder::parsed_data_line_from_matrix;
+use crate::{matrix_add, Network, ParameterMatrix, SMatrix, TouchstoneError};
+
+impl Network {
+    /// Connect two 2-port networks in series at both ports by summing their Z-parameters.
+    ///
+    /// Both networks must be S-parameter 2-ports with the same common reference impedance,
+    /// frequency unit, and frequency grid, as for [`try_cascade()`](Network::try_cascade). Returns
+    /// [`TouchstoneError::SingularMatrix`] when a Z matrix does not exist, as for an ideal
+    /// through. Comments from both networks are merged the way [`cascade()`](Network::cascade)
+    /// merges them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net1 = Network::new("files/ntwk1.s2p")?;
+    /// let net2 = Network::new("files/ntwk2.s2p")?;
+    /// let series = net1.connect_series(&net2)?;
+    ///
+    /// let expected = net1.z_matrix_at(0)?.get(2, 1)? + net2.z_matrix_at(0)?.get(2, 1)?;
+    /// let error = series.z_matrix_at(0)?.get(2, 1)? - expected;
+    /// assert!(error.re.abs() < 1e-6 && error.im.abs() < 1e-6);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "series-series")]
+    pub fn connect_series(&self, other: &Network) -> Result<Network, TouchstoneError> {
+        self.connect_two_ports(
+            other,
+            "Series",
+            "Series-series connection",
+            SMatrix::to_z_matrix,
+            SMatrix::try_from_z_matrix,
+        )
+    }
+
+    /// Connect two 2-port networks in parallel at both ports by summing their Y-parameters.
+    ///
+    /// Validation and comment merging follow [`connect_series()`](Network::connect_series).
+    /// Returns [`TouchstoneError::SingularMatrix`] when a Y matrix does not exist.
+    #[doc(alias = "parallel-parallel")]
+    #[doc(alias = "shunt")]
+    pub fn connect_parallel(&self, other: &Network) -> Result<Network, TouchstoneError> {
+        self.connect_two_ports(
+            other,
+            "Parallel",
+            "Parallel-parallel connection",
+            SMatrix::to_y_matrix,
+            SMatrix::try_from_y_matrix,
+        )
+    }
+
+    /// Connect two 2-port networks in series at the input and in parallel at the output by
+    /// summing their H-parameters.
+    ///
+    /// Validation and comment merging follow [`connect_series()`](Network::connect_series).
+    /// Returns [`TouchstoneError::SingularMatrix`] when an H matrix does not exist.
+    #[doc(alias = "series-shunt")]
+    pub fn connect_series_parallel(&self, other: &Network) -> Result<Network, TouchstoneError> {
+        self.connect_two_ports(
+            other,
+            "SeriesParallel",
+            "Series-parallel connection",
+            SMatrix::to_h_matrix,
+            SMatrix::try_from_h_matrix,
+        )
+    }
+
+    /// Connect two 2-port networks in parallel at the input and in series at the output by
+    /// summing their G-parameters.
+    ///
+    /// Validation and comment merging follow [`connect_series()`](Network::connect_series).
+    /// Returns [`TouchstoneError::SingularMatrix`] when a G matrix does not exist.
+    #[doc(alias = "shunt-series")]
+    pub fn connect_parallel_series(&self, other: &Network) -> Result<Network, TouchstoneError> {
+        self.connect_two_ports(
+            other,
+            "ParallelSeries",
+            "Parallel-series connection",
+            SMatrix::to_g_matrix,
+            SMatrix::try_from_g_matrix,
+        )
+    }
+
+    /// Convert both networks with `to_parameters` at every point, sum the matrices, and convert
+    /// the sum back with `from_parameters`.
+    fn connect_two_ports(
+        &self,
+        other: &Network,
+        label: &str,
+        description: &str,
+        to_parameters: fn(&SMatrix, f64) -> Result<ParameterMatrix, TouchstoneError>,
+        from_parameters: fn(&ParameterMatrix, f64) -> Result<SMatrix, TouchstoneError>,
+    ) -> Result<Network, TouchstoneError> {
+        let z0 = self.validate_two_port_pair(other)?;
+
+        let mut s = Vec::with_capacity(self.s.len());
+        for (point_index, data_line) in self.s.iter().enumerate() {
+            let first = to_parameters(&self.s_matrix_at(point_index)?, z0)?;
+            let second = to_parameters(&other.s_matrix_at(point_index)?, z0)?;
+            let sum = ParameterMatrix {
+                rank: 2,
+                data: matrix_add(&first.data, &second.data),
+            };
+            s.push(parsed_data_line_from_matrix(
+                data_line.frequency,
+                &from_parameters(&sum, z0)?,
+            ));
+        }
+
+        Ok(self.combined_two_port(
+            other,
+            format!("{label}({},{})", self.name, other.name),
+            description,
+            z0,
+            s,
+        ))
+    }
+}
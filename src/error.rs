@@ -24,7 +24,7 @@ impl fmt::Display for TouchstoneErrorContext {
     }
 }
 
-/// Non-fatal condition reported while parsing Touchstone data.
+/// Non-fatal condition reported while parsing or processing Touchstone data.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum TouchstoneWarning {
@@ -51,6 +51,17 @@ pub enum TouchstoneWarning {
         /// Normalized keyword name.
         keyword: String,
     },
+    /// A non-passive frequency point was modified by [`crate::Network::enforce_passivity`].
+    PassivityEnforced {
+        /// Source name or path of the network.
+        source_name: String,
+        /// 0-based index of the modified frequency point in [`crate::Network::f`].
+        point_index: usize,
+        /// Largest singular value before enforcement, formatted with six decimals.
+        max_singular_value: String,
+        /// Value the singular values above it were clipped to, formatted with six decimals.
+        clipped_to: String,
+    },
 }
 
 impl fmt::Display for TouchstoneWarning {
@@ -78,6 +89,16 @@ impl fmt::Display for TouchstoneWarning {
                 f,
                 "{source_name}:{line_number}: unsupported keyword ignored: [{keyword}]"
             ),
+            Self::PassivityEnforced {
+                source_name,
+                point_index,
+                max_singular_value,
+                clipped_to,
+            } => write!(
+                f,
+                "{source_name}: passivity enforced at frequency point {point_index}; \
+                 largest singular value {max_singular_value} clipped to {clipped_to}"
+            ),
         }
     }
 }
@@ -182,7 +203,7 @@ pub enum TouchstoneError {
         /// Matrix format token from the keyword.
         format: String,
     },
-    /// `[Reference]` did not contain either one common value or one value per port.
+    /// Reference impedance metadata did not contain either one common value or one value per port.
     InvalidReferenceImpedanceCount {
         /// Number of ports in the network.
         ports: usize,
@@ -205,7 +226,32 @@ pub enum TouchstoneError {
         /// Number of ports in the network or matrix.
         rank: usize,
     },
-    /// A generated network rank was outside the supported range.
+    /// A requested 1-based port index was outside the network port range.
+    InvalidPort {
+        /// Requested port, using 1-based RF indexing.
+        port: usize,
+        /// Number of ports in the network.
+        rank: usize,
+    },
+    /// A port was listed more than once in a port selection.
+    DuplicatePort {
+        /// Repeated port, using 1-based RF indexing.
+        port: usize,
+    },
+    /// Port renumbering lists did not contain the same set of ports.
+    InvalidPortRenumbering {
+        /// Original ports, using 1-based RF indexing.
+        from: Vec<usize>,
+        /// New port numbers, using 1-based RF indexing.
+        to: Vec<usize>,
+    },
+    /// A port selection did not list any ports.
+    EmptyPortList,
+    /// The only port of a 1-port network was terminated, which would leave no ports.
+    CannotTerminateOnlyPort,
+    /// A network rank was outside the range supported by the operation, such as a generated
+    /// network with no ports, a cascade of networks that are not 2-ports, or a T-parameter
+    /// conversion of an odd-rank network.
     InvalidNetworkRank {
         /// Requested network rank.
         rank: usize,
@@ -307,6 +353,26 @@ pub enum TouchstoneError {
         /// Per-port reference impedance values in ohms.
         values: Vec<f64>,
     },
+    /// A scalar network parameter conversion was requested with complex reference impedances.
+    UnsupportedComplexReferenceImpedance,
+    /// Frequency-dependent reference impedances did not contain one entry per frequency point.
+    ReferenceImpedanceFrequencyCountMismatch {
+        /// Number of network frequency points.
+        expected: usize,
+        /// Number of reference impedance entries found.
+        actual: usize,
+    },
+    /// Per-port or frequency-dependent reference impedance metadata did not contain any values.
+    EmptyReferenceImpedance,
+    /// A complex reference impedance was not finite with a positive real part.
+    InvalidComplexReferenceImpedance {
+        /// Port using the invalid reference impedance, using 1-based RF indexing.
+        port: usize,
+        /// Real component in ohms.
+        re: f64,
+        /// Imaginary component in ohms.
+        im: f64,
+    },
     /// Network parameter conversion was requested for an unsupported matrix rank.
     UnsupportedConversionRank {
         /// Conversion that was requested.
@@ -358,6 +424,110 @@ pub enum TouchstoneError {
         /// Invalid reference impedance in ohms.
         z0: f64,
     },
+    /// An operation needs more frequency points than the network contains.
+    InsufficientFrequencyPoints {
+        /// Operation that was requested.
+        operation: String,
+        /// Minimum number of frequency points required.
+        required: usize,
+        /// Number of frequency points found.
+        actual: usize,
+    },
+    /// A time-domain transform or gate setting was out of range.
+    InvalidTimeDomainSetting {
+        /// Name of the invalid setting.
+        setting: String,
+        /// Invalid value.
+        value: f64,
+    },
+    /// A time-domain operation was requested in band-pass mode but requires low-pass mode.
+    UnsupportedTimeDomainMode {
+        /// Operation that was requested.
+        operation: String,
+    },
+    /// A limit line was malformed.
+    InvalidLimitLine {
+        /// Description of the problem.
+        reason: String,
+    },
+    /// A frequency band was reversed, not finite, or contained no frequency points.
+    InvalidFrequencyBand {
+        /// Band start frequency in Hz.
+        start: f64,
+        /// Band stop frequency in Hz.
+        stop: f64,
+    },
+    /// A network set was created without any networks.
+    EmptyNetworkSet,
+    /// A network could not be combined with the other networks in a set.
+    IncompatibleNetwork {
+        /// 0-based index of the network in the set.
+        index: usize,
+        /// Name of the network.
+        name: String,
+        /// Description of the incompatibility.
+        reason: String,
+    },
+    /// A percentile was not finite or was outside `0..=100`.
+    InvalidPercentile {
+        /// Requested percentile.
+        percentile: f64,
+    },
+    /// A swept parameter value was not finite or was listed more than once.
+    InvalidSweepValue {
+        /// Name of the swept parameter.
+        parameter: String,
+        /// Invalid parameter value.
+        value: f64,
+    },
+    /// Two networks had different frequency grids where identical grids were required.
+    FrequencyGridMismatch {
+        /// 0-based index of the first frequency point that differs.
+        point_index: usize,
+        /// Frequency of the first network in Hz, or `None` when its grid is shorter.
+        first: Option<f64>,
+        /// Frequency of the second network in Hz, or `None` when its grid is shorter.
+        second: Option<f64>,
+    },
+    /// Two networks to be connected used different frequency units.
+    FrequencyUnitMismatch {
+        /// Frequency unit of the first network.
+        first: String,
+        /// Frequency unit of the second network.
+        second: String,
+    },
+    /// Two networks to be connected used different reference impedances.
+    ReferenceImpedanceMismatch {
+        /// Reference impedance of the first network in ohms.
+        first: f64,
+        /// Reference impedance of the second network in ohms.
+        second: f64,
+    },
+    /// A requested port connection between two networks is not supported.
+    UnsupportedPortConnection {
+        /// Output port of the first network, using 1-based RF indexing.
+        from_port: usize,
+        /// Input port of the second network, using 1-based RF indexing.
+        to_port: usize,
+    },
+    /// A port extension delay or loss setting was not finite or was out of range.
+    InvalidPortExtension {
+        /// Name of the invalid setting.
+        setting: String,
+        /// Invalid value.
+        value: f64,
+    },
+    /// A requested swept parameter value was outside the swept range.
+    ParameterOutOfRange {
+        /// Name of the swept parameter.
+        parameter: String,
+        /// Requested parameter value.
+        value: f64,
+        /// Smallest swept value.
+        min: f64,
+        /// Largest swept value.
+        max: f64,
+    },
 }
 
 impl TouchstoneError {
@@ -494,8 +664,20 @@ impl fmt::Display for TouchstoneError {
                 f,
                 "S-parameter port index S{to_port}{from_port} out of range for {rank}-port network"
             ),
+            Self::InvalidPort { port, rank } => {
+                write!(f, "port {port} out of range for {rank}-port network")
+            }
+            Self::DuplicatePort { port } => write!(f, "port {port} is listed more than once"),
+            Self::InvalidPortRenumbering { from, to } => write!(
+                f,
+                "port renumbering must map a set of ports onto itself: {from:?} -> {to:?}"
+            ),
+            Self::EmptyPortList => write!(f, "port list must contain at least one port"),
+            Self::CannotTerminateOnlyPort => {
+                write!(f, "cannot terminate the only port of a 1-port network")
+            }
             Self::InvalidNetworkRank { rank } => {
-                write!(f, "invalid generated network rank: {rank}")
+                write!(f, "invalid network rank for this operation: {rank}")
             }
             Self::NetworkRankExtensionMismatch {
                 rank,
@@ -586,6 +768,21 @@ impl fmt::Display for TouchstoneError {
                 f,
                 "per-port reference impedances are not supported for this conversion: {values:?}"
             ),
+            Self::UnsupportedComplexReferenceImpedance => write!(
+                f,
+                "complex or frequency-dependent reference impedances are not supported for this conversion"
+            ),
+            Self::ReferenceImpedanceFrequencyCountMismatch { expected, actual } => write!(
+                f,
+                "frequency-dependent reference impedance has {actual} entries, expected {expected}"
+            ),
+            Self::EmptyReferenceImpedance => {
+                write!(f, "reference impedance metadata must contain at least one value")
+            }
+            Self::InvalidComplexReferenceImpedance { port, re, im } => write!(
+                f,
+                "reference impedance for port {port} must be finite with a positive real part: ({re}, {im})"
+            ),
             Self::UnsupportedConversionRank {
                 conversion,
                 rank,
@@ -635,6 +832,76 @@ impl fmt::Display for TouchstoneError {
             Self::InvalidReferenceImpedance { z0 } => {
                 write!(f, "reference impedance must be finite and positive: {z0}")
             }
+            Self::InsufficientFrequencyPoints {
+                operation,
+                required,
+                actual,
+            } => write!(
+                f,
+                "{operation} requires at least {required} frequency points, found {actual}"
+            ),
+            Self::InvalidTimeDomainSetting { setting, value } => {
+                write!(f, "invalid time-domain {setting}: {value}")
+            }
+            Self::UnsupportedTimeDomainMode { operation } => {
+                write!(f, "{operation} requires low-pass time-domain mode")
+            }
+            Self::InvalidLimitLine { reason } => write!(f, "invalid limit line: {reason}"),
+            Self::InvalidFrequencyBand { start, stop } => write!(
+                f,
+                "frequency band {start} Hz to {stop} Hz is invalid or contains no frequency points"
+            ),
+            Self::EmptyNetworkSet => write!(f, "network set contains no networks"),
+            Self::IncompatibleNetwork {
+                index,
+                name,
+                reason,
+            } => write!(f, "network {index} ({name}) is incompatible with the set: {reason}"),
+            Self::InvalidPercentile { percentile } => {
+                write!(f, "percentile must be between 0 and 100: {percentile}")
+            }
+            Self::InvalidSweepValue { parameter, value } => {
+                write!(f, "{parameter} value {value} is not finite or is duplicated")
+            }
+            Self::FrequencyGridMismatch {
+                point_index,
+                first,
+                second,
+            } => {
+                let describe = |frequency: &Option<f64>| {
+                    frequency.map_or_else(|| "no point".to_string(), |value| format!("{value} Hz"))
+                };
+                write!(
+                    f,
+                    "frequency grids differ at point {point_index}: {} and {}",
+                    describe(first),
+                    describe(second)
+                )
+            }
+            Self::FrequencyUnitMismatch { first, second } => write!(
+                f,
+                "networks use different frequency units: {first} and {second}"
+            ),
+            Self::ReferenceImpedanceMismatch { first, second } => write!(
+                f,
+                "networks use different reference impedances: {first} ohms and {second} ohms"
+            ),
+            Self::UnsupportedPortConnection { from_port, to_port } => write!(
+                f,
+                "connecting port {from_port} to port {to_port} is not supported; only port 2 to port 1 is"
+            ),
+            Self::InvalidPortExtension { setting, value } => {
+                write!(f, "invalid port extension {setting}: {value}")
+            }
+            Self::ParameterOutOfRange {
+                parameter,
+                value,
+                min,
+                max,
+            } => write!(
+                f,
+                "{parameter} {value} is outside the swept range {min} to {max}"
+            ),
         }
     }
 }
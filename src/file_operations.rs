@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
 pub struct FilePathConfig {
@@ -75,10 +75,41 @@ pub fn get_file_url(file_path: &String) -> String {
     path_to_url_manual(&path_str)
 }
 
+/// Return the Touchstone files directly inside `directory`, sorted by path.
+///
+/// Matches regular files with an `.sNp` extension for a single-digit port count, in any case.
+/// Subdirectories are not searched.
+pub fn touchstone_files_in_directory(directory: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_file() {
+            if let Some(extension) = path.extension() {
+                let ext_str = extension.to_string_lossy().to_lowercase();
+                // Check for s2p, s1p, etc. (s*p)
+                if ext_str.starts_with('s') && ext_str.ends_with('p') && ext_str.len() == 3 {
+                    paths.push(path);
+                }
+            }
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_touchstone_files_in_directory() {
+        let paths = touchstone_files_in_directory(Path::new("files/test_plot_dir")).unwrap();
+
+        assert_eq!(paths.len(), 3);
+        assert!(paths.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(paths.iter().all(|path| path.is_file()));
+    }
+
     #[test]
     fn test_get_file_path_config_absolute_path() {
         let config = get_file_path_config("/home/user/files/measured.s2p");
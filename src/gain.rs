@@ -0,0 +1,222 @@
+use crate::stability::{stability_factors, two_port_entries};
+use crate::{Complex, Network, TouchstoneError};
+
+/// Reflection-independent gain figures of a two-port at a single frequency.
+///
+/// Gains are linear power ratios; [`max_gain_db`](Self::max_gain_db) converts the headline figure
+/// to dB.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::Network;
+///
+/// let net = Network::new("files/fet.s2p").unwrap();
+/// let metrics = net.gain_metrics().unwrap();
+/// let point = &metrics[0];
+/// println!("f = {} Hz, max gain = {} dB", point.frequency, point.max_gain_db());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GainMetrics {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Maximum available gain, `MSG·(K − √(K² − 1))`.
+    ///
+    /// MAG only exists where a simultaneous conjugate match exists, which requires `K >= 1` and
+    /// `|Δ| < 1`. It is `None` at other frequencies; [`max_gain`](Self::max_gain) falls back to
+    /// the maximum stable gain there.
+    pub max_available_gain: Option<f64>,
+    /// Maximum stable gain, `|S21| / |S12|`. Infinite for unilateral networks.
+    pub max_stable_gain: f64,
+    /// Unilateral figure of merit `U`.
+    ///
+    /// The transducer gain is within `1/(1 + U)²` and `1/(1 − U)²` of the unilateral
+    /// transducer gain.
+    pub unilateral_figure_of_merit: f64,
+}
+
+impl GainMetrics {
+    /// Return MAG where it is defined and MSG elsewhere, as a linear power ratio.
+    #[must_use]
+    pub fn max_gain(&self) -> f64 {
+        self.max_available_gain.unwrap_or(self.max_stable_gain)
+    }
+
+    /// Return [`max_gain`](Self::max_gain) in dB.
+    #[must_use]
+    pub fn max_gain_db(&self) -> f64 {
+        10.0 * self.max_gain().log10()
+    }
+}
+
+/// Power gain of a two-port at a single frequency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyGain {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Linear power ratio.
+    pub gain: f64,
+}
+
+impl FrequencyGain {
+    /// Return the gain in dB, `10·log10(gain)`.
+    #[must_use]
+    pub fn gain_db(&self) -> f64 {
+        10.0 * self.gain.log10()
+    }
+}
+
+impl Network {
+    /// Return MAG, MSG, and the unilateral figure of merit at every frequency.
+    ///
+    /// The network must be a two-port; other ranks return
+    /// [`TouchstoneError::InvalidNetworkRank`]. Where `K < 1` (or `|Δ| >= 1`) the device cannot
+    /// be simultaneously conjugate matched, so [`GainMetrics::max_available_gain`] is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/fet.s2p")?;
+    /// for point in net.gain_metrics()? {
+    ///     match point.max_available_gain {
+    ///         Some(mag) => println!("{} Hz: MAG = {mag}", point.frequency),
+    ///         None => println!("{} Hz: MSG = {}", point.frequency, point.max_stable_gain),
+    ///     }
+    /// }
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "gain")]
+    #[doc(alias = "mag")]
+    #[doc(alias = "msg")]
+    pub fn gain_metrics(&self) -> Result<Vec<GainMetrics>, TouchstoneError> {
+        Ok(self
+            .two_port_points()?
+            .iter()
+            .map(|point| {
+                let [s11, s12, s21, s22] = two_port_entries(&point.s);
+                let factors = stability_factors(point.frequency, &point.s);
+                let max_stable_gain = s21.magnitude() / s12.magnitude();
+                let max_available_gain = (factors.k >= 1.0 && factors.delta < 1.0)
+                    .then(|| max_stable_gain * (factors.k - (factors.k.powi(2) - 1.0).sqrt()));
+                let s11_magnitude = s11.magnitude();
+                let s22_magnitude = s22.magnitude();
+
+                GainMetrics {
+                    frequency: point.frequency,
+                    max_available_gain,
+                    max_stable_gain,
+                    unilateral_figure_of_merit: s11_magnitude
+                        * s12.magnitude()
+                        * s21.magnitude()
+                        * s22_magnitude
+                        / ((1.0 - s11_magnitude.powi(2)) * (1.0 - s22_magnitude.powi(2))),
+                }
+            })
+            .collect())
+    }
+
+    /// Return the transducer gain `G_T` for source and load reflections `gamma_s` and `gamma_l`.
+    ///
+    /// `G_T` is the power delivered to the load divided by the power available from the source.
+    /// Reflections are referenced to the network's port reference impedances and applied at every
+    /// frequency. The network must be a two-port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Complex, Network};
+    ///
+    /// let net = Network::new("files/fet.s2p")?;
+    /// let matched = Complex { re: 0.0, im: 0.0 };
+    ///
+    /// // With matched terminations G_T = |S21|².
+    /// let gain = net.transducer_gain(matched, matched)?;
+    /// let s21 = net.try_s_ri_at(0, 2, 1)?;
+    /// assert!((gain[0].gain - (s21.re.powi(2) + s21.im.powi(2))).abs() < 1e-12);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn transducer_gain(
+        &self,
+        gamma_s: Complex,
+        gamma_l: Complex,
+    ) -> Result<Vec<FrequencyGain>, TouchstoneError> {
+        self.two_port_gain(|[s11, s12, s21, s22]| {
+            let one = Complex::one();
+            let denominator =
+                (one - s11 * gamma_s) * (one - s22 * gamma_l) - s12 * s21 * gamma_s * gamma_l;
+            s21.magnitude().powi(2)
+                * (1.0 - gamma_s.magnitude().powi(2))
+                * (1.0 - gamma_l.magnitude().powi(2))
+                / denominator.magnitude().powi(2)
+        })
+    }
+
+    /// Return the available gain `G_A` for source reflection `gamma_s`.
+    ///
+    /// `G_A` is the power available from the output divided by the power available from the
+    /// source, i.e. the transducer gain with the load conjugately matched to the output. The
+    /// network must be a two-port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Complex, Network};
+    ///
+    /// let net = Network::new("files/fet.s2p")?;
+    /// let gain = net.available_gain(Complex { re: 0.3, im: 0.1 })?;
+    ///
+    /// assert_eq!(gain.len(), net.f.len());
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn available_gain(&self, gamma_s: Complex) -> Result<Vec<FrequencyGain>, TouchstoneError> {
+        self.two_port_gain(|[s11, s12, s21, s22]| {
+            let input_loop = Complex::one() - s11 * gamma_s;
+            let gamma_out = s22 + s12 * s21 * gamma_s / input_loop;
+            s21.magnitude().powi(2) * (1.0 - gamma_s.magnitude().powi(2))
+                / (input_loop.magnitude().powi(2) * (1.0 - gamma_out.magnitude().powi(2)))
+        })
+    }
+
+    /// Return the operating (power) gain `G_P` for load reflection `gamma_l`.
+    ///
+    /// `G_P` is the power delivered to the load divided by the power delivered to the input, i.e.
+    /// the transducer gain with the source conjugately matched to the input. The network must be
+    /// a two-port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Complex, Network};
+    ///
+    /// let net = Network::new("files/fet.s2p")?;
+    /// let gain = net.operating_gain(Complex { re: 0.2, im: -0.2 })?;
+    ///
+    /// assert_eq!(gain.len(), net.f.len());
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "power_gain")]
+    pub fn operating_gain(&self, gamma_l: Complex) -> Result<Vec<FrequencyGain>, TouchstoneError> {
+        self.two_port_gain(|[s11, s12, s21, s22]| {
+            let output_loop = Complex::one() - s22 * gamma_l;
+            let gamma_in = s11 + s12 * s21 * gamma_l / output_loop;
+            s21.magnitude().powi(2) * (1.0 - gamma_l.magnitude().powi(2))
+                / (output_loop.magnitude().powi(2) * (1.0 - gamma_in.magnitude().powi(2)))
+        })
+    }
+
+    fn two_port_gain(
+        &self,
+        gain: impl Fn([Complex; 4]) -> f64,
+    ) -> Result<Vec<FrequencyGain>, TouchstoneError> {
+        Ok(self
+            .two_port_points()?
+            .iter()
+            .map(|point| FrequencyGain {
+                frequency: point.frequency,
+                gain: gain(two_port_entries(&point.s)),
+            })
+            .collect())
+    }
+}
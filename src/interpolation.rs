@@ -0,0 +1,320 @@
+use std::f64::consts::PI;
+
+use crate::data_line::{parsed_data_line_from_ri_matrix, ParsedDataLine};
+use crate::data_pairs::{RealImaginary, RealImaginaryMatrix};
+use crate::time_domain::linear_dc_value;
+use crate::{Complex, Interpolation};
+
+/// Sample `data`, which is sampled at the sorted positions `axis`, at each `(lookup,
+/// output_frequency)` pair. Every lookup must lie inside the range of `axis`, and each returned
+/// data line is tagged with its output frequency.
+///
+/// [`Interpolation::DelayCompensated`] treats `axis` as frequencies in Hz.
+pub(crate) fn sample_data_lines(
+    axis: &[f64],
+    data: &[ParsedDataLine],
+    samples: &[(f64, f64)],
+    interpolation: Interpolation,
+) -> Vec<ParsedDataLine> {
+    match interpolation {
+        Interpolation::DelayCompensated => sample_delay_compensated(axis, data, samples),
+        Interpolation::CubicSpline => {
+            let second_derivatives = spline_second_derivatives(axis, data);
+            samples
+                .iter()
+                .map(|(lookup, output_frequency)| {
+                    sample_between(axis, data, *lookup, *output_frequency, |lower, t| {
+                        map_entries(*output_frequency, data[lower].s_ri.size(), |row, col| {
+                            let moments = &second_derivatives[entry_index(data, row, col)];
+                            spline_value(
+                                axis[lower + 1] - axis[lower],
+                                t,
+                                entry(&data[lower], row, col),
+                                entry(&data[lower + 1], row, col),
+                                moments[lower],
+                                moments[lower + 1],
+                            )
+                        })
+                    })
+                })
+                .collect()
+        }
+        _ => samples
+            .iter()
+            .map(|(lookup, output_frequency)| {
+                sample_between(axis, data, *lookup, *output_frequency, |lower, t| {
+                    let (lower_line, upper_line) = (&data[lower], &data[lower + 1]);
+                    match interpolation {
+                        Interpolation::Nearest => {
+                            let index = if t <= 0.5 { lower } else { lower + 1 };
+                            parsed_data_line_with_frequency(&data[index], *output_frequency)
+                        }
+                        Interpolation::MagnitudePhase => {
+                            map_entries(*output_frequency, lower_line.s_ri.size(), |row, col| {
+                                interpolate_magnitude_phase(
+                                    entry(lower_line, row, col),
+                                    entry(upper_line, row, col),
+                                    t,
+                                )
+                            })
+                        }
+                        _ => map_entries(*output_frequency, lower_line.s_ri.size(), |row, col| {
+                            let (lower_value, upper_value) =
+                                (entry(lower_line, row, col), entry(upper_line, row, col));
+                            lower_value + (upper_value - lower_value) * t
+                        }),
+                    }
+                })
+            })
+            .collect(),
+    }
+}
+
+/// Extend the line through the two points of `data` nearest `lookup`, which lies outside the
+/// range of `axis`, in real/imaginary space.
+pub(crate) fn extrapolate_linear(
+    axis: &[f64],
+    data: &[ParsedDataLine],
+    lookup: f64,
+    output_frequency: f64,
+) -> ParsedDataLine {
+    if axis.len() < 2 {
+        return parsed_data_line_with_frequency(&data[0], output_frequency);
+    }
+
+    let lower = if lookup < axis[0] { 0 } else { axis.len() - 2 };
+    let t = (lookup - axis[lower]) / (axis[lower + 1] - axis[lower]);
+    map_entries(output_frequency, data[0].s_ri.size(), |row, col| {
+        let (lower_value, upper_value) = (
+            entry(&data[lower], row, col),
+            entry(&data[lower + 1], row, col),
+        );
+        lower_value + (upper_value - lower_value) * t
+    })
+}
+
+/// Return a data line with `diagonal` on the diagonal and zero elsewhere.
+pub(crate) fn filled_data_line(frequency: f64, rank: usize, diagonal: Complex) -> ParsedDataLine {
+    map_entries(frequency, rank, |row, col| {
+        if row == col {
+            diagonal
+        } else {
+            Complex::zero()
+        }
+    })
+}
+
+/// Interpolate linearly between a real DC value extrapolated from the two lowest points and the
+/// lowest point. `frequency` must lie in `0..frequencies[0]`, and at least two points are needed.
+pub(crate) fn extrapolate_to_dc(
+    frequencies: &[f64],
+    data: &[ParsedDataLine],
+    frequency: f64,
+) -> ParsedDataLine {
+    let t = frequency / frequencies[0];
+    map_entries(frequency, data[0].s_ri.size(), |row, col| {
+        let lowest = [entry(&data[0], row, col), entry(&data[1], row, col)];
+        let dc = Complex {
+            re: linear_dc_value(frequencies, &lowest),
+            im: 0.0,
+        };
+        dc + (lowest[0] - dc) * t
+    })
+}
+
+/// Return the data line at `lookup` when it is on the grid, otherwise call `interpolate` with the
+/// index of the point below `lookup` and the fractional position toward the point above it.
+fn sample_between(
+    axis: &[f64],
+    data: &[ParsedDataLine],
+    lookup: f64,
+    output_frequency: f64,
+    interpolate: impl FnOnce(usize, f64) -> ParsedDataLine,
+) -> ParsedDataLine {
+    if let Ok(index) = axis.binary_search_by(|value| value.partial_cmp(&lookup).unwrap()) {
+        return parsed_data_line_with_frequency(&data[index], output_frequency);
+    }
+
+    let upper_index = axis.partition_point(|value| *value < lookup);
+    let lower_index = upper_index - 1;
+    let t = (lookup - axis[lower_index]) / (axis[upper_index] - axis[lower_index]);
+    interpolate(lower_index, t)
+}
+
+/// Remove each entry's average delay, interpolate linearly in real/imaginary space, then restore
+/// the delay at the requested frequency.
+fn sample_delay_compensated(
+    frequencies: &[f64],
+    data: &[ParsedDataLine],
+    samples: &[(f64, f64)],
+) -> Vec<ParsedDataLine> {
+    let rank = data[0].s_ri.size();
+    let delays = (1..=rank)
+        .flat_map(|row| (1..=rank).map(move |col| (row, col)))
+        .map(|(row, col)| average_delay(frequencies, data, row, col))
+        .collect::<Vec<_>>();
+    let delay_at = |row: usize, col: usize| delays[(row - 1) * rank + col - 1];
+
+    let compensated = data
+        .iter()
+        .map(|data_line| {
+            map_entries(data_line.frequency, rank, |row, col| {
+                entry(data_line, row, col) * phase_ramp(data_line.frequency, -delay_at(row, col))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    sample_data_lines(frequencies, &compensated, samples, Interpolation::Linear)
+        .into_iter()
+        .zip(samples)
+        .map(|(data_line, (lookup, output_frequency))| {
+            map_entries(*output_frequency, rank, |row, col| {
+                entry(&data_line, row, col) * phase_ramp(*lookup, delay_at(row, col))
+            })
+        })
+        .collect()
+}
+
+/// Return the least-squares slope of the unwrapped phase of S(row,col) as a delay in seconds.
+fn average_delay(frequencies: &[f64], data: &[ParsedDataLine], row: usize, col: usize) -> f64 {
+    if frequencies.len() < 2 {
+        return 0.0;
+    }
+
+    let mut phases = Vec::with_capacity(data.len());
+    for data_line in data {
+        let value = entry(data_line, row, col);
+        let phase = value.im.atan2(value.re);
+        let unwrapped = match phases.last() {
+            Some(previous) => {
+                let step = phase - previous;
+                previous + step - 2.0 * PI * (step / (2.0 * PI)).round()
+            }
+            None => phase,
+        };
+        phases.push(unwrapped);
+    }
+
+    let count = frequencies.len() as f64;
+    let mean_frequency = frequencies.iter().sum::<f64>() / count;
+    let mean_phase = phases.iter().sum::<f64>() / count;
+    let (covariance, variance) = frequencies.iter().zip(&phases).fold(
+        (0.0, 0.0),
+        |(covariance, variance), (frequency, phase)| {
+            let df = frequency - mean_frequency;
+            (covariance + df * (phase - mean_phase), variance + df * df)
+        },
+    );
+    -covariance / variance / (2.0 * PI)
+}
+
+/// Return `e^(−j·2π·f·τ)`, the response of an ideal delay `τ` at frequency `f`.
+fn phase_ramp(frequency: f64, delay: f64) -> Complex {
+    let angle = -2.0 * PI * frequency * delay;
+    Complex {
+        re: angle.cos(),
+        im: angle.sin(),
+    }
+}
+
+/// Interpolate magnitude and phase linearly, taking the shorter way around for the phase.
+fn interpolate_magnitude_phase(lower: Complex, upper: Complex, t: f64) -> Complex {
+    let magnitude = lower.magnitude() + (upper.magnitude() - lower.magnitude()) * t;
+    let lower_phase = lower.im.atan2(lower.re);
+    let mut step = upper.im.atan2(upper.re) - lower_phase;
+    step -= 2.0 * PI * (step / (2.0 * PI)).round();
+    let phase = lower_phase + step * t;
+    Complex {
+        re: magnitude * phase.cos(),
+        im: magnitude * phase.sin(),
+    }
+}
+
+/// Return the second derivatives of the natural cubic spline through every entry, indexed by
+/// [`entry_index`].
+fn spline_second_derivatives(axis: &[f64], data: &[ParsedDataLine]) -> Vec<Vec<Complex>> {
+    let rank = data[0].s_ri.size();
+    let count = axis.len();
+
+    (1..=rank)
+        .flat_map(|row| (1..=rank).map(move |col| (row, col)))
+        .map(|(row, col)| {
+            let mut moments = vec![Complex::zero(); count];
+            if count < 3 {
+                return moments;
+            }
+
+            // Thomas algorithm on the interior points; the natural ends have zero curvature.
+            let mut diagonal = vec![0.0; count];
+            let mut rhs = vec![Complex::zero(); count];
+            for index in 1..count - 1 {
+                let h0 = axis[index] - axis[index - 1];
+                let h1 = axis[index + 1] - axis[index];
+                let slope0 =
+                    (entry(&data[index], row, col) - entry(&data[index - 1], row, col)) / h0;
+                let slope1 =
+                    (entry(&data[index + 1], row, col) - entry(&data[index], row, col)) / h1;
+                diagonal[index] = 2.0 * (h0 + h1);
+                rhs[index] = (slope1 - slope0) * 6.0;
+                if index > 1 {
+                    let factor = h0 / diagonal[index - 1];
+                    diagonal[index] -= factor * h0;
+                    rhs[index] = rhs[index] - rhs[index - 1] * factor;
+                }
+            }
+            for index in (1..count - 1).rev() {
+                let h1 = axis[index + 1] - axis[index];
+                moments[index] = (rhs[index] - moments[index + 1] * h1) / diagonal[index];
+            }
+            moments
+        })
+        .collect()
+}
+
+/// Evaluate a cubic spline segment of width `h` at fractional position `t`.
+fn spline_value(
+    h: f64,
+    t: f64,
+    lower: Complex,
+    upper: Complex,
+    m0: Complex,
+    m1: Complex,
+) -> Complex {
+    let a = 1.0 - t;
+    lower * a + upper * t + (m0 * (a.powi(3) - a) + m1 * (t.powi(3) - t)) * (h * h / 6.0)
+}
+
+fn entry_index(data: &[ParsedDataLine], row: usize, col: usize) -> usize {
+    (row - 1) * data[0].s_ri.size() + col - 1
+}
+
+fn entry(data_line: &ParsedDataLine, row: usize, col: usize) -> Complex {
+    let value = data_line.s_ri.get(row, col);
+    Complex {
+        re: value.0,
+        im: value.1,
+    }
+}
+
+/// Build a data line at `frequency` from one complex value per 1-based `(row, col)` entry.
+fn map_entries(
+    frequency: f64,
+    rank: usize,
+    mut value: impl FnMut(usize, usize) -> Complex,
+) -> ParsedDataLine {
+    let data = (1..=rank)
+        .map(|row| {
+            (1..=rank)
+                .map(|col| {
+                    let value = value(row, col);
+                    RealImaginary(value.re, value.im)
+                })
+                .collect()
+        })
+        .collect();
+    parsed_data_line_from_ri_matrix(frequency, RealImaginaryMatrix::from_vec(data))
+}
+
+fn parsed_data_line_with_frequency(data_line: &ParsedDataLine, frequency: f64) -> ParsedDataLine {
+    parsed_data_line_from_ri_matrix(frequency, data_line.s_ri.clone())
+}
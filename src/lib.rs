@@ -22,24 +22,62 @@
 //! ```
 
 use std::{io::Write, ops};
+mod causality;
+mod circles;
 /// Command-line interface helpers for the touchstone binary.
 pub mod cli;
+mod connections;
 mod data_line;
 mod data_pairs;
 mod error;
 mod file_extension;
 mod file_operations;
+mod gain;
+mod interpolation;
+mod measure;
 mod network_builder;
+mod network_set;
 mod open;
 mod option_line;
 mod parser;
+mod passivity;
+mod phase;
 mod plot;
+mod port_extension;
+mod ports;
+mod reflection;
+mod renormalization;
+mod spec;
+mod stability;
+mod sweep;
+mod symmetry;
+mod time_domain;
+mod transfer;
 mod utils;
 
+pub use circles::{NoiseParameters, ReflectionCircle, StabilityCircle};
 pub use error::{TouchstoneError, TouchstoneErrorContext, TouchstoneWarning};
+pub use gain::{FrequencyGain, GainMetrics};
+pub use measure::{BandMeasurement, FrequencyValue, Quantity};
 pub use network_builder::NetworkBuilder;
+pub use network_set::{FrequencyStatistics, NetworkSet};
+pub use passivity::FrequencySingularValue;
+pub use phase::{AngleUnit, FrequencyGroupDelay, FrequencyPhase};
+pub use plot::{generate_plot_from_networks, generate_plot_with_circles, SmithChartCircles};
+pub use port_extension::LossModel;
+pub use ports::Termination;
+pub use reflection::{FrequencyAdmittance, FrequencyImpedance, FrequencyLoss, FrequencyVswr};
+pub use spec::{LimitKind, LimitLine, LimitResult, Spec, SpecReport};
+pub use stability::StabilityFactors;
+pub use sweep::ParameterSweep;
+pub use symmetry::FrequencyDeviation;
+pub use time_domain::{
+    DcExtrapolation, GateMode, TimeDomainMode, TimeDomainOptions, TimeGate, TimePoint, Window,
+};
+pub use transfer::{PortGrouping, TMatrix};
 
 const PARAMETER_CONVERSION_TOLERANCE: f64 = 1.0e-12;
+const FREQUENCY_MATCH_TOLERANCE: f64 = 1.0e-12;
 
 /// Reference impedance metadata for a Touchstone network.
 #[derive(Debug, Clone, PartialEq)]
@@ -49,17 +87,46 @@ pub enum ReferenceImpedance {
     Common(f64),
     /// One real reference impedance in ohms per port, ordered by port number.
     PerPort(Vec<f64>),
+    /// One complex reference impedance in ohms per port, ordered by port number.
+    PerPortComplex(Vec<Complex>),
+    /// Complex per-port reference impedances in ohms at each frequency point.
+    ///
+    /// The outer vector is indexed by 0-based frequency point and each inner vector is ordered by
+    /// port number.
+    FrequencyDependent(Vec<Vec<Complex>>),
 }
 
 impl ReferenceImpedance {
-    pub(crate) fn scalar_compatibility_value(&self) -> f64 {
+    pub(crate) fn scalar_compatibility_value(&self) -> Result<f64, TouchstoneError> {
         match self {
-            Self::Common(z0) => *z0,
-            Self::PerPort(values) => values[0],
+            Self::Common(z0) => Some(*z0),
+            Self::PerPort(values) => values.first().copied(),
+            Self::PerPortComplex(values) => values.first().map(|value| value.re),
+            Self::FrequencyDependent(values) => values
+                .first()
+                .and_then(|point_values| point_values.first())
+                .map(|value| value.re),
         }
+        .ok_or(TouchstoneError::EmptyReferenceImpedance)
     }
 }
 
+/// Wave definition used to relate S-parameters to port voltages and currents.
+///
+/// Both definitions agree for real, positive reference impedances. They differ for complex
+/// reference impedances, which changes the result of Z/Y conversion and renormalization.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WaveDefinition {
+    /// Kurokawa power waves: `a = (V + Zr I) / (2 sqrt(Re Zr))`,
+    /// `b = (V - Zr* I) / (2 sqrt(Re Zr))`.
+    #[default]
+    Power,
+    /// Marks-Williams pseudo-waves: `a = sqrt(Re Zr) / (2 |Zr|) (V + Zr I)`,
+    /// `b = sqrt(Re Zr) / (2 |Zr|) (V - Zr I)`.
+    Pseudo,
+}
+
 /// Interpolation algorithm used when sampling S-parameter data between parsed frequencies.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 #[non_exhaustive]
@@ -71,6 +138,25 @@ pub enum Interpolation {
     /// Linearly interpolate each real and imaginary S-parameter component.
     #[default]
     Linear,
+    /// Fit a natural cubic spline through each real and imaginary S-parameter component.
+    ///
+    /// The spline passes through every parsed point with a continuous slope and curvature, which
+    /// follows smooth responses more closely than [`Linear`](Self::Linear) on coarse grids.
+    #[doc(alias = "spline")]
+    CubicSpline,
+    /// Linearly interpolate each S-parameter's magnitude and unwrapped phase.
+    ///
+    /// The phase follows the shorter way around the circle between neighboring points, so
+    /// values rotate instead of cutting across the circle and losing magnitude.
+    #[doc(alias = "polar")]
+    MagnitudePhase,
+    /// Remove each S-parameter's average delay, interpolate linearly, then restore the delay.
+    ///
+    /// The delay is the least-squares slope of the unwrapped phase over the whole frequency
+    /// range. Removing it leaves a slowly varying residual that interpolates accurately, which
+    /// suits long transmission paths sampled with many degrees of phase between points.
+    #[doc(alias = "linear phase")]
+    DelayCompensated,
 }
 
 /// Policy used when sampling outside the parsed frequency range.
@@ -82,6 +168,39 @@ pub enum Extrapolation {
     Error,
     /// Hold the nearest boundary S-parameter values at the requested frequency.
     Clamp,
+    /// Extend the straight line through the two nearest boundary points in real/imaginary space.
+    ///
+    /// Networks with a single frequency point hold that point, like [`Clamp`](Self::Clamp).
+    Linear,
+    /// Fill every S-parameter with zero, a matched network with no transmission.
+    Zero,
+    /// Fill the S-parameters with the identity matrix: every port fully reflects in phase and
+    /// no power couples between ports.
+    Identity,
+    /// Extrapolate each S-parameter to a real value at 0 Hz from its low-frequency trend, then
+    /// interpolate linearly between that DC point and the lowest parsed point.
+    ///
+    /// The DC value follows [`DcExtrapolation::Linear`]: magnitude and unwrapped phase are
+    /// extended from the two lowest points and the phase is rounded to 0° or 180°. Requested
+    /// frequencies below 0 Hz or above the parsed range return
+    /// [`TouchstoneError::FrequencyOutOfRange`], and at least two frequency points are
+    /// required.
+    #[doc(alias = "DC point")]
+    Dc,
+}
+
+/// How the frequency grids of two networks are matched before they are combined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FrequencyAlignment {
+    /// Require identical frequency grids.
+    #[default]
+    Exact,
+    /// Keep only the frequencies present in both grids.
+    Intersection,
+    /// Use every frequency of either grid inside the range both grids cover, interpolating each
+    /// network onto the merged grid.
+    Union(Interpolation),
 }
 
 /// A network parsed from a Touchstone (`.sNp`) file.
@@ -223,6 +342,13 @@ impl Complex {
     fn is_finite(self) -> bool {
         self.re.is_finite() && self.im.is_finite()
     }
+
+    fn conj(self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
 }
 
 impl ops::Add for Complex {
@@ -434,6 +560,82 @@ impl SMatrix {
         })
     }
 
+    /// Convert this two-port S-parameter matrix to hybrid (H) parameters.
+    ///
+    /// `z0` is the common real reference impedance in ohms. `h11` is in ohms, `h22` is in
+    /// siemens, and `h12` and `h21` are dimensionless. Returns
+    /// [`TouchstoneError::UnsupportedConversionRank`] for other ranks and
+    /// [`TouchstoneError::SingularMatrix`] when the H-parameters do not exist, as for a shorted
+    /// output port.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Complex, SMatrix};
+    ///
+    /// // An ideal through has h11 = 0, h12 = 1, h21 = -1, and h22 = 0.
+    /// let zero = Complex { re: 0.0, im: 0.0 };
+    /// let one = Complex { re: 1.0, im: 0.0 };
+    /// let through = SMatrix {
+    ///     rank: 2,
+    ///     data: vec![vec![zero, one], vec![one, zero]],
+    /// };
+    ///
+    /// let h = through.to_h_matrix(50.0)?;
+    /// assert_eq!(h.get(2, 1)?.re, -1.0);
+    /// assert_eq!(SMatrix::try_from_h_matrix(&h, 50.0)?, through);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "hybrid parameters")]
+    #[doc(alias = "h-parameters")]
+    pub fn to_h_matrix(&self, z0: f64) -> Result<ParameterMatrix, TouchstoneError> {
+        let [s11, s12, s21, s22] = self.two_port_values("S to H", z0)?;
+        let [h11, h12, h21, h22] = two_port_s_to_h("S to H denominator", [s11, s12, s21, s22], z0)?;
+        Ok(ParameterMatrix {
+            rank: 2,
+            data: vec![vec![h11, h12], vec![h21, h22]],
+        })
+    }
+
+    /// Convert this two-port S-parameter matrix to inverse hybrid (G) parameters.
+    ///
+    /// `z0` is the common real reference impedance in ohms. `g11` is in siemens, `g22` is in
+    /// ohms, and `g12` and `g21` are dimensionless. Returns
+    /// [`TouchstoneError::UnsupportedConversionRank`] for other ranks and
+    /// [`TouchstoneError::SingularMatrix`] when the G-parameters do not exist, as for a shorted
+    /// input port.
+    #[doc(alias = "inverse hybrid parameters")]
+    #[doc(alias = "g-parameters")]
+    pub fn to_g_matrix(&self, z0: f64) -> Result<ParameterMatrix, TouchstoneError> {
+        let [s11, s12, s21, s22] = self.two_port_values("S to G", z0)?;
+        // G-parameters are the H-parameters of the network with its ports swapped.
+        let [h11, h12, h21, h22] = two_port_s_to_h("S to G denominator", [s22, s21, s12, s11], z0)?;
+        Ok(ParameterMatrix {
+            rank: 2,
+            data: vec![vec![h22, h21], vec![h12, h11]],
+        })
+    }
+
+    fn two_port_values(&self, conversion: &str, z0: f64) -> Result<[Complex; 4], TouchstoneError> {
+        validate_reference_impedance(z0)?;
+        validate_matrix_data("S", self.rank, &self.data)?;
+
+        if self.rank != 2 {
+            return Err(TouchstoneError::UnsupportedConversionRank {
+                conversion: conversion.to_string(),
+                rank: self.rank,
+                expected_rank: 2,
+            });
+        }
+
+        Ok([
+            self.data[0][0],
+            self.data[0][1],
+            self.data[1][0],
+            self.data[1][1],
+        ])
+    }
+
     /// Convert an admittance-parameter matrix to an S-parameter matrix.
     ///
     /// `z0` is the common real reference impedance in ohms. Per-port reference impedances are not
@@ -460,12 +662,27 @@ impl SMatrix {
     pub fn try_from_abcd(matrix: &ABCDMatrix, z0: f64) -> Result<Self, TouchstoneError> {
         matrix.to_s_matrix(z0)
     }
+
+    /// Convert two-port hybrid (H) parameters to an S-parameter matrix.
+    ///
+    /// `z0` is the common real reference impedance in ohms.
+    pub fn try_from_h_matrix(matrix: &ParameterMatrix, z0: f64) -> Result<Self, TouchstoneError> {
+        matrix.to_s_matrix_from_h(z0)
+    }
+
+    /// Convert two-port inverse hybrid (G) parameters to an S-parameter matrix.
+    ///
+    /// `z0` is the common real reference impedance in ohms.
+    pub fn try_from_g_matrix(matrix: &ParameterMatrix, z0: f64) -> Result<Self, TouchstoneError> {
+        matrix.to_s_matrix_from_g(z0)
+    }
 }
 
-/// Stable admittance- or impedance-parameter matrix for one frequency point.
+/// Stable admittance-, impedance-, or hybrid-parameter matrix for one frequency point.
 ///
 /// `data` is arranged as rows of destination/output ports and columns of source/input ports. Values
-/// are in siemens for admittance matrices and ohms for impedance matrices.
+/// are in siemens for admittance matrices and ohms for impedance matrices. Two-port hybrid (H) and
+/// inverse hybrid (G) matrices mix ohms, siemens, and dimensionless entries.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ParameterMatrix {
     /// Number of ports in this square parameter matrix.
@@ -541,6 +758,51 @@ impl ParameterMatrix {
             data,
         })
     }
+
+    /// Convert this two-port hybrid (H) parameter matrix to an S-parameter matrix.
+    ///
+    /// `z0` is the common real reference impedance in ohms.
+    pub fn to_s_matrix_from_h(&self, z0: f64) -> Result<SMatrix, TouchstoneError> {
+        let [h11, h12, h21, h22] = self.two_port_values("H", z0)?;
+        let [s11, s12, s21, s22] = two_port_h_to_s("H to S denominator", [h11, h12, h21, h22], z0)?;
+        Ok(SMatrix {
+            rank: 2,
+            data: vec![vec![s11, s12], vec![s21, s22]],
+        })
+    }
+
+    /// Convert this two-port inverse hybrid (G) parameter matrix to an S-parameter matrix.
+    ///
+    /// `z0` is the common real reference impedance in ohms.
+    pub fn to_s_matrix_from_g(&self, z0: f64) -> Result<SMatrix, TouchstoneError> {
+        let [g11, g12, g21, g22] = self.two_port_values("G", z0)?;
+        // Treat G as the H-parameters of the port-swapped network, then swap the ports back.
+        let [s11, s12, s21, s22] = two_port_h_to_s("G to S denominator", [g22, g21, g12, g11], z0)?;
+        Ok(SMatrix {
+            rank: 2,
+            data: vec![vec![s22, s21], vec![s12, s11]],
+        })
+    }
+
+    fn two_port_values(&self, matrix: &str, z0: f64) -> Result<[Complex; 4], TouchstoneError> {
+        validate_reference_impedance(z0)?;
+        validate_matrix_data(matrix, self.rank, &self.data)?;
+
+        if self.rank != 2 {
+            return Err(TouchstoneError::UnsupportedConversionRank {
+                conversion: format!("{matrix} to S"),
+                rank: self.rank,
+                expected_rank: 2,
+            });
+        }
+
+        Ok([
+            self.data[0][0],
+            self.data[0][1],
+            self.data[1][0],
+            self.data[1][1],
+        ])
+    }
 }
 
 /// Stable two-port ABCD transmission-parameter matrix.
@@ -717,7 +979,7 @@ impl Network {
     pub fn reference_impedance(&self) -> ReferenceImpedance {
         match &self.reference_impedance {
             ReferenceImpedance::Common(_) => ReferenceImpedance::Common(self.z0),
-            ReferenceImpedance::PerPort(values) => ReferenceImpedance::PerPort(values.clone()),
+            reference_impedance => reference_impedance.clone(),
         }
     }
 
@@ -910,6 +1172,24 @@ impl Network {
         self.s_matrix_at(point_index)?.to_abcd(z0)
     }
 
+    /// Return the hybrid (H) parameter matrix at one frequency point.
+    ///
+    /// `point_index` is 0-based. The network must be a two-port S-parameter network with one common
+    /// scalar reference impedance.
+    pub fn h_matrix_at(&self, point_index: usize) -> Result<ParameterMatrix, TouchstoneError> {
+        let z0 = self.scalar_reference_impedance_for_conversions()?;
+        self.s_matrix_at(point_index)?.to_h_matrix(z0)
+    }
+
+    /// Return the inverse hybrid (G) parameter matrix at one frequency point.
+    ///
+    /// `point_index` is 0-based. The network must be a two-port S-parameter network with one common
+    /// scalar reference impedance.
+    pub fn g_matrix_at(&self, point_index: usize) -> Result<ParameterMatrix, TouchstoneError> {
+        let z0 = self.scalar_reference_impedance_for_conversions()?;
+        self.s_matrix_at(point_index)?.to_g_matrix(z0)
+    }
+
     /// Return all stable data for one frequency point.
     ///
     /// `point_index` is 0-based.
@@ -943,13 +1223,17 @@ impl Network {
             ReferenceImpedance::PerPort(values) => {
                 Err(TouchstoneError::UnsupportedReferenceImpedance { values })
             }
+            ReferenceImpedance::PerPortComplex(_) | ReferenceImpedance::FrequencyDependent(_) => {
+                Err(TouchstoneError::UnsupportedComplexReferenceImpedance)
+            }
         }
     }
 
     /// Sample the full S-parameter matrix at one frequency in Hz.
     ///
-    /// Interpolation is performed in real/imaginary space. The returned point uses the requested
-    /// frequency, even when [`Extrapolation::Clamp`] supplies boundary S-parameter values.
+    /// Values between parsed frequencies are computed with `interpolation`; see [`Interpolation`]
+    /// for the available modes. The returned point uses the requested frequency, even when
+    /// [`Extrapolation::Clamp`] supplies boundary S-parameter values.
     ///
     /// # Examples
     ///
@@ -977,17 +1261,19 @@ impl Network {
         validate_sample_frequency(0, frequency_hz)?;
         self.validate_frequency_data()?;
 
-        let data_line =
-            self.sample_data_line_at_validated(frequency_hz, interpolation, extrapolation)?;
-        Ok(network_point_from_data_line(&data_line))
+        let data_lines =
+            self.sample_data_lines_at_validated(&[frequency_hz], interpolation, extrapolation)?;
+        Ok(network_point_from_data_line(&data_lines[0]))
     }
 
     /// Resample the network onto a new strictly increasing frequency grid in Hz.
     ///
     /// The returned network preserves rank, name, comments, option-line metadata, reference
     /// impedance metadata, warnings, and the original data format intent. New S-parameters are
-    /// interpolated in real/imaginary space, then derived magnitude/angle and dB/angle matrices are
-    /// rebuilt from those interpolated values.
+    /// computed with `interpolation` exactly as [`sample_at`](Self::sample_at) computes them,
+    /// then derived magnitude/angle and dB/angle matrices are rebuilt from those interpolated
+    /// values. Frequency-dependent reference impedances are interpolated onto the new grid and
+    /// held at the boundary values outside the parsed range.
     ///
     /// # Examples
     ///
@@ -1023,12 +1309,7 @@ impl Network {
         validate_frequency_slice(&frequencies)?;
         self.validate_frequency_data()?;
 
-        let s = frequencies
-            .iter()
-            .map(|frequency| {
-                self.sample_data_line_at_validated(*frequency, interpolation, extrapolation)
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+        let s = self.sample_data_lines_at_validated(&frequencies, interpolation, extrapolation)?;
 
         Ok(Network {
             name: self.name.clone(),
@@ -1038,7 +1319,7 @@ impl Network {
             format: self.format.clone(),
             resistance_string: self.resistance_string.clone(),
             z0: self.z0,
-            reference_impedance: self.reference_impedance(),
+            reference_impedance: self.resampled_reference_impedance(&frequencies, interpolation)?,
             comments: self.comments.clone(),
             comments_after_option_line: self.comments_after_option_line.clone(),
             warnings: self.warnings.clone(),
@@ -1047,6 +1328,108 @@ impl Network {
         })
     }
 
+    /// Return copies of `self` and `other` on a shared frequency grid.
+    ///
+    /// [`FrequencyAlignment::Exact`] returns [`TouchstoneError::FrequencyGridMismatch`] unless
+    /// both grids are identical. [`FrequencyAlignment::Intersection`] keeps the frequencies found
+    /// in both grids, and [`FrequencyAlignment::Union`] resamples both networks onto every
+    /// frequency of either grid inside the range both cover. Frequencies that differ only by
+    /// floating-point rounding, such as `1.1 GHz` and `1100 MHz`, are treated as equal. When
+    /// no frequency is shared, [`TouchstoneError::InvalidFrequencyBand`] reports the overlapping
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{FrequencyAlignment, Interpolation, Network};
+    ///
+    /// let coarse =
+    ///     Network::from_str("coarse.s1p", "# GHz S RI R 50\n1.0 0.1 0.0\n3.0 0.3 0.0\n")?;
+    /// let fine = Network::from_str(
+    ///     "fine.s1p",
+    ///     "# GHz S RI R 50\n2.0 0.5 0.0\n2.5 0.5 0.0\n4.0 0.5 0.0\n",
+    /// )?;
+    /// let (coarse, fine) =
+    ///     coarse.align_frequencies(&fine, FrequencyAlignment::Union(Interpolation::Linear))?;
+    ///
+    /// assert_eq!(coarse.f, vec![2.0e9, 2.5e9, 3.0e9]);
+    /// assert_eq!(fine.f, coarse.f);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "intersection")]
+    #[doc(alias = "union")]
+    pub fn align_frequencies(
+        &self,
+        other: &Network,
+        alignment: FrequencyAlignment,
+    ) -> Result<(Network, Network), TouchstoneError> {
+        self.validate_frequency_data()?;
+        other.validate_frequency_data()?;
+
+        let (frequencies, interpolation) = match alignment {
+            FrequencyAlignment::Exact => {
+                validate_matching_frequencies(&self.f, &other.f)?;
+                return Ok((self.clone(), other.clone()));
+            }
+            FrequencyAlignment::Intersection => (
+                self.f
+                    .iter()
+                    .copied()
+                    .filter(|frequency| {
+                        other
+                            .f
+                            .iter()
+                            .any(|other_frequency| frequencies_match(*frequency, *other_frequency))
+                    })
+                    .collect::<Vec<_>>(),
+                Interpolation::Nearest,
+            ),
+            FrequencyAlignment::Union(interpolation) => {
+                let start = self.f[0].max(other.f[0]);
+                let stop = self.f[self.f.len() - 1].min(other.f[other.f.len() - 1]);
+                let mut frequencies = self
+                    .f
+                    .iter()
+                    .chain(&other.f)
+                    .copied()
+                    .filter(|frequency| start <= *frequency && *frequency <= stop)
+                    .collect::<Vec<_>>();
+                frequencies.sort_by(f64::total_cmp);
+                frequencies.dedup_by(|next, previous| frequencies_match(*next, *previous));
+                (frequencies, interpolation)
+            }
+        };
+
+        if frequencies.is_empty() {
+            return Err(TouchstoneError::InvalidFrequencyBand {
+                start: self.f[0].max(other.f[0]),
+                stop: self.f[self.f.len() - 1].min(other.f[other.f.len() - 1]),
+            });
+        }
+
+        // Snap to each network's own grid so rounding differences never fall outside its range.
+        let aligned = |network: &Network| {
+            let grid = frequencies
+                .iter()
+                .map(|frequency| {
+                    network
+                        .f
+                        .iter()
+                        .copied()
+                        .find(|own| frequencies_match(*own, *frequency))
+                        .unwrap_or(*frequency)
+                })
+                .collect::<Vec<_>>();
+            let mut resampled = network.resample(grid, interpolation, Extrapolation::Error)?;
+            resampled.f.clone_from(&frequencies);
+            for (data_line, frequency) in resampled.s.iter_mut().zip(&frequencies) {
+                data_line.frequency = *frequency;
+            }
+            Ok::<_, TouchstoneError>(resampled)
+        };
+        Ok((aligned(self)?, aligned(other)?))
+    }
+
     fn data_line_at(
         &self,
         point_index: usize,
@@ -1070,86 +1453,89 @@ impl Network {
         validate_frequency_slice(&self.f)
     }
 
-    fn sample_data_line_at_validated(
+    fn sample_data_lines_at_validated(
         &self,
-        frequency_hz: f64,
+        frequencies_hz: &[f64],
         interpolation: Interpolation,
         extrapolation: Extrapolation,
-    ) -> Result<data_line::ParsedDataLine, TouchstoneError> {
+    ) -> Result<Vec<data_line::ParsedDataLine>, TouchstoneError> {
         let min = self.f[0];
         let max = self.f[self.f.len() - 1];
 
-        let lookup_frequency = if frequency_hz < min {
-            match extrapolation {
-                Extrapolation::Error => {
-                    return Err(TouchstoneError::FrequencyOutOfRange {
-                        frequency: frequency_hz,
-                        min,
-                        max,
-                    });
-                }
-                Extrapolation::Clamp => min,
-            }
-        } else if frequency_hz > max {
-            match extrapolation {
-                Extrapolation::Error => {
-                    return Err(TouchstoneError::FrequencyOutOfRange {
-                        frequency: frequency_hz,
-                        min,
-                        max,
-                    });
-                }
-                Extrapolation::Clamp => max,
-            }
-        } else {
-            frequency_hz
+        let rank = self.rank as usize;
+        let out_of_range = |frequency| TouchstoneError::FrequencyOutOfRange {
+            frequency,
+            min,
+            max,
         };
 
-        if let Ok(index) = self
-            .f
-            .binary_search_by(|frequency| frequency.partial_cmp(&lookup_frequency).unwrap())
-        {
-            return Ok(parsed_data_line_with_frequency(
-                &self.s[index],
-                frequency_hz,
-            ));
-        }
-
-        let upper_index = self
-            .f
-            .partition_point(|frequency| *frequency < lookup_frequency);
-        let lower_index = upper_index - 1;
-
-        let selected_index = match interpolation {
-            Interpolation::Nearest => {
-                let lower_frequency = self.f[lower_index];
-                let upper_frequency = self.f[upper_index];
-                if lookup_frequency - lower_frequency <= upper_frequency - lookup_frequency {
-                    lower_index
-                } else {
-                    upper_index
+        // Extrapolated points are filled directly; the rest are interpolated together so modes
+        // that fit the whole grid only do so once.
+        let mut data_lines = Vec::with_capacity(frequencies_hz.len());
+        let mut samples = Vec::new();
+        for &frequency_hz in frequencies_hz {
+            let data_line = if (min..=max).contains(&frequency_hz) {
+                samples.push((frequency_hz, frequency_hz));
+                None
+            } else {
+                match extrapolation {
+                    Extrapolation::Error => return Err(out_of_range(frequency_hz)),
+                    Extrapolation::Clamp => {
+                        samples.push((frequency_hz.clamp(min, max), frequency_hz));
+                        None
+                    }
+                    Extrapolation::Linear => Some(interpolation::extrapolate_linear(
+                        &self.f,
+                        &self.s,
+                        frequency_hz,
+                        frequency_hz,
+                    )),
+                    Extrapolation::Zero => Some(interpolation::filled_data_line(
+                        frequency_hz,
+                        rank,
+                        Complex::zero(),
+                    )),
+                    Extrapolation::Identity => Some(interpolation::filled_data_line(
+                        frequency_hz,
+                        rank,
+                        Complex::one(),
+                    )),
+                    Extrapolation::Dc if (0.0..min).contains(&frequency_hz) => {
+                        if self.f.len() < 2 {
+                            return Err(TouchstoneError::InsufficientFrequencyPoints {
+                                operation: "DC extrapolation".to_string(),
+                                required: 2,
+                                actual: self.f.len(),
+                            });
+                        }
+                        Some(interpolation::extrapolate_to_dc(
+                            &self.f,
+                            &self.s,
+                            frequency_hz,
+                        ))
+                    }
+                    Extrapolation::Dc => return Err(out_of_range(frequency_hz)),
                 }
-            }
-            Interpolation::Linear => {
-                return Ok(interpolate_data_lines(
-                    frequency_hz,
-                    lookup_frequency,
-                    self.f[lower_index],
-                    self.f[upper_index],
-                    &self.s[lower_index],
-                    &self.s[upper_index],
-                ));
-            }
-        };
+            };
+            data_lines.push(data_line);
+        }
 
-        Ok(parsed_data_line_with_frequency(
-            &self.s[selected_index],
-            frequency_hz,
-        ))
+        let interpolated =
+            interpolation::sample_data_lines(&self.f, &self.s, &samples, interpolation);
+        for (data_line, sampled) in data_lines
+            .iter_mut()
+            .filter(|data_line| data_line.is_none())
+            .zip(interpolated)
+        {
+            *data_line = Some(sampled);
+        }
+        Ok(data_lines.into_iter().flatten().collect())
     }
 
     /// Cascade two 2-port networks (standard connection: port 2 → port 1).
     ///
+    /// Both networks must share the same frequency grid; use
+    /// [`cascade_aligned()`](Network::cascade_aligned) for networks measured on different grids.
     /// For more control over port connections, use [`cascade_ports()`](Network::cascade_ports).
     ///
     /// # Examples
@@ -1162,37 +1548,167 @@ impl Network {
     /// let cascaded = net1.cascade(&net2);
     /// assert_eq!(cascaded.rank, 2);
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics when [`try_cascade()`](Network::try_cascade) would return an error.
     #[must_use]
     #[doc(alias = "network cascading")]
     #[doc(alias = "ABCD parameters")]
     #[doc(alias = "chain")]
     pub fn cascade(&self, other: &Network) -> Network {
-        if self.rank != 2 || other.rank != 2 {
-            panic!("Cascading is only implemented for 2-port networks. Use cascade_ports() for explicit port specification.");
+        match self.try_cascade(other) {
+            Ok(network) => network,
+            Err(TouchstoneError::InvalidNetworkRank { .. }) => {
+                panic!("Cascading is only implemented for 2-port networks. Use cascade_ports() for explicit port specification.")
+            }
+            Err(
+                TouchstoneError::UnsupportedReferenceImpedance { .. }
+                | TouchstoneError::UnsupportedComplexReferenceImpedance,
+            ) => panic!(
+                "Cannot cascade networks with per-port reference impedances; common scalar reference impedance is required"
+            ),
+            Err(TouchstoneError::ReferenceImpedanceMismatch { first, second }) => panic!(
+                "Cannot cascade networks with different reference impedances: {} and {}",
+                first, second
+            ),
+            Err(TouchstoneError::FrequencyUnitMismatch { first, second }) => panic!(
+                "Cannot cascade networks with different frequency units: {} and {}",
+                first, second
+            ),
+            Err(error @ TouchstoneError::FrequencyGridMismatch { .. }) => panic!(
+                "Cannot cascade networks with different frequency grids: {}. Use cascade_aligned() to resample them first.",
+                error
+            ),
+            Err(error) => panic!("Cannot cascade networks: {}", error),
+        }
+    }
+
+    /// Cascade two 2-port networks (port 2 → port 1), returning an error instead of panicking.
+    ///
+    /// Returns [`TouchstoneError::UnsupportedNetworkParameter`] when either network does not
+    /// contain S-parameter data, [`TouchstoneError::InvalidNetworkRank`] when either network is
+    /// not a 2-port, [`TouchstoneError::UnsupportedReferenceImpedance`] or
+    /// [`TouchstoneError::UnsupportedComplexReferenceImpedance`] when either network lacks a
+    /// common real reference impedance, [`TouchstoneError::ReferenceImpedanceMismatch`] and
+    /// [`TouchstoneError::FrequencyUnitMismatch`] when the networks disagree, and
+    /// [`TouchstoneError::FrequencyGridMismatch`] when their frequency grids differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Network, TouchstoneError};
+    ///
+    /// let net1 = Network::new("files/ntwk1.s2p")?;
+    /// let net2 = Network::new("files/ntwk2.s2p")?;
+    /// assert_eq!(net1.try_cascade(&net2)?.rank, 2);
+    ///
+    /// let three_port = Network::new("files/hfss_18.2.s3p")?;
+    /// assert!(matches!(
+    ///     net1.try_cascade(&three_port),
+    ///     Err(TouchstoneError::InvalidNetworkRank { rank: 3 })
+    /// ));
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn try_cascade(&self, other: &Network) -> Result<Network, TouchstoneError> {
+        let self_z0 = self.validate_two_port_pair(other)?;
+
+        let mut s_new = Vec::new();
+
+        for i in 0..self.s.len() {
+            let freq = self.s[i].frequency;
+            let s1 = &self.s[i].s_ri;
+            let s2 = &other.s[i].s_ri;
+
+            let abcd1 = s1.to_abcd(self_z0);
+            let abcd2 = s2.to_abcd(self_z0);
+
+            let abcd_new = abcd1 * abcd2;
+
+            // Resulting Z0? Usually the Z0 of the output port of the second network,
+            // but for S-parameters of the cascaded block, we usually reference the input port of the first
+            // and output port of the second.
+            // If Z0 is the same for both (checked at start of function), then it's just self_z0.
+            let s_new_ri = abcd_new.to_s(self_z0);
+
+            let s_new_ma = crate::data_pairs::MagnitudeAngleMatrix::from_vec(vec![
+                vec![
+                    s_new_ri.get(1, 1).magnitude_angle(),
+                    s_new_ri.get(1, 2).magnitude_angle(),
+                ],
+                vec![
+                    s_new_ri.get(2, 1).magnitude_angle(),
+                    s_new_ri.get(2, 2).magnitude_angle(),
+                ],
+            ]);
+
+            let s_new_db =
+                crate::data_pairs::DecibelAngleMatrix::from_magnitude_angle_matrix(&s_new_ma);
+
+            s_new.push(crate::data_line::ParsedDataLine {
+                frequency: freq,
+                s_ri: s_new_ri,
+                s_ma: s_new_ma,
+                s_db: s_new_db,
+            });
         }
 
-        let self_z0 = common_reference_impedance_or_panic(self);
-        let other_z0 = common_reference_impedance_or_panic(other);
+        Ok(self.combined_two_port(
+            other,
+            format!("Cascaded({},{})", self.name, other.name),
+            "Cascaded network",
+            self_z0,
+            s_new,
+        ))
+    }
+
+    /// Check that `self` and `other` are 2-ports that can be connected point by point and return
+    /// their common reference impedance.
+    fn validate_two_port_pair(&self, other: &Network) -> Result<f64, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+        other.ensure_s_parameter_data()?;
+        if let Some(rank) = [self.rank, other.rank].into_iter().find(|rank| *rank != 2) {
+            return Err(TouchstoneError::InvalidNetworkRank {
+                rank: rank as usize,
+            });
+        }
+
+        let self_z0 = common_reference_impedance(self)?;
+        let other_z0 = common_reference_impedance(other)?;
 
         if self_z0 != other_z0 {
-            panic!(
-                "Cannot cascade networks with different reference impedances: {} and {}",
-                self_z0, other_z0
-            );
+            return Err(TouchstoneError::ReferenceImpedanceMismatch {
+                first: self_z0,
+                second: other_z0,
+            });
         }
 
         // can avoid this by converting other.f to use self.frequency_unit instead of other.frequency_unit
         if self.frequency_unit != other.frequency_unit {
-            panic!(
-                "Cannot cascade networks with different frequency units: {} and {}",
-                self.frequency_unit, other.frequency_unit
-            );
+            return Err(TouchstoneError::FrequencyUnitMismatch {
+                first: self.frequency_unit.clone(),
+                second: other.frequency_unit.clone(),
+            });
         }
 
+        validate_matching_frequencies(&self.f, &other.f)?;
+        Ok(self_z0)
+    }
+
+    /// Build the 2-port that results from connecting `self` and `other`, merging both networks'
+    /// comments under headers that start with `description`.
+    fn combined_two_port(
+        &self,
+        other: &Network,
+        name: String,
+        description: &str,
+        z0: f64,
+        s: Vec<data_line::ParsedDataLine>,
+    ) -> Network {
         let mut comments = Vec::<String>::new();
         comments.push(format!(
-            "! Cascaded network of {} and {}",
-            self.name, other.name
+            "! {} of {} and {}",
+            description, self.name, other.name
         ));
         let comment_header_self = format!("! Comments from first network ({:?}):", self.name);
         comments.push(comment_header_self);
@@ -1208,8 +1724,8 @@ impl Network {
 
         let mut comments_after_option_line = Vec::<String>::new();
         comments_after_option_line.push(format!(
-            "! Cascaded network of {} and {}",
-            self.name, other.name
+            "! {} of {} and {}",
+            description, self.name, other.name
         ));
         let comments_after_option_line_header_self = format!(
             "! Comments (after option line) from first network ({:?}):",
@@ -1229,67 +1745,59 @@ impl Network {
             comments_after_option_line.push(comment_after_option_line.clone());
         }
 
-        let new_name = format!("Cascaded({},{})", self.name, other.name);
-
-        let mut s_new = Vec::new();
-        // Assuming index-wise alignment as discussed
-        let len = std::cmp::min(self.s.len(), other.s.len());
-
-        for i in 0..len {
-            let freq = self.s[i].frequency;
-            let s1 = &self.s[i].s_ri;
-            let s2 = &other.s[i].s_ri;
-
-            let abcd1 = s1.to_abcd(self_z0);
-            let abcd2 = s2.to_abcd(other_z0);
-
-            let abcd_new = abcd1 * abcd2;
-
-            // Resulting Z0? Usually the Z0 of the output port of the second network,
-            // but for S-parameters of the cascaded block, we usually reference the input port of the first
-            // and output port of the second.
-            // If Z0 is the same for both (checked at start of function), then it's just self_z0.
-            let s_new_ri = abcd_new.to_s(self_z0);
-
-            let s_new_ma = crate::data_pairs::MagnitudeAngleMatrix::from_vec(vec![
-                vec![
-                    s_new_ri.get(1, 1).magnitude_angle(),
-                    s_new_ri.get(1, 2).magnitude_angle(),
-                ],
-                vec![
-                    s_new_ri.get(2, 1).magnitude_angle(),
-                    s_new_ri.get(2, 2).magnitude_angle(),
-                ],
-            ]);
-
-            let s_new_db =
-                crate::data_pairs::DecibelAngleMatrix::from_magnitude_angle_matrix(&s_new_ma);
-
-            s_new.push(crate::data_line::ParsedDataLine {
-                frequency: freq,
-                s_ri: s_new_ri,
-                s_ma: s_new_ma,
-                s_db: s_new_db,
-            });
-        }
-
         Network {
-            name: new_name,
+            name,
             rank: self.rank,
             frequency_unit: self.frequency_unit.clone(),
             parameter: self.parameter.clone(),
             format: self.format.clone(),
             resistance_string: self.resistance_string.clone(),
-            z0: self_z0,
-            reference_impedance: ReferenceImpedance::Common(self_z0),
+            z0,
+            reference_impedance: ReferenceImpedance::Common(z0),
             comments,
             comments_after_option_line,
             warnings: [self.warnings.clone(), other.warnings.clone()].concat(),
-            f: self.f.clone(), // Note: this might be longer than s_new if other is shorter
-            s: s_new,
+            f: self.f.clone(),
+            s,
         }
     }
 
+    /// Cascade two 2-port networks after matching their frequency grids with `alignment`.
+    ///
+    /// The grids are matched with [`align_frequencies()`](Network::align_frequencies), so
+    /// mismatched grids return an error instead of pairing unrelated frequency points. The grids
+    /// are compared in Hz, so the networks may use different frequency units; the result keeps
+    /// the unit of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Extrapolation, FrequencyAlignment, Interpolation, Network};
+    ///
+    /// let net1 = Network::new("files/ntwk1.s2p")?;
+    /// let net2 = Network::new("files/ntwk2.s2p")?.resample(
+    ///     (0..=36).map(|index| 1.0e9 + index as f64 * 0.25e9),
+    ///     Interpolation::Linear,
+    ///     Extrapolation::Error,
+    /// )?;
+    ///
+    /// // Every 0.25 GHz point that is not already on the 0.1 GHz grid is added.
+    /// let cascaded =
+    ///     net1.cascade_aligned(&net2, FrequencyAlignment::Union(Interpolation::Linear))?;
+    /// assert_eq!(cascaded.f.len(), 109);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn cascade_aligned(
+        &self,
+        other: &Network,
+        alignment: FrequencyAlignment,
+    ) -> Result<Network, TouchstoneError> {
+        let (first, mut second) = self.align_frequencies(other, alignment)?;
+        // Both grids are held in Hz; the unit only controls how frequencies are written.
+        second.frequency_unit.clone_from(&first.frequency_unit);
+        first.try_cascade(&second)
+    }
+
     /// Cascade two networks with explicit port specification
     ///
     /// # Arguments
@@ -1357,8 +1865,8 @@ impl Network {
              \n\
              Future enhancement: Full N-port cascade with arbitrary port connections.\n\
              \n\
-             Workaround: Extract 2-port sub-networks from your {}-port and {}-port networks,\n\
-             then cascade those 2-port networks.",
+             Workaround: Extract 2-port sub-networks from your {}-port and {}-port networks\n\
+             with Network::subnetwork(), then cascade those 2-port networks.",
             self.rank,
             other.rank,
             self.rank,
@@ -1366,6 +1874,49 @@ impl Network {
         );
     }
 
+    /// Cascade two networks with explicit port specification, returning an error instead of
+    /// panicking.
+    ///
+    /// Returns [`TouchstoneError::InvalidPort`] when a port is out of range,
+    /// [`TouchstoneError::InvalidNetworkRank`] when either network is not a 2-port, and
+    /// [`TouchstoneError::UnsupportedPortConnection`] for any connection other than port 2 →
+    /// port 1. Otherwise behaves like [`try_cascade()`](Network::try_cascade).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Network, TouchstoneError};
+    ///
+    /// let net1 = Network::new("files/ntwk1.s2p")?;
+    /// let net2 = Network::new("files/ntwk2.s2p")?;
+    /// assert_eq!(net1.try_cascade_ports(&net2, 2, 1)?.rank, 2);
+    /// assert!(matches!(
+    ///     net1.try_cascade_ports(&net2, 3, 1),
+    ///     Err(TouchstoneError::InvalidPort { port: 3, rank: 2 })
+    /// ));
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn try_cascade_ports(
+        &self,
+        other: &Network,
+        from_port: usize,
+        to_port: usize,
+    ) -> Result<Network, TouchstoneError> {
+        ports::validate_port(from_port, self.rank as usize)?;
+        ports::validate_port(to_port, other.rank as usize)?;
+
+        if let Some(rank) = [self.rank, other.rank].into_iter().find(|rank| *rank != 2) {
+            return Err(TouchstoneError::InvalidNetworkRank {
+                rank: rank as usize,
+            });
+        }
+        if from_port != 2 || to_port != 1 {
+            return Err(TouchstoneError::UnsupportedPortConnection { from_port, to_port });
+        }
+
+        self.try_cascade(other)
+    }
+
     /// Serialize the network to an in-memory Touchstone string.
     ///
     /// The output matches [`save`](Self::save), including Touchstone 2.1 keywords and N-port
@@ -1381,7 +1932,27 @@ impl Network {
     ///
     /// The writer auto-selects single-line format for 1-port and 2-port networks and multi-line
     /// full-matrix format for 3-port and larger networks.
+    ///
+    /// Touchstone 2.1 `[Reference]` values are real, so complex or frequency-dependent reference
+    /// impedances return [`std::io::ErrorKind::InvalidInput`] unless they reduce to real per-port
+    /// values. Reference impedances that do not cover every port return the same error.
     pub fn write_touchstone<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        self.reference_impedance()
+            .validate_shape(self.rank as usize, self.s.len())
+            .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidInput, error))?;
+        let reference_values = match self.reference_impedance() {
+            ReferenceImpedance::Common(_) => None,
+            ReferenceImpedance::PerPort(values) => Some(values),
+            reference_impedance => Some(reference_impedance.real_per_port_values().ok_or_else(
+                || {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "complex or frequency-dependent reference impedances cannot be written as Touchstone 2.1 [Reference] values",
+                    )
+                },
+            )?),
+        };
+
         // Write comments
         for comment in &self.comments {
             writeln!(writer, "{}", comment)?;
@@ -1406,7 +1977,7 @@ impl Network {
         if n == 2 {
             writeln!(writer, "[Two-Port Data Order] 21_12")?;
         }
-        if let ReferenceImpedance::PerPort(values) = self.reference_impedance() {
+        if let Some(values) = reference_values {
             writeln!(writer, "[Reference] {}", format_real_values(&values))?;
         }
         writeln!(writer, "[Number of Frequencies] {}", self.f.len())?;
@@ -1797,13 +2368,51 @@ fn ensure_non_singular_value(
     }
 }
 
-fn common_reference_impedance_or_panic(network: &Network) -> f64 {
+/// Convert two-port `[s11, s12, s21, s22]` to `[h11, h12, h21, h22]`.
+fn two_port_s_to_h(
+    operation: &str,
+    [s11, s12, s21, s22]: [Complex; 4],
+    z0: f64,
+) -> Result<[Complex; 4], TouchstoneError> {
+    let one = Complex::one();
+    let denominator = (one - s11) * (one + s22) + s12 * s21;
+    ensure_non_singular_value(operation, 0, denominator, PARAMETER_CONVERSION_TOLERANCE)?;
+
+    Ok([
+        ((one + s11) * (one + s22) - s12 * s21) * z0 / denominator,
+        s12 * 2.0 / denominator,
+        -(s21 * 2.0) / denominator,
+        ((one - s11) * (one - s22) - s12 * s21) / z0 / denominator,
+    ])
+}
+
+/// Convert two-port `[h11, h12, h21, h22]` to `[s11, s12, s21, s22]`.
+fn two_port_h_to_s(
+    operation: &str,
+    [h11, h12, h21, h22]: [Complex; 4],
+    z0: f64,
+) -> Result<[Complex; 4], TouchstoneError> {
+    let one = Complex::one();
+    let (h11, h22) = (h11 / z0, h22 * z0);
+    let denominator = (h11 + one) * (h22 + one) - h12 * h21;
+    ensure_non_singular_value(operation, 0, denominator, PARAMETER_CONVERSION_TOLERANCE)?;
+
+    Ok([
+        ((h11 - one) * (h22 + one) - h12 * h21) / denominator,
+        h12 * 2.0 / denominator,
+        -(h21 * 2.0) / denominator,
+        ((one + h11) * (one - h22) + h12 * h21) / denominator,
+    ])
+}
+
+fn common_reference_impedance(network: &Network) -> Result<f64, TouchstoneError> {
     match network.reference_impedance() {
-        ReferenceImpedance::Common(z0) => z0,
-        ReferenceImpedance::PerPort(_) => {
-            panic!(
-                "Cannot cascade networks with per-port reference impedances; common scalar reference impedance is required"
-            );
+        ReferenceImpedance::Common(z0) => Ok(z0),
+        ReferenceImpedance::PerPort(values) => {
+            Err(TouchstoneError::UnsupportedReferenceImpedance { values })
+        }
+        ReferenceImpedance::PerPortComplex(_) | ReferenceImpedance::FrequencyDependent(_) => {
+            Err(TouchstoneError::UnsupportedComplexReferenceImpedance)
         }
     }
 }
@@ -1813,6 +2422,7 @@ fn common_reference_impedance_or_panic(network: &Network) -> f64 {
 // The following block implements the operation: Foo * Bar = FooBar
 // This cascades Foo with Bar where in a gain lineup Foo comes before Bar
 // using a device analogy -> [Foo] & [Bar] = [Foo Bar]
+// Like `cascade`, this panics on mismatched networks; use `try_cascade` to handle them.
 impl ops::Mul<Network> for Network {
     type Output = Network;
 
@@ -1823,6 +2433,29 @@ impl ops::Mul<Network> for Network {
     }
 }
 
+/// Return whether two frequencies are equal up to floating-point rounding from unit scaling.
+pub(crate) fn frequencies_match(first: f64, second: f64) -> bool {
+    (first - second).abs() <= FREQUENCY_MATCH_TOLERANCE * first.abs().max(second.abs())
+}
+
+fn validate_matching_frequencies(first: &[f64], second: &[f64]) -> Result<(), TouchstoneError> {
+    for point_index in 0..first.len().max(second.len()) {
+        let (first_frequency, second_frequency) = (first.get(point_index), second.get(point_index));
+        let matches = matches!(
+            (first_frequency, second_frequency),
+            (Some(first), Some(second)) if frequencies_match(*first, *second)
+        );
+        if !matches {
+            return Err(TouchstoneError::FrequencyGridMismatch {
+                point_index,
+                first: first_frequency.copied(),
+                second: second_frequency.copied(),
+            });
+        }
+    }
+    Ok(())
+}
+
 fn validate_frequency_slice(frequencies: &[f64]) -> Result<(), TouchstoneError> {
     if frequencies.is_empty() {
         return Err(TouchstoneError::EmptyNetworkData);
@@ -1870,46 +2503,6 @@ fn validate_sample_frequency(point_index: usize, frequency: f64) -> Result<(), T
     }
 }
 
-fn parsed_data_line_with_frequency(
-    data_line: &data_line::ParsedDataLine,
-    frequency: f64,
-) -> data_line::ParsedDataLine {
-    data_line::parsed_data_line_from_ri_matrix(frequency, data_line.s_ri.clone())
-}
-
-fn interpolate_data_lines(
-    output_frequency: f64,
-    lookup_frequency: f64,
-    lower_frequency: f64,
-    upper_frequency: f64,
-    lower: &data_line::ParsedDataLine,
-    upper: &data_line::ParsedDataLine,
-) -> data_line::ParsedDataLine {
-    let n = lower.s_ri.size();
-    let t = (lookup_frequency - lower_frequency) / (upper_frequency - lower_frequency);
-    let mut data = Vec::with_capacity(n);
-
-    for row in 1..=n {
-        let mut row_data = Vec::with_capacity(n);
-
-        for col in 1..=n {
-            let lower_value = lower.s_ri.get(row, col);
-            let upper_value = upper.s_ri.get(row, col);
-            row_data.push(data_pairs::RealImaginary(
-                lower_value.0 + (upper_value.0 - lower_value.0) * t,
-                lower_value.1 + (upper_value.1 - lower_value.1) * t,
-            ));
-        }
-
-        data.push(row_data);
-    }
-
-    data_line::parsed_data_line_from_ri_matrix(
-        output_frequency,
-        data_pairs::RealImaginaryMatrix::from_vec(data),
-    )
-}
-
 fn network_point_from_data_line(data_line: &data_line::ParsedDataLine) -> NetworkPoint {
     let rank = data_line.s_ri.size();
     let mut data = Vec::with_capacity(rank);
@@ -2556,6 +3149,153 @@ mod tests {
         let _ = net1.cascade(&net2);
     }
 
+    #[test]
+    fn test_try_cascade_reports_mismatches() {
+        let net1 = Network::new("files/ntwk1.s2p").unwrap();
+        let net2 = Network::new("files/ntwk2.s2p").unwrap();
+        assert_eq!(
+            net1.try_cascade(&net2).unwrap().s[0].s_ri,
+            net1.cascade(&net2).s[0].s_ri
+        );
+
+        let mut other = net2.clone();
+        other.z0 = 75.0;
+        other.reference_impedance = ReferenceImpedance::Common(75.0);
+        assert!(matches!(
+            net1.try_cascade(&other),
+            Err(TouchstoneError::ReferenceImpedanceMismatch {
+                first: 50.0,
+                second: 75.0
+            })
+        ));
+
+        let mut other = net2.clone();
+        other.frequency_unit = "MHz".to_string();
+        assert!(matches!(
+            net1.try_cascade(&other),
+            Err(TouchstoneError::FrequencyUnitMismatch { .. })
+        ));
+
+        let mut other = net2.clone();
+        other.reference_impedance = ReferenceImpedance::PerPort(vec![50.0, 75.0]);
+        assert!(matches!(
+            net1.try_cascade(&other),
+            Err(TouchstoneError::UnsupportedReferenceImpedance { .. })
+        ));
+
+        let three_port = Network::new("files/hfss_18.2.s3p").unwrap();
+        assert!(matches!(
+            three_port.try_cascade(&net2),
+            Err(TouchstoneError::InvalidNetworkRank { rank: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_try_cascade_ports_reports_invalid_connections() {
+        let net1 = Network::new("files/ntwk1.s2p").unwrap();
+        let net2 = Network::new("files/ntwk2.s2p").unwrap();
+        assert_eq!(net1.try_cascade_ports(&net2, 2, 1).unwrap().rank, 2);
+        assert!(matches!(
+            net1.try_cascade_ports(&net2, 0, 1),
+            Err(TouchstoneError::InvalidPort { port: 0, rank: 2 })
+        ));
+        assert!(matches!(
+            net1.try_cascade_ports(&net2, 1, 2),
+            Err(TouchstoneError::UnsupportedPortConnection {
+                from_port: 1,
+                to_port: 2
+            })
+        ));
+
+        let three_port = Network::new("files/hfss_18.2.s3p").unwrap();
+        assert!(matches!(
+            three_port.try_cascade_ports(&three_port, 2, 1),
+            Err(TouchstoneError::InvalidNetworkRank { rank: 3 })
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot cascade networks with different frequency grids")]
+    fn test_cascade_different_grids() {
+        let net1 = Network::new("files/ntwk1.s2p").unwrap();
+        let net2 = net1
+            .resample([1.0e9, 2.0e9], Interpolation::Linear, Extrapolation::Error)
+            .unwrap();
+        let _ = net1.cascade(&net2);
+    }
+
+    #[test]
+    fn test_align_frequencies() {
+        let net1 = Network::new("files/ntwk1.s2p").unwrap();
+        let net2 = Network::new("files/ntwk2.s2p")
+            .unwrap()
+            .resample(
+                [0.5e9, 2.0e9, 2.25e9, 3.0e9],
+                Interpolation::Linear,
+                Extrapolation::Clamp,
+            )
+            .unwrap();
+
+        let error = net1
+            .align_frequencies(&net2, FrequencyAlignment::Exact)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            TouchstoneError::FrequencyGridMismatch { point_index: 0, .. }
+        ));
+
+        let (first, second) = net1
+            .align_frequencies(&net2, FrequencyAlignment::Intersection)
+            .unwrap();
+        assert_eq!(first.f, vec![2.0e9, 3.0e9]);
+        assert_eq!(second.f, first.f);
+        assert_eq!(
+            second.try_s_ri_at(1, 2, 1).unwrap(),
+            net2.try_s_ri_at(3, 2, 1).unwrap()
+        );
+
+        let (first, second) = net1
+            .align_frequencies(&net2, FrequencyAlignment::Union(Interpolation::Linear))
+            .unwrap();
+        assert_eq!(first.f.len(), 22);
+        assert_eq!(first.f[0], 1.0e9);
+        assert_eq!(first.f[21], 3.0e9);
+        assert_eq!(second.f, first.f);
+
+        let cascaded = net1
+            .cascade_aligned(&net2, FrequencyAlignment::Intersection)
+            .unwrap();
+        assert_eq!(cascaded.f, vec![2.0e9, 3.0e9]);
+    }
+
+    #[test]
+    fn test_cascade_aligned_accepts_different_frequency_units() {
+        let net1 = Network::new("files/ntwk1.s2p").unwrap();
+        let net2 = Network::new("files/ntwk2.s2p").unwrap();
+        let mut megahertz = net2.clone();
+        megahertz.frequency_unit = "MHz".to_string();
+        let mut contents = Vec::new();
+        megahertz.write_touchstone(&mut contents).unwrap();
+        let megahertz =
+            Network::from_str("ntwk2_mhz.s2p", &String::from_utf8(contents).unwrap()).unwrap();
+        assert_eq!(megahertz.frequency_unit, "MHz");
+
+        let cascaded = net1
+            .cascade_aligned(&megahertz, FrequencyAlignment::Exact)
+            .unwrap();
+
+        let expected = net1.try_cascade(&net2).unwrap();
+        assert_eq!(cascaded.frequency_unit, "GHz");
+        assert_eq!(cascaded.f.len(), expected.f.len());
+        for point in 0..expected.f.len() {
+            for (row, column) in [(1, 1), (1, 2), (2, 1), (2, 2)] {
+                let actual = cascaded.try_s_ri_at(point, row, column).unwrap();
+                let expected = expected.try_s_ri_at(point, row, column).unwrap();
+                assert!((actual - expected).magnitude() < 1.0e-9);
+            }
+        }
+    }
+
     #[test]
     fn test_print_summary() {
         let net = Network::new("files/ntwk1.s2p").unwrap();
@@ -2695,6 +3435,76 @@ mod tests {
         assert_s11(&upper, 2.0, 4.0);
     }
 
+    #[test]
+    fn sample_at_linear_extrapolation_extends_boundary_segments() {
+        let network = interpolation_1port_network();
+
+        let below = network
+            .sample_at(0.5e9, Interpolation::Linear, Extrapolation::Linear)
+            .unwrap();
+        let above = network
+            .sample_at(3.0e9, Interpolation::Nearest, Extrapolation::Linear)
+            .unwrap();
+
+        assert_s11(&below, -1.0, -2.0);
+        assert_s11(&above, 4.0, 8.0);
+    }
+
+    #[test]
+    fn resample_zero_and_identity_fill_outside_range() {
+        let network = Network::new("files/ntwk1.s2p").unwrap();
+        let max = network.f[network.f.len() - 1];
+
+        let zero = network
+            .resample([max, 2.0 * max], Interpolation::Linear, Extrapolation::Zero)
+            .unwrap();
+        let identity = network
+            .resample(
+                [max, 2.0 * max],
+                Interpolation::Linear,
+                Extrapolation::Identity,
+            )
+            .unwrap();
+
+        assert_eq!(zero.s[0].s_ri, network.s[network.s.len() - 1].s_ri);
+        for (to_port, from_port) in [(1, 1), (2, 1), (1, 2), (2, 2)] {
+            let expected = if to_port == from_port { 1.0 } else { 0.0 };
+            assert_eq!(zero.s[1].s_ri.get(to_port, from_port).0, 0.0);
+            assert_eq!(identity.s[1].s_ri.get(to_port, from_port).0, expected);
+            assert_eq!(identity.s[1].s_ri.get(to_port, from_port).1, 0.0);
+        }
+    }
+
+    #[test]
+    fn resample_dc_extrapolation_adds_real_dc_point() {
+        // Through with 90 degrees per GHz of delay and falling magnitude.
+        let network =
+            Network::from_str("delay.s1p", "# GHz S MA R 50\n1.0 0.9 -90\n2.0 0.8 -180\n").unwrap();
+
+        let resampled = network
+            .resample(
+                [0.0, 0.5e9, 1.0e9],
+                Interpolation::Linear,
+                Extrapolation::Dc,
+            )
+            .unwrap();
+
+        let dc = resampled.try_s_ri_at(0, 1, 1).unwrap();
+        assert_approx_eq(dc.re, 1.0);
+        assert_approx_eq(dc.im, 0.0);
+        let half = resampled.try_s_ri_at(1, 1, 1).unwrap();
+        assert_approx_eq(half.re, 0.5);
+        assert_approx_eq(half.im, -0.45);
+        assert!(matches!(
+            network.sample_at(3.0e9, Interpolation::Linear, Extrapolation::Dc),
+            Err(TouchstoneError::FrequencyOutOfRange { .. })
+        ));
+        assert!(matches!(
+            network.sample_at(-1.0e9, Interpolation::Linear, Extrapolation::Dc),
+            Err(TouchstoneError::FrequencyOutOfRange { .. })
+        ));
+    }
+
     #[test]
     fn sample_at_interpolates_n_port_matrix_values() {
         let network = Network::from_str(
@@ -2715,6 +3525,74 @@ mod tests {
         assert_approx_eq(s32.im, -13.0);
     }
 
+    #[test]
+    fn sample_at_cubic_spline_uses_natural_spline_through_every_point() {
+        let network = Network::from_str(
+            "curve.s1p",
+            "# GHz S RI R 50\n1.0 0.0 0.0\n2.0 1.0 -2.0\n3.0 0.0 0.0\n",
+        )
+        .unwrap();
+
+        let node = network
+            .sample_at(2.0e9, Interpolation::CubicSpline, Extrapolation::Error)
+            .unwrap();
+        let point = network
+            .sample_at(1.5e9, Interpolation::CubicSpline, Extrapolation::Error)
+            .unwrap();
+
+        assert_s11(&node, 1.0, -2.0);
+        // Natural spline with curvature -6 at the middle point: 0.5 + 3 * 0.375 / 6.
+        assert_s11(&point, 0.6875, -1.375);
+    }
+
+    #[test]
+    fn sample_at_magnitude_phase_rotates_between_points() {
+        let network = Network::from_str(
+            "rotate.s1p",
+            "# GHz S MA R 50\n1.0 1.0 170.0\n2.0 0.5 -150.0\n",
+        )
+        .unwrap();
+
+        let point = network
+            .sample_at(1.5e9, Interpolation::MagnitudePhase, Extrapolation::Error)
+            .unwrap();
+
+        // The phase takes the 40 degree path through 180 degrees.
+        let expected = 0.75 * (-170.0_f64).to_radians().cos();
+        assert_s11(&point, expected, 0.75 * (-170.0_f64).to_radians().sin());
+        let s11 = point.s.get(1, 1).unwrap();
+        assert_approx_eq(s11.magnitude(), 0.75);
+    }
+
+    #[test]
+    fn resample_delay_compensated_restores_pure_delay() {
+        // 0.3 ns delay: -108 degrees per GHz.
+        let network = Network::from_str(
+            "delay.s1p",
+            "# GHz S MA R 50\n1.0 1 -108\n2.0 1 -216\n3.0 1 -324\n4.0 1 -432\n",
+        )
+        .unwrap();
+
+        let resampled = network
+            .resample(
+                [1.25e9, 2.5e9, 3.75e9],
+                Interpolation::DelayCompensated,
+                Extrapolation::Error,
+            )
+            .unwrap();
+        let linear = network
+            .sample_at(2.5e9, Interpolation::Linear, Extrapolation::Error)
+            .unwrap();
+
+        for (point_index, frequency) in resampled.f.iter().enumerate() {
+            let angle = -2.0 * std::f64::consts::PI * frequency * 0.3e-9;
+            let s11 = resampled.try_s_ri_at(point_index, 1, 1).unwrap();
+            assert_approx_eq(s11.re, angle.cos());
+            assert_approx_eq(s11.im, angle.sin());
+        }
+        assert!(linear.s.get(1, 1).unwrap().magnitude() < 0.9);
+    }
+
     #[test]
     fn resample_preserves_network_metadata() {
         let network = Network::from_str(
@@ -0,0 +1,284 @@
+use std::f64::consts::PI;
+
+use crate::{
+    validate_port_indexes, validate_sample_frequency, AngleUnit, Extrapolation, Interpolation,
+    Network, TouchstoneError,
+};
+
+/// Scalar derived from one S-parameter for measurements and markers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Quantity {
+    /// Magnitude in dB, `20·log10|S|`.
+    #[default]
+    Decibel,
+    /// Linear magnitude `|S|`.
+    Magnitude,
+    /// Unwrapped phase in degrees, as returned by [`Network::s_phase_unwrapped`].
+    Phase,
+    /// Group delay in seconds, as returned by [`Network::group_delay`] with an aperture of 1.
+    GroupDelay,
+}
+
+/// Value of a [`Quantity`] at a single frequency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyValue {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Value in the units of the measured [`Quantity`].
+    pub value: f64,
+}
+
+/// Statistics of a [`Quantity`] over a frequency band.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::{Network, Quantity};
+///
+/// let net = Network::new("files/ntwk1.s2p").unwrap();
+/// let s21 = net.measure(2, 1, Quantity::Decibel, 2.0e9, 4.0e9).unwrap();
+/// println!(
+///     "S21 min {} dB at {} Hz, ripple {} dB",
+///     s21.min.value,
+///     s21.min.frequency,
+///     s21.peak_to_peak()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BandMeasurement {
+    /// Smallest value and the frequency where it occurs first.
+    pub min: FrequencyValue,
+    /// Largest value and the frequency where it occurs first.
+    pub max: FrequencyValue,
+    /// Mean of the values at the frequency points in the band.
+    pub mean: f64,
+}
+
+impl BandMeasurement {
+    /// Return the peak-to-peak variation `max − min`.
+    #[must_use]
+    #[doc(alias = "ripple")]
+    pub fn peak_to_peak(&self) -> f64 {
+        self.max.value - self.min.value
+    }
+}
+
+impl Network {
+    /// Return `quantity` of S(j,k) at every frequency point.
+    ///
+    /// Port indices `j` and `k` are 1-indexed. [`Quantity::GroupDelay`] needs at least two
+    /// frequency points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Network, Quantity};
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let s11_db = net.quantity(1, 1, Quantity::Decibel)?;
+    ///
+    /// assert_eq!(s11_db[0].value, net.s_db(1, 1)[0].s_db.decibel());
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn quantity(
+        &self,
+        j: usize,
+        k: usize,
+        quantity: Quantity,
+    ) -> Result<Vec<FrequencyValue>, TouchstoneError> {
+        validate_port_indexes(j, k, self.rank as usize)?;
+        self.validate_frequency_data()?;
+
+        let (j, k) = (j as i8, k as i8);
+        Ok(match quantity {
+            Quantity::Decibel => self
+                .s_db(j, k)
+                .into_iter()
+                .map(|point| FrequencyValue {
+                    frequency: point.frequency,
+                    value: point.s_db.decibel(),
+                })
+                .collect(),
+            Quantity::Magnitude => self
+                .s_ma(j, k)
+                .into_iter()
+                .map(|point| FrequencyValue {
+                    frequency: point.frequency,
+                    value: point.s_ma.magnitude(),
+                })
+                .collect(),
+            Quantity::Phase => self
+                .s_phase_unwrapped(j, k, AngleUnit::Degrees)
+                .into_iter()
+                .map(|point| FrequencyValue {
+                    frequency: point.frequency,
+                    value: point.phase,
+                })
+                .collect(),
+            Quantity::GroupDelay => {
+                if self.f.len() < 2 {
+                    return Err(TouchstoneError::InsufficientFrequencyPoints {
+                        operation: "group delay".to_string(),
+                        required: 2,
+                        actual: self.f.len(),
+                    });
+                }
+                self.group_delay(j, k, 1)
+                    .into_iter()
+                    .map(|point| FrequencyValue {
+                        frequency: point.frequency,
+                        value: point.group_delay,
+                    })
+                    .collect()
+            }
+        })
+    }
+
+    /// Return the minimum, maximum, and mean of `quantity` of S(j,k) over a frequency band.
+    ///
+    /// The band includes every parsed frequency point from `start_hz` to `stop_hz`, inclusive.
+    /// Bands that are reversed, not finite, or contain no frequency points return
+    /// [`TouchstoneError::InvalidFrequencyBand`]. Port indices `j` and `k` are 1-indexed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Network, Quantity};
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let s11 = net.measure(1, 1, Quantity::Decibel, 1.0e9, 10.0e9)?;
+    ///
+    /// assert!(s11.min.value <= s11.mean && s11.mean <= s11.max.value);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "statistics")]
+    #[doc(alias = "min")]
+    #[doc(alias = "max")]
+    pub fn measure(
+        &self,
+        j: usize,
+        k: usize,
+        quantity: Quantity,
+        start_hz: f64,
+        stop_hz: f64,
+    ) -> Result<BandMeasurement, TouchstoneError> {
+        let values = self.quantity(j, k, quantity)?;
+        let mut in_band = values
+            .iter()
+            .filter(|point| start_hz <= point.frequency && point.frequency <= stop_hz);
+
+        let first = *in_band
+            .next()
+            .ok_or(TouchstoneError::InvalidFrequencyBand {
+                start: start_hz,
+                stop: stop_hz,
+            })?;
+        let (min, max, sum, count) = in_band.fold(
+            (first, first, first.value, 1),
+            |(min, max, sum, count), point| {
+                (
+                    if point.value < min.value { *point } else { min },
+                    if point.value > max.value { *point } else { max },
+                    sum + point.value,
+                    count + 1,
+                )
+            },
+        );
+
+        Ok(BandMeasurement {
+            min,
+            max,
+            mean: sum / count as f64,
+        })
+    }
+
+    /// Return `quantity` of S(j,k) at a marker frequency in Hz.
+    ///
+    /// Magnitudes are taken from [`sample_at`](Self::sample_at) with `interpolation`, so markers
+    /// between frequency points match the interpolated S-parameters. Phase is unwrapped
+    /// consistently with [`Quantity::Phase`], and group delay is interpolated between its values
+    /// at the frequency points, taking the nearest point for [`Interpolation::Nearest`] and
+    /// interpolating linearly otherwise. Frequencies outside the parsed range return
+    /// [`TouchstoneError::FrequencyOutOfRange`]. Port indices `j` and `k` are 1-indexed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Interpolation, Network, Quantity};
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let marker = net.marker(2, 1, Quantity::Decibel, 2.45e9, Interpolation::Linear)?;
+    ///
+    /// println!("S21 at {} Hz: {} dB", marker.frequency, marker.value);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn marker(
+        &self,
+        j: usize,
+        k: usize,
+        quantity: Quantity,
+        frequency_hz: f64,
+        interpolation: Interpolation,
+    ) -> Result<FrequencyValue, TouchstoneError> {
+        validate_port_indexes(j, k, self.rank as usize)?;
+        validate_sample_frequency(0, frequency_hz)?;
+        let sample = self
+            .sample_at(frequency_hz, interpolation, Extrapolation::Error)?
+            .s
+            .get(j, k)?;
+
+        let value = match quantity {
+            Quantity::Decibel => 20.0 * sample.magnitude().log10(),
+            Quantity::Magnitude => sample.magnitude(),
+            Quantity::Phase => {
+                // Unwrap against the nearest frequency point.
+                let phases = self.quantity(j, k, Quantity::Phase)?;
+                let nearest = nearest_index(&self.f, frequency_hz);
+                let point = self.s[nearest].s_ri.get(j, k);
+                let mut step = sample.im.atan2(sample.re) - point.1.atan2(point.0);
+                step -= 2.0 * PI * (step / (2.0 * PI)).round();
+                phases[nearest].value + step.to_degrees()
+            }
+            Quantity::GroupDelay => {
+                let delays = self.quantity(j, k, Quantity::GroupDelay)?;
+                let upper = self
+                    .f
+                    .partition_point(|frequency| *frequency < frequency_hz);
+                if upper == 0 || self.f[upper] == frequency_hz {
+                    delays[upper].value
+                } else {
+                    let lower = upper - 1;
+                    match interpolation {
+                        Interpolation::Nearest => {
+                            delays[nearest_index(&self.f, frequency_hz)].value
+                        }
+                        _ => {
+                            let t =
+                                (frequency_hz - self.f[lower]) / (self.f[upper] - self.f[lower]);
+                            delays[lower].value + t * (delays[upper].value - delays[lower].value)
+                        }
+                    }
+                }
+            }
+        };
+
+        Ok(FrequencyValue {
+            frequency: frequency_hz,
+            value,
+        })
+    }
+}
+
+/// Return the index of the frequency point closest to `frequency`, preferring the lower point on
+/// ties.
+fn nearest_index(frequencies: &[f64], frequency: f64) -> usize {
+    let upper = frequencies
+        .partition_point(|value| *value < frequency)
+        .min(frequencies.len() - 1);
+    if upper > 0 && frequency - frequencies[upper - 1] <= frequencies[upper] - frequency {
+        upper - 1
+    } else {
+        upper
+    }
+}
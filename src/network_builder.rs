@@ -247,7 +247,10 @@ fn validate_matrix(
     Ok(())
 }
 
-fn parsed_data_line_from_matrix(frequency: f64, matrix: &SMatrix) -> data_line::ParsedDataLine {
+pub(crate) fn parsed_data_line_from_matrix(
+    frequency: f64,
+    matrix: &SMatrix,
+) -> data_line::ParsedDataLine {
     let s_ri_data = matrix
         .data
         .iter()
@@ -276,7 +279,7 @@ fn infer_touchstone_extension_rank(name: &str) -> Option<usize> {
     digits.parse::<usize>().ok()
 }
 
-fn canonical_frequency_unit(unit: &str) -> Option<&'static str> {
+pub(crate) fn canonical_frequency_unit(unit: &str) -> Option<&'static str> {
     match unit.trim().to_ascii_lowercase().as_str() {
         "hz" => Some("Hz"),
         "khz" => Some("kHz"),
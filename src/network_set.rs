@@ -0,0 +1,465 @@
+use std::path::Path;
+
+use crate::file_operations;
+use crate::network_builder::parsed_data_line_from_matrix;
+use crate::{
+    Complex, Extrapolation, FrequencyValue, Interpolation, Network, Quantity, SMatrix,
+    TouchstoneError,
+};
+
+/// Statistics of a [`Quantity`] across the networks of a [`NetworkSet`] at a single frequency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyStatistics {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Mean value.
+    pub mean: f64,
+    /// Population standard deviation.
+    pub std: f64,
+    /// Smallest value; the lower edge of the envelope.
+    pub min: f64,
+    /// Largest value; the upper edge of the envelope.
+    pub max: f64,
+}
+
+/// Several compatible networks, such as measurements of different units of the same part.
+///
+/// Every network in a set has the same rank, frequency grid, and reference impedance, so
+/// statistics can be taken point by point.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::{NetworkSet, Quantity};
+///
+/// let set = NetworkSet::from_directory("files/test_plot_dir")?;
+/// let mean = set.mean();
+/// let s21 = set.statistics(2, 1, Quantity::Decibel)?;
+///
+/// assert_eq!(mean.rank, 2);
+/// assert!(s21[0].min <= s21[0].mean && s21[0].mean <= s21[0].max);
+/// # Ok::<(), touchstone::TouchstoneError>(())
+/// ```
+#[doc(alias = "ensemble")]
+#[doc(alias = "monte carlo")]
+#[derive(Debug, Clone)]
+pub struct NetworkSet {
+    networks: Vec<Network>,
+}
+
+impl NetworkSet {
+    /// Create a set from networks that share rank, frequency grid, and reference impedance.
+    ///
+    /// Returns [`TouchstoneError::EmptyNetworkSet`] for an empty list and
+    /// [`TouchstoneError::IncompatibleNetwork`] for the first network that does not match the
+    /// first one. Frequencies are compared up to floating-point rounding, so files written with
+    /// different frequency units can share a set. Use [`resampled`](Self::resampled) for
+    /// networks measured on different grids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Network, NetworkSet};
+    ///
+    /// let set = NetworkSet::new(vec![
+    ///     Network::new("files/ntwk1.s2p")?,
+    ///     Network::new("files/ntwk2.s2p")?,
+    /// ])?;
+    ///
+    /// assert_eq!(set.len(), 2);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn new(networks: Vec<Network>) -> Result<Self, TouchstoneError> {
+        let first = networks.first().ok_or(TouchstoneError::EmptyNetworkSet)?;
+
+        for (index, network) in networks.iter().enumerate() {
+            network.ensure_s_parameter_data()?;
+            network.validate_frequency_data()?;
+
+            let reason = if network.rank != first.rank {
+                format!(
+                    "{}-port network, expected {} ports",
+                    network.rank, first.rank
+                )
+            } else if network.f.len() != first.f.len()
+                || network
+                    .f
+                    .iter()
+                    .zip(&first.f)
+                    .any(|(frequency, first_frequency)| {
+                        !crate::frequencies_match(*frequency, *first_frequency)
+                    })
+            {
+                "frequency grid differs from the first network".to_string()
+            } else if network.reference_impedance != first.reference_impedance {
+                "reference impedance differs from the first network".to_string()
+            } else {
+                continue;
+            };
+            return Err(TouchstoneError::IncompatibleNetwork {
+                index,
+                name: network.name.clone(),
+                reason,
+            });
+        }
+
+        Ok(Self { networks })
+    }
+
+    /// Create a set after resampling every network onto a common frequency grid.
+    ///
+    /// The grid is the first network's frequency points that lie inside every network's
+    /// frequency range, so no network is extrapolated. Returns
+    /// [`TouchstoneError::InvalidFrequencyBand`] when the ranges do not overlap at any of those
+    /// points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Interpolation, Network, NetworkSet};
+    ///
+    /// let coarse =
+    ///     Network::from_str("coarse.s1p", "# GHz S RI R 50\n1.0 0.0 0.0\n3.0 0.4 0.0\n")?;
+    /// let fine = Network::from_str(
+    ///     "fine.s1p",
+    ///     "# GHz S RI R 50\n1.0 0.2 0.0\n2.0 0.2 0.0\n3.0 0.2 0.0\n",
+    /// )?;
+    /// let set = NetworkSet::resampled(vec![fine, coarse], Interpolation::Linear)?;
+    ///
+    /// assert_eq!(set.frequencies(), &[1.0e9, 2.0e9, 3.0e9]);
+    /// assert!((set.mean().try_s_ri_at(1, 1, 1)?.re - 0.2).abs() < 1e-12);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn resampled(
+        networks: Vec<Network>,
+        interpolation: Interpolation,
+    ) -> Result<Self, TouchstoneError> {
+        let first = networks.first().ok_or(TouchstoneError::EmptyNetworkSet)?;
+        for network in &networks {
+            network.validate_frequency_data()?;
+        }
+
+        let start = networks
+            .iter()
+            .map(|network| network.f[0])
+            .fold(f64::NEG_INFINITY, f64::max);
+        let stop = networks
+            .iter()
+            .map(|network| network.f[network.f.len() - 1])
+            .fold(f64::INFINITY, f64::min);
+        let grid = first
+            .f
+            .iter()
+            .copied()
+            .filter(|frequency| start <= *frequency && *frequency <= stop)
+            .collect::<Vec<_>>();
+        if grid.is_empty() {
+            return Err(TouchstoneError::InvalidFrequencyBand { start, stop });
+        }
+
+        let networks = networks
+            .iter()
+            .map(|network| {
+                network.resample(grid.iter().copied(), interpolation, Extrapolation::Error)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(networks)
+    }
+
+    /// Create a set from every Touchstone file in a directory.
+    ///
+    /// Files are selected the same way the `touchstone` command line tool selects them when
+    /// plotting a directory: regular files with an `.sNp` extension for a single-digit port
+    /// count, in any case. Subdirectories are not searched. Networks are ordered by file path
+    /// and must be compatible as described in [`new`](Self::new).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::NetworkSet;
+    ///
+    /// let set = NetworkSet::from_directory("files/test_plot_dir")?;
+    ///
+    /// assert_eq!(set.len(), 3);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn from_directory<P: AsRef<Path>>(path: P) -> Result<Self, TouchstoneError> {
+        let paths = file_operations::touchstone_files_in_directory(path.as_ref())?;
+
+        let networks = paths
+            .iter()
+            .map(Network::new)
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(networks)
+    }
+
+    /// Return the networks in the set.
+    #[must_use]
+    pub fn networks(&self) -> &[Network] {
+        &self.networks
+    }
+
+    /// Consume the set and return its networks.
+    #[must_use]
+    pub fn into_networks(self) -> Vec<Network> {
+        self.networks
+    }
+
+    /// Return the number of networks in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.networks.len()
+    }
+
+    /// Return whether the set contains no networks.
+    ///
+    /// Sets are never empty once created; this exists for API symmetry with
+    /// [`len`](Self::len).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.networks.is_empty()
+    }
+
+    /// Return the frequency grid in Hz shared by every network.
+    #[must_use]
+    pub fn frequencies(&self) -> &[f64] {
+        &self.networks[0].f
+    }
+
+    /// Return a network holding the mean of every S-parameter across the set.
+    ///
+    /// The mean is taken in real/imaginary space. Use [`statistics`](Self::statistics) for the
+    /// mean magnitude or dB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::NetworkSet;
+    ///
+    /// let set = NetworkSet::from_directory("files/test_plot_dir")?;
+    /// let mean = set.mean();
+    /// let first = set.networks()[0].try_s_ri_at(0, 2, 1)?;
+    ///
+    /// println!("S21 mean {:?}, first unit {:?}", mean.try_s_ri_at(0, 2, 1)?, first);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[must_use]
+    #[doc(alias = "average")]
+    pub fn mean(&self) -> Network {
+        self.combine("mean", mean)
+    }
+
+    /// Return a network holding the population standard deviation of every S-parameter.
+    ///
+    /// The real and imaginary parts are treated separately: each entry is
+    /// `std(Re S) + j·std(Im S)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::NetworkSet;
+    ///
+    /// let set = NetworkSet::from_directory("files/test_plot_dir")?;
+    /// let spread = set.std().try_s_ri_at(0, 2, 1)?;
+    ///
+    /// assert!(spread.re >= 0.0 && spread.im >= 0.0);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[must_use]
+    #[doc(alias = "standard deviation")]
+    pub fn std(&self) -> Network {
+        self.combine("std", standard_deviation)
+    }
+
+    /// Return a network holding the `percentile` of every S-parameter across the set.
+    ///
+    /// Real and imaginary parts are ranked separately. Percentiles between ranks are linearly
+    /// interpolated, so `percentile(50.0)` is the median. `percentile` must be in `0..=100`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::NetworkSet;
+    ///
+    /// let set = NetworkSet::from_directory("files/test_plot_dir")?;
+    /// let median = set.percentile(50.0)?;
+    ///
+    /// assert_eq!(median.f, set.frequencies());
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "median")]
+    pub fn percentile(&self, percentile: f64) -> Result<Network, TouchstoneError> {
+        validate_percentile(percentile)?;
+        Ok(self.combine(&format!("p{percentile}"), |values| {
+            percentile_of(values, percentile)
+        }))
+    }
+
+    /// Return the mean, standard deviation, and min/max envelope of `quantity` of S(j,k) across
+    /// the set at every frequency.
+    ///
+    /// The statistics are taken in the space of `quantity`, so [`Quantity::Decibel`] gives the
+    /// mean dB rather than the dB of the mean. Port indices `j` and `k` are 1-indexed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{NetworkSet, Quantity};
+    ///
+    /// let set = NetworkSet::from_directory("files/test_plot_dir")?;
+    /// let s21 = set.statistics(2, 1, Quantity::Decibel)?;
+    ///
+    /// println!("S21 at {} Hz: {} dB ± {} dB", s21[0].frequency, s21[0].mean, s21[0].std);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "envelope")]
+    pub fn statistics(
+        &self,
+        j: usize,
+        k: usize,
+        quantity: Quantity,
+    ) -> Result<Vec<FrequencyStatistics>, TouchstoneError> {
+        Ok(self
+            .quantity_columns(j, k, quantity)?
+            .into_iter()
+            .zip(self.frequencies())
+            .map(|(values, frequency)| FrequencyStatistics {
+                frequency: *frequency,
+                mean: mean(&values),
+                std: standard_deviation(&values),
+                min: values.iter().copied().fold(f64::INFINITY, f64::min),
+                max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            })
+            .collect())
+    }
+
+    /// Return the `percentile` of `quantity` of S(j,k) across the set at every frequency.
+    ///
+    /// Percentiles between ranks are linearly interpolated. `percentile` must be in `0..=100`,
+    /// and port indices `j` and `k` are 1-indexed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{NetworkSet, Quantity};
+    ///
+    /// let set = NetworkSet::from_directory("files/test_plot_dir")?;
+    /// let low = set.quantity_percentile(2, 1, Quantity::Magnitude, 10.0)?;
+    /// let high = set.quantity_percentile(2, 1, Quantity::Magnitude, 90.0)?;
+    ///
+    /// assert!(low[0].value <= high[0].value);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn quantity_percentile(
+        &self,
+        j: usize,
+        k: usize,
+        quantity: Quantity,
+        percentile: f64,
+    ) -> Result<Vec<FrequencyValue>, TouchstoneError> {
+        validate_percentile(percentile)?;
+        Ok(self
+            .quantity_columns(j, k, quantity)?
+            .into_iter()
+            .zip(self.frequencies())
+            .map(|(values, frequency)| FrequencyValue {
+                frequency: *frequency,
+                value: percentile_of(&values, percentile),
+            })
+            .collect())
+    }
+
+    /// Return `quantity` of S(j,k) grouped by frequency point, one value per network.
+    fn quantity_columns(
+        &self,
+        j: usize,
+        k: usize,
+        quantity: Quantity,
+    ) -> Result<Vec<Vec<f64>>, TouchstoneError> {
+        let rows = self
+            .networks
+            .iter()
+            .map(|network| network.quantity(j, k, quantity))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((0..self.frequencies().len())
+            .map(|point_index| rows.iter().map(|row| row[point_index].value).collect())
+            .collect())
+    }
+
+    /// Return a network whose S-parameters apply `statistic` to the real and imaginary parts of
+    /// each entry across the set.
+    fn combine(&self, label: &str, statistic: impl Fn(&[f64]) -> f64) -> Network {
+        let first = &self.networks[0];
+        let rank = first.rank as usize;
+
+        let mut network = first.clone();
+        network.name = format!("{label}.s{rank}p");
+        network.warnings.clear();
+        for (point_index, data_line) in network.s.iter_mut().enumerate() {
+            let mut matrix = SMatrix {
+                rank,
+                data: vec![vec![Complex::zero(); rank]; rank],
+            };
+            for to_port in 1..=rank {
+                for from_port in 1..=rank {
+                    let (re, im): (Vec<f64>, Vec<f64>) = self
+                        .networks
+                        .iter()
+                        .map(|network| {
+                            let value = network.s[point_index].s_ri.get(to_port, from_port);
+                            (value.0, value.1)
+                        })
+                        .unzip();
+                    matrix.data[to_port - 1][from_port - 1] = Complex {
+                        re: statistic(&re),
+                        im: statistic(&im),
+                    };
+                }
+            }
+            *data_line = parsed_data_line_from_matrix(first.f[point_index], &matrix);
+        }
+        network.comments.push(format!(
+            "! {label} of {} networks: {}",
+            self.networks.len(),
+            self.networks
+                .iter()
+                .map(|network| network.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        network
+    }
+}
+
+fn validate_percentile(percentile: f64) -> Result<(), TouchstoneError> {
+    if (0.0..=100.0).contains(&percentile) {
+        Ok(())
+    } else {
+        Err(TouchstoneError::InvalidPercentile { percentile })
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn standard_deviation(values: &[f64]) -> f64 {
+    let mean = mean(values);
+    (values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / values.len() as f64)
+        .sqrt()
+}
+
+/// Return the `percentile` of `values`, interpolating linearly between ranks.
+fn percentile_of(values: &[f64], percentile: f64) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let rank = percentile / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (rank - lower as f64) * (sorted[upper] - sorted[lower])
+}
@@ -283,7 +283,7 @@ pub fn parse_str(source_name: &str, contents: &str) -> Result<Network, Touchston
     let reference_impedance = parser_state
         .reference_impedance
         .unwrap_or(ReferenceImpedance::Common(option_line_z0));
-    let z0 = reference_impedance.scalar_compatibility_value();
+    let z0 = reference_impedance.scalar_compatibility_value()?;
 
     tracing::debug!(
         num_ports = n_ports,
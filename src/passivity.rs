@@ -0,0 +1,321 @@
+use crate::network_builder::parsed_data_line_from_matrix;
+use crate::{Complex, Network, SMatrix, TouchstoneError, TouchstoneWarning};
+
+/// Singular values within this distance of 1 are treated as passive, which absorbs the rounding
+/// of lossless networks stored with a limited number of digits.
+const PASSIVITY_TOLERANCE: f64 = 1.0e-6;
+
+const JACOBI_MAX_SWEEPS: usize = 64;
+
+/// Largest singular value of the S-matrix at a single frequency.
+///
+/// A network is passive at a frequency when no combination of incident waves can produce more
+/// reflected than incident power, i.e. when the largest singular value is at most 1.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::Network;
+///
+/// let net = Network::new("files/ntwk1.s2p").unwrap();
+/// let singular_values = net.passivity_violations().unwrap();
+/// let point = &singular_values[0];
+/// println!(
+///     "f = {} Hz, σmax = {}, passive = {}",
+///     point.frequency,
+///     point.max_singular_value,
+///     point.is_passive()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencySingularValue {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Largest singular value of the S-matrix.
+    pub max_singular_value: f64,
+}
+
+impl FrequencySingularValue {
+    /// Return whether the largest singular value is at most 1, within a tolerance of `1e-6`.
+    #[must_use]
+    pub fn is_passive(&self) -> bool {
+        self.max_singular_value <= 1.0 + PASSIVITY_TOLERANCE
+    }
+}
+
+impl Network {
+    /// Return the largest singular value of the S-matrix at every frequency.
+    ///
+    /// Points whose value exceeds 1 violate passivity; filter with
+    /// [`FrequencySingularValue::is_passive`] to list only the violations. The check assumes
+    /// power waves referenced to real impedances, which is how Touchstone data are normally
+    /// defined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let violations = net
+    ///     .passivity_violations()?
+    ///     .into_iter()
+    ///     .filter(|point| !point.is_passive())
+    ///     .count();
+    ///
+    /// assert_eq!(violations, 0);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "singular_value")]
+    pub fn passivity_violations(&self) -> Result<Vec<FrequencySingularValue>, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+
+        Ok(self
+            .points()?
+            .iter()
+            .map(|point| FrequencySingularValue {
+                frequency: point.frequency,
+                max_singular_value: max_singular_value(&point.s),
+            })
+            .collect())
+    }
+
+    /// Return a copy of the network with every non-passive point made passive.
+    ///
+    /// At each point whose largest singular value exceeds 1, the singular values above 1 are
+    /// clipped to 1. This is the smallest change to the S-matrix that restores passivity, and it
+    /// leaves the response to passive excitations untouched. Passive points are not modified.
+    /// Each modified point is recorded as a [`TouchstoneWarning::PassivityEnforced`] in
+    /// `warnings`, together with its original largest singular value and the clip target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::from_str("gain.s1p", "# GHz S RI R 50\n1.0 1.2 0.0\n2.0 0.5 0.0\n")?;
+    /// let passive = net.enforce_passivity()?;
+    ///
+    /// assert!(passive.passivity_violations()?.iter().all(|point| point.is_passive()));
+    /// assert_eq!(passive.warnings.len(), 1);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn enforce_passivity(&self) -> Result<Network, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+
+        let mut network = self.clone();
+        let mut enforced = 0;
+        for (point_index, (data_line, point)) in
+            network.s.iter_mut().zip(self.points()?).enumerate()
+        {
+            let max_singular_value = max_singular_value(&point.s);
+            if max_singular_value <= 1.0 + PASSIVITY_TOLERANCE {
+                continue;
+            }
+
+            *data_line =
+                parsed_data_line_from_matrix(point.frequency, &clip_singular_values(&point.s));
+            network.warnings.push(TouchstoneWarning::PassivityEnforced {
+                source_name: self.name.clone(),
+                point_index,
+                max_singular_value: format!("{max_singular_value:.6}"),
+                clipped_to: format!("{:.6}", 1.0),
+            });
+            enforced += 1;
+        }
+
+        if enforced > 0 {
+            network.comments.push(format!(
+                "! Passivity enforced at {enforced} frequency points"
+            ));
+        }
+        Ok(network)
+    }
+}
+
+fn max_singular_value(s: &SMatrix) -> f64 {
+    gram_eigen_decomposition(s)
+        .0
+        .into_iter()
+        .fold(0.0, f64::max)
+        .max(0.0)
+        .sqrt()
+}
+
+/// Replace every singular value above 1 with 1: `S − Σ (1 − 1/σᵢ)(S vᵢ) vᵢᴴ`.
+fn clip_singular_values(s: &SMatrix) -> SMatrix {
+    let rank = s.rank;
+    let mut clipped = s.clone();
+
+    for v in active_right_singular_vectors(s) {
+        let s_v = (0..rank)
+            .map(|row| {
+                (0..rank).fold(Complex::zero(), |sum, column| {
+                    sum + s.data[row][column] * v[column]
+                })
+            })
+            .collect::<Vec<_>>();
+        let sigma = s_v
+            .iter()
+            .map(|value| value.magnitude().powi(2))
+            .sum::<f64>()
+            .sqrt();
+        let shrink = 1.0 - 1.0 / sigma;
+
+        for (clipped_row, s_v_row) in clipped.data.iter_mut().zip(&s_v) {
+            for (value, v_column) in clipped_row.iter_mut().zip(&v) {
+                *value = *value - *s_v_row * v_column.conj() * shrink;
+            }
+        }
+    }
+
+    // Nearly degenerate singular values can leave a residual violation; scale it away.
+    let remaining = max_singular_value(&clipped);
+    if remaining > 1.0 + PASSIVITY_TOLERANCE {
+        for value in clipped.data.iter_mut().flatten() {
+            *value = *value / remaining;
+        }
+    }
+    clipped
+}
+
+/// Return orthonormal right singular vectors of `s` whose singular values exceed 1.
+fn active_right_singular_vectors(s: &SMatrix) -> Vec<Vec<Complex>> {
+    let rank = s.rank;
+    let (eigenvalues, eigenvectors) = gram_eigen_decomposition(s);
+
+    // Each eigenvalue of the real embedding appears twice, as `v` and `j·v`. Gram-Schmidt in
+    // complex space keeps one vector per complex dimension.
+    let mut candidates = (0..2 * rank)
+        .filter(|&index| eigenvalues[index] > (1.0 + PASSIVITY_TOLERANCE).powi(2))
+        .map(|index| {
+            (0..rank)
+                .map(|row| Complex {
+                    re: eigenvectors[row][index],
+                    im: eigenvectors[row + rank][index],
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut basis: Vec<Vec<Complex>> = Vec::new();
+
+    while !candidates.is_empty() {
+        let residuals = candidates
+            .iter()
+            .map(|candidate| {
+                basis.iter().fold(candidate.clone(), |residual, vector| {
+                    let projection = vector
+                        .iter()
+                        .zip(&residual)
+                        .fold(Complex::zero(), |sum, (b, r)| sum + b.conj() * *r);
+                    residual
+                        .iter()
+                        .zip(vector)
+                        .map(|(r, b)| *r - *b * projection)
+                        .collect()
+                })
+            })
+            .collect::<Vec<Vec<Complex>>>();
+        let norms = residuals
+            .iter()
+            .map(|residual| {
+                residual
+                    .iter()
+                    .map(|value| value.magnitude().powi(2))
+                    .sum::<f64>()
+                    .sqrt()
+            })
+            .collect::<Vec<_>>();
+        let (best, norm) = norms
+            .iter()
+            .copied()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .expect("candidates is not empty");
+        if norm < 1.0e-6 {
+            break;
+        }
+
+        basis.push(residuals[best].iter().map(|value| *value / norm).collect());
+        candidates.swap_remove(best);
+    }
+
+    basis
+}
+
+/// Eigen-decompose `SᴴS` through its real symmetric embedding `[[A, −B], [B, A]]`, where
+/// `SᴴS = A + jB`.
+///
+/// Returns the `2·rank` eigenvalues and the eigenvectors as matrix columns. Each eigenvector
+/// `[x; y]` corresponds to the complex eigenvector `x + j·y`.
+fn gram_eigen_decomposition(s: &SMatrix) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let rank = s.rank;
+    let mut embedding = vec![vec![0.0; 2 * rank]; 2 * rank];
+
+    for row in 0..rank {
+        for column in 0..rank {
+            let value = (0..rank).fold(Complex::zero(), |sum, inner| {
+                sum + s.data[inner][row].conj() * s.data[inner][column]
+            });
+            embedding[row][column] = value.re;
+            embedding[row + rank][column + rank] = value.re;
+            embedding[row + rank][column] = value.im;
+            embedding[row][column + rank] = -value.im;
+        }
+    }
+
+    symmetric_eigen_decomposition(embedding)
+}
+
+/// Cyclic Jacobi eigenvalue algorithm for a real symmetric matrix.
+fn symmetric_eigen_decomposition(mut matrix: Vec<Vec<f64>>) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let size = matrix.len();
+    let mut vectors = vec![vec![0.0; size]; size];
+    for (index, row) in vectors.iter_mut().enumerate() {
+        row[index] = 1.0;
+    }
+
+    let scale = matrix
+        .iter()
+        .flatten()
+        .map(|value| value * value)
+        .sum::<f64>()
+        .sqrt();
+    for _ in 0..JACOBI_MAX_SWEEPS {
+        let off_diagonal = (0..size)
+            .flat_map(|p| (0..size).filter(move |&q| q != p).map(move |q| (p, q)))
+            .map(|(p, q)| matrix[p][q] * matrix[p][q])
+            .sum::<f64>()
+            .sqrt();
+        if off_diagonal <= f64::EPSILON * scale {
+            break;
+        }
+
+        for p in 0..size {
+            for q in p + 1..size {
+                if matrix[p][q] == 0.0 {
+                    continue;
+                }
+                let theta = (matrix[q][q] - matrix[p][p]) / (2.0 * matrix[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for row in matrix.iter_mut().chain(vectors.iter_mut()) {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (upper, lower) = matrix.split_at_mut(q);
+                for (pk, qk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                    let (old_p, old_q) = (*pk, *qk);
+                    *pk = c * old_p - s * old_q;
+                    *qk = s * old_p + c * old_q;
+                }
+            }
+        }
+    }
+
+    let eigenvalues = (0..size).map(|index| matrix[index][index]).collect();
+    (eigenvalues, vectors)
+}
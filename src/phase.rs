@@ -0,0 +1,158 @@
+use std::f64::consts::PI;
+
+use crate::Network;
+
+/// Unit used for phase angles.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AngleUnit {
+    /// Degrees.
+    #[default]
+    Degrees,
+    /// Radians.
+    Radians,
+}
+
+/// Unwrapped S-parameter phase at a single frequency.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::{AngleUnit, Network};
+///
+/// let net = Network::new("files/ntwk1.s2p").unwrap();
+/// let s21_phase = net.s_phase_unwrapped(2, 1, AngleUnit::Degrees);
+/// let point = &s21_phase[0];
+/// println!("f = {} Hz, ∠S21 = {}°", point.frequency, point.phase);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrequencyPhase {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Unwrapped phase in the requested [`AngleUnit`].
+    pub phase: f64,
+}
+
+/// S-parameter group delay at a single frequency.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::Network;
+///
+/// let net = Network::new("files/ntwk1.s2p").unwrap();
+/// let s21_delay = net.group_delay(2, 1, 1);
+/// let point = &s21_delay[0];
+/// println!("f = {} Hz, group delay = {} s", point.frequency, point.group_delay);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrequencyGroupDelay {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Group delay in seconds.
+    pub group_delay: f64,
+}
+
+impl Network {
+    /// Return the unwrapped phase of S-parameter S(j,k) at all frequencies.
+    ///
+    /// Port indices `j` and `k` are 1-indexed. The first point keeps its principal-value angle
+    /// in the range (-180°, 180°], and each later point is shifted by whole turns so the step
+    /// from the previous point is at most half a turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{AngleUnit, Network};
+    ///
+    /// let net = Network::new("files/LFCN-2352+_Plus25degC.s2p").unwrap();
+    /// let phase = net.s_phase_unwrapped(2, 1, AngleUnit::Radians);
+    ///
+    /// assert_eq!(phase.len(), net.f.len());
+    /// assert!(phase
+    ///     .windows(2)
+    ///     .all(|pair| (pair[1].phase - pair[0].phase).abs() <= std::f64::consts::PI));
+    /// ```
+    #[must_use]
+    #[doc(alias = "unwrap")]
+    pub fn s_phase_unwrapped(&self, j: i8, k: i8, unit: AngleUnit) -> Vec<FrequencyPhase> {
+        self.unwrapped_phase_radians(j, k)
+            .into_iter()
+            .zip(&self.s)
+            .map(|(phase, data_line)| FrequencyPhase {
+                frequency: data_line.frequency,
+                phase: match unit {
+                    AngleUnit::Degrees => phase.to_degrees(),
+                    AngleUnit::Radians => phase,
+                },
+            })
+            .collect()
+    }
+
+    /// Return the group delay of S-parameter S(j,k) in seconds at all frequencies.
+    ///
+    /// Port indices `j` and `k` are 1-indexed. Group delay is `-dφ/dω`, estimated from the
+    /// unwrapped phase `aperture` points either side of each frequency. Larger apertures smooth
+    /// the result at the cost of frequency resolution. At the band edges the difference is taken
+    /// over the available one-sided points. An aperture of 0 is treated as 1, and networks with
+    /// fewer than two frequency points return `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/LFCN-2352+_Plus25degC.s2p").unwrap();
+    /// let fine = net.group_delay(2, 1, 1);
+    /// let smoothed = net.group_delay(2, 1, 5);
+    ///
+    /// assert_eq!(fine.len(), smoothed.len());
+    /// ```
+    #[must_use]
+    #[doc(alias = "delay")]
+    pub fn group_delay(&self, j: i8, k: i8, aperture: usize) -> Vec<FrequencyGroupDelay> {
+        let phase = self.unwrapped_phase_radians(j, k);
+        let aperture = aperture.max(1);
+        let last_index = phase.len().saturating_sub(1);
+
+        (0..phase.len())
+            .map(|index| {
+                let lower = index.saturating_sub(aperture);
+                let upper = (index + aperture).min(last_index);
+                let frequency_span = self.s[upper].frequency - self.s[lower].frequency;
+                let group_delay = if upper > lower {
+                    -(phase[upper] - phase[lower]) / (2.0 * PI * frequency_span)
+                } else {
+                    f64::NAN
+                };
+
+                FrequencyGroupDelay {
+                    frequency: self.s[index].frequency,
+                    group_delay,
+                }
+            })
+            .collect()
+    }
+
+    fn unwrapped_phase_radians(&self, j: i8, k: i8) -> Vec<f64> {
+        let mut unwrapped = Vec::with_capacity(self.s.len());
+        let mut previous: Option<(f64, f64)> = None;
+
+        for data_line in &self.s {
+            let value = data_line.s_ri.get(j as usize, k as usize);
+            let wrapped = value.1.atan2(value.0);
+            let phase = match previous {
+                Some((previous_wrapped, previous_phase)) => {
+                    let mut step = wrapped - previous_wrapped;
+                    step -= 2.0 * PI * (step / (2.0 * PI)).round();
+                    previous_phase + step
+                }
+                None => wrapped,
+            };
+            previous = Some((wrapped, phase));
+            unwrapped.push(phase);
+        }
+
+        unwrapped
+    }
+}
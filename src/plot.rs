@@ -1,5 +1,9 @@
 use std::{fs, path::Path};
 
+use crate::{
+    AngleUnit, Complex, NoiseParameters, ReflectionCircle, StabilityCircle, TouchstoneError,
+};
+
 // The paths are relative to this .rs file
 pub(crate) static PLOTLY_JS: &str = include_str!("assets/js/plotly-3.3.0.min.js");
 // pub (crate) static PLOTLY_SRC_LINE: &str = "./js/plotly-3.3.0.min.js";
@@ -32,6 +36,42 @@ pub(crate) fn write_plot_html(file_path: &str, html_content: &str) -> std::io::R
     Ok(())
 }
 
+/// Constant-gain and constant-noise-figure circles to overlay on the 2-port Smith charts.
+///
+/// Stability circles are always drawn; these circles are only drawn for the levels listed here.
+/// Like the stability circles, every overlay starts hidden and is enabled from the legend.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::{Complex, NoiseParameters, SmithChartCircles};
+///
+/// let circles = SmithChartCircles {
+///     available_gains_db: vec![10.0, 12.0],
+///     noise: vec![NoiseParameters {
+///         frequency: 1.0e9,
+///         min_noise_figure_db: 0.5,
+///         gamma_opt: Complex { re: 0.3, im: 0.2 },
+///         noise_resistance: 5.8,
+///     }],
+///     noise_figures_db: vec![1.0],
+///     ..SmithChartCircles::default()
+/// };
+///
+/// assert!(circles.operating_gains_db.is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SmithChartCircles {
+    /// Available gains in dB whose source-plane circles are drawn on the S11 chart.
+    pub available_gains_db: Vec<f64>,
+    /// Operating gains in dB whose load-plane circles are drawn on the S22 chart.
+    pub operating_gains_db: Vec<f64>,
+    /// Noise parameters at each frequency, used for the noise-figure circles.
+    pub noise: Vec<NoiseParameters>,
+    /// Noise figures in dB whose source-plane circles are drawn on the S11 chart.
+    pub noise_figures_db: Vec<f64>,
+}
+
 struct TwoPortPlotHtmlData<'a> {
     network_names: &'a [String],
     frequency_data: &'a [String],
@@ -41,6 +81,12 @@ struct TwoPortPlotHtmlData<'a> {
     s22_data: &'a [String],
     s11_complex_data: &'a [String],
     s22_complex_data: &'a [String],
+    s21_phase_data: &'a [String],
+    s21_group_delay_data: &'a [String],
+    source_stability_circle_data: &'a [String],
+    load_stability_circle_data: &'a [String],
+    source_circle_groups: &'a [String],
+    load_circle_groups: &'a [String],
 }
 
 fn generate_two_port_plot_html(
@@ -93,6 +139,30 @@ fn generate_two_port_plot_html(
         "{{ s22_complex_data }}",
         &format_js_data_array(data.s22_complex_data),
     );
+    html_content = html_content.replace(
+        "{{ s21_phase_data }}",
+        &format_js_data_array(data.s21_phase_data),
+    );
+    html_content = html_content.replace(
+        "{{ s21_group_delay_data }}",
+        &format_js_data_array(data.s21_group_delay_data),
+    );
+    html_content = html_content.replace(
+        "{{ source_stability_circle_data }}",
+        &format_js_data_array(data.source_stability_circle_data),
+    );
+    html_content = html_content.replace(
+        "{{ load_stability_circle_data }}",
+        &format_js_data_array(data.load_stability_circle_data),
+    );
+    html_content = html_content.replace(
+        "{{ source_circle_groups }}",
+        &format_js_data_array(data.source_circle_groups),
+    );
+    html_content = html_content.replace(
+        "{{ load_circle_groups }}",
+        &format_js_data_array(data.load_circle_groups),
+    );
 
     write_plot_html(output_path, &html_content)?;
 
@@ -168,9 +238,77 @@ pub fn generate_one_port_plot_html(
     Ok(())
 }
 
+/// Format `(frequency, center, radius)` circles as a JS array of `{ f, cx, cy, r }`, dropping
+/// degenerate (line) circles and circles that do not exist.
+fn format_circles(circles: impl Iterator<Item = (f64, Complex, f64)>) -> String {
+    let circles = circles
+        .filter(|(_, center, radius)| center.is_finite() && radius.is_finite())
+        .map(|(frequency, center, radius)| {
+            format!(
+                "{{ f: {}, cx: {}, cy: {}, r: {} }}",
+                frequency, center.re, center.im, radius
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+    format!("[{}]", circles)
+}
+
+fn format_stability_circles(circles: &[StabilityCircle]) -> String {
+    format_circles(
+        circles
+            .iter()
+            .map(|circle| (circle.frequency, circle.center, circle.radius)),
+    )
+}
+
+/// Format a labelled set of circles as a JS `{ label, circles }` object.
+fn format_circle_group(label: &str, circles: &[ReflectionCircle]) -> String {
+    format!(
+        "{{ label: '{}', circles: {} }}",
+        label,
+        format_circles(circles.iter().map(|circle| (
+            circle.frequency,
+            circle.center,
+            circle.radius
+        )))
+    )
+}
+
+/// Return the computed circles, or log why `circles` could not be drawn for `network_name` and
+/// return none so the rest of the plot is still generated.
+fn circles_or_warn<T>(
+    circles: Result<Vec<T>, TouchstoneError>,
+    network_name: &str,
+    label: &str,
+) -> Vec<T> {
+    circles.unwrap_or_else(|error| {
+        tracing::warn!(network = network_name, label, %error, "Skipping Smith chart circles");
+        Vec::new()
+    })
+}
+
+/// Generate an interactive HTML plot of 1-port or 2-port networks at `output_path`.
+///
+/// All networks must have the same rank. The plot's JavaScript assets are written to a `js/`
+/// folder next to the HTML file. 2-port plots overlay the stability circles on the Smith charts;
+/// use [`generate_plot_with_circles()`] to add gain and noise-figure circles.
 pub fn generate_plot_from_networks(
     networks: &[crate::Network],
     output_path: &str,
+) -> std::io::Result<()> {
+    generate_plot_with_circles(networks, output_path, &SmithChartCircles::default())
+}
+
+/// Generate an interactive HTML plot like [`generate_plot_from_networks()`], overlaying the
+/// gain and noise-figure circles listed in `circles` on the 2-port Smith charts.
+///
+/// Circles are computed for each network. `circles` is ignored for 1-port networks. Circles that
+/// cannot be computed for a network are logged as warnings and left out of the plot.
+pub fn generate_plot_with_circles(
+    networks: &[crate::Network],
+    output_path: &str,
+    circles: &SmithChartCircles,
 ) -> std::io::Result<()> {
     // Check if all networks have the same rank
     if networks.is_empty() {
@@ -246,6 +384,12 @@ pub fn generate_plot_from_networks(
             let mut s22_data_list = Vec::new();
             let mut s11_complex_data_list = Vec::new();
             let mut s22_complex_data_list = Vec::new();
+            let mut s21_phase_data_list = Vec::new();
+            let mut s21_group_delay_data_list = Vec::new();
+            let mut source_stability_circle_data_list = Vec::new();
+            let mut load_stability_circle_data_list = Vec::new();
+            let mut source_circle_groups_list = Vec::new();
+            let mut load_circle_groups_list = Vec::new();
 
             for network in networks {
                 network_names.push(network.name.clone());
@@ -305,6 +449,83 @@ pub fn generate_plot_from_networks(
                     .collect::<Vec<String>>()
                     .join(", ");
                 s22_complex_data_list.push(format!("[{}]", s22_complex));
+
+                let s21_phase = network
+                    .s_phase_unwrapped(2, 1, AngleUnit::Degrees)
+                    .iter()
+                    .map(|s| s.phase.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                s21_phase_data_list.push(format!("[{}]", s21_phase));
+
+                // JSON has no NaN literal; emit null so Plotly leaves a gap.
+                let s21_group_delay = network
+                    .group_delay(2, 1, 1)
+                    .iter()
+                    .map(|s| {
+                        if s.group_delay.is_finite() {
+                            s.group_delay.to_string()
+                        } else {
+                            "null".to_string()
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                s21_group_delay_data_list.push(format!("[{}]", s21_group_delay));
+
+                source_stability_circle_data_list.push(format_stability_circles(&circles_or_warn(
+                    network.input_stability_circles(),
+                    &network.name,
+                    "input stability",
+                )));
+                load_stability_circle_data_list.push(format_stability_circles(&circles_or_warn(
+                    network.output_stability_circles(),
+                    &network.name,
+                    "output stability",
+                )));
+
+                let available_gain_groups = circles.available_gains_db.iter().map(|gain_db| {
+                    let label = format!("G_A {gain_db} dB");
+                    let group = circles_or_warn(
+                        network.available_gain_circles(*gain_db),
+                        &network.name,
+                        &label,
+                    );
+                    format_circle_group(&label, &group)
+                });
+                let noise_groups = circles.noise_figures_db.iter().map(|noise_figure_db| {
+                    let label = format!("NF {noise_figure_db} dB");
+                    let group = circles_or_warn(
+                        network.noise_circles(&circles.noise, *noise_figure_db),
+                        &network.name,
+                        &label,
+                    );
+                    format_circle_group(&label, &group)
+                });
+                source_circle_groups_list.push(format!(
+                    "[{}]",
+                    available_gain_groups
+                        .chain(noise_groups)
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ));
+                load_circle_groups_list.push(format!(
+                    "[{}]",
+                    circles
+                        .operating_gains_db
+                        .iter()
+                        .map(|gain_db| {
+                            let label = format!("G_P {gain_db} dB");
+                            let group = circles_or_warn(
+                                network.operating_gain_circles(*gain_db),
+                                &network.name,
+                                &label,
+                            );
+                            format_circle_group(&label, &group)
+                        })
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ));
             }
 
             generate_two_port_plot_html(
@@ -318,6 +539,12 @@ pub fn generate_plot_from_networks(
                     s22_data: &s22_data_list,
                     s11_complex_data: &s11_complex_data_list,
                     s22_complex_data: &s22_complex_data_list,
+                    s21_phase_data: &s21_phase_data_list,
+                    s21_group_delay_data: &s21_group_delay_data_list,
+                    source_stability_circle_data: &source_stability_circle_data_list,
+                    load_stability_circle_data: &load_stability_circle_data_list,
+                    source_circle_groups: &source_circle_groups_list,
+                    load_circle_groups: &load_circle_groups_list,
                 },
             )
         }
@@ -518,11 +745,64 @@ mod tests {
         assert!(!html.contains("{{ s22_complex_data }}"));
         assert!(html.contains("Plotly.newPlot('plot-smith-s11', createSmithTraces(s11ComplexData"));
         assert!(html.contains("Plotly.newPlot('plot-smith-s22', createSmithTraces(s22ComplexData"));
+        let card_s21_phase = html.find("id=\"card-s21-phase\"").unwrap();
+        let card_s21_group_delay = html.find("id=\"card-s21-group-delay\"").unwrap();
+        assert!(card_smith_s22 < card_s21_phase);
+        assert!(card_s21_phase < card_s21_group_delay);
+
+        let phase_card = &html[card_s21_phase..card_s21_group_delay];
+        assert!(phase_card.contains("S21 Phase (Unwrapped)"));
+        assert!(phase_card.contains("downloadCSV('plot-s21-phase', 's21_phase_data')"));
+        assert!(html.contains("downloadCSV('plot-s21-group-delay', 's21_group_delay_data')"));
+        assert!(!html.contains("{{ s21_phase_data }}"));
+        assert!(!html.contains("{{ s21_group_delay_data }}"));
+        assert!(
+            html.contains("Plotly.newPlot('plot-s21-phase', createTraces(freqData, s21PhaseData")
+        );
+        assert!(html.contains(
+            "Plotly.newPlot('plot-s21-group-delay', createTraces(freqData, s21GroupDelayData"
+        ));
+        assert!(html.contains("const sourceStabilityCircleData = [[{ f:"));
+        assert!(html.contains("const loadStabilityCircleData = [[{ f:"));
+        assert!(!html.contains("{{ source_stability_circle_data }}"));
+        assert!(!html.contains("{{ load_stability_circle_data }}"));
+        assert!(html.contains("sourceStabilityCircleData, 'Input Stability', sourceCircleGroups)"));
+        assert!(html.contains("loadStabilityCircleData, 'Output Stability', loadCircleGroups)"));
+        assert!(html.contains("const sourceCircleGroups = [[], []];"));
+        assert!(html.contains("const loadCircleGroups = [[], []];"));
         assert!(html.contains("function constrainYAxisRange(plotId, range)"));
         assert!(html.contains("min = Math.min(min, 0);"));
         assert!(html.contains("if (min > 0) min = 0;"));
     }
 
+    #[test]
+    fn test_generate_plot_with_gain_and_noise_circles() {
+        let network = Network::new("files/fet.s2p").unwrap();
+        let test_dir = setup_test_dir("test_plot_circles");
+        let output_path = test_dir.join("circles.html");
+        let circles = SmithChartCircles {
+            available_gains_db: vec![10.0],
+            operating_gains_db: vec![8.0, 9.0],
+            noise: vec![NoiseParameters {
+                frequency: network.f[0],
+                min_noise_figure_db: 0.5,
+                gamma_opt: Complex { re: 0.3, im: 0.2 },
+                noise_resistance: 5.8,
+            }],
+            noise_figures_db: vec![1.0],
+        };
+
+        generate_plot_with_circles(&[network], output_path.to_str().unwrap(), &circles).unwrap();
+
+        let html = fs::read_to_string(&output_path).unwrap();
+        assert!(html.contains("const sourceCircleGroups = [[{ label: 'G_A 10 dB', circles: [{ f:"));
+        assert!(html.contains("{ label: 'NF 1 dB', circles: [{ f:"));
+        assert!(html.contains("const loadCircleGroups = [[{ label: 'G_P 8 dB', circles: [{ f:"));
+        assert!(html.contains("{ label: 'G_P 9 dB', circles: ["));
+        assert!(!html.contains("{{ source_circle_groups }}"));
+        assert!(!html.contains("{{ load_circle_groups }}"));
+    }
+
     #[test]
     fn test_generate_two_port_plot_html() {
         let test_dir = setup_test_dir("test_generate_two_port_plot_html");
@@ -0,0 +1,165 @@
+use std::f64::consts::PI;
+
+use crate::network_builder::parsed_data_line_from_matrix;
+use crate::ports::validate_port;
+use crate::{Complex, Network, TouchstoneError};
+
+/// One-way loss of the line added by [`Network::extend_port`] or removed by
+/// [`Network::remove_port_extension`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[non_exhaustive]
+pub enum LossModel {
+    /// The line only delays the signal.
+    #[default]
+    Lossless,
+    /// The same loss in dB at every frequency.
+    Constant {
+        /// One-way loss in dB.
+        db: f64,
+    },
+    /// Skin-effect loss that grows with the square root of frequency,
+    /// `db·√(f / reference_frequency)`.
+    SquareRootFrequency {
+        /// One-way loss in dB at `reference_frequency`.
+        db: f64,
+        /// Frequency in Hz at which the loss equals `db`.
+        reference_frequency: f64,
+    },
+}
+
+impl LossModel {
+    fn validate(self) -> Result<(), TouchstoneError> {
+        let invalid = |setting: &str, value: f64| TouchstoneError::InvalidPortExtension {
+            setting: setting.to_string(),
+            value,
+        };
+        match self {
+            Self::Lossless => Ok(()),
+            Self::Constant { db } | Self::SquareRootFrequency { db, .. }
+                if !db.is_finite() || db < 0.0 =>
+            {
+                Err(invalid("loss", db))
+            }
+            Self::SquareRootFrequency {
+                reference_frequency,
+                ..
+            } if !reference_frequency.is_finite() || reference_frequency <= 0.0 => {
+                Err(invalid("loss reference frequency", reference_frequency))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Return the one-way loss in dB at `frequency` in Hz.
+    fn db_at(self, frequency: f64) -> f64 {
+        match self {
+            Self::Lossless => 0.0,
+            Self::Constant { db } => db,
+            Self::SquareRootFrequency {
+                db,
+                reference_frequency,
+            } => db * (frequency / reference_frequency).sqrt(),
+        }
+    }
+}
+
+impl Network {
+    /// Move the reference plane of `port` away from the network through a matched line.
+    ///
+    /// The line delays waves by `delay_seconds` each way and attenuates them by `loss_model`, so
+    /// every entry in row and column `port` is multiplied by `10^(-L/20)·e^(−j·2π·f·τ)` and
+    /// S(port, port) by its square. Reference impedances are unchanged. Call this once per port to
+    /// extend several ports.
+    ///
+    /// `delay_seconds` must be finite and non-negative; use
+    /// [`remove_port_extension()`](Network::remove_port_extension) to move the plane toward the
+    /// network instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{LossModel, Network};
+    ///
+    /// let net = Network::from_str("load.s1p", "# GHz S RI R 50\n1.0 0.5 0.0\n")?;
+    ///
+    /// // A 250 ps line is a quarter wave at 1 GHz, so the round trip flips the reflection.
+    /// let extended = net.extend_port(1, 250.0e-12, LossModel::Lossless)?;
+    ///
+    /// assert!((extended.try_s_ri_at(0, 1, 1)?.re + 0.5).abs() < 1e-12);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "port extension")]
+    #[doc(alias = "reference plane")]
+    #[doc(alias = "electrical length")]
+    pub fn extend_port(
+        &self,
+        port: usize,
+        delay_seconds: f64,
+        loss_model: LossModel,
+    ) -> Result<Network, TouchstoneError> {
+        self.shift_reference_plane(port, delay_seconds, loss_model, 1.0)
+    }
+
+    /// Move the reference plane of `port` toward the network by removing a matched line, such as
+    /// a fixture of known length.
+    ///
+    /// This undoes [`extend_port()`](Network::extend_port) with the same arguments: row and column
+    /// `port` are multiplied by `10^(L/20)·e^(j·2π·f·τ)`, which acts as a negative delay.
+    #[doc(alias = "de-embed")]
+    #[doc(alias = "fixture removal")]
+    pub fn remove_port_extension(
+        &self,
+        port: usize,
+        delay_seconds: f64,
+        loss_model: LossModel,
+    ) -> Result<Network, TouchstoneError> {
+        self.shift_reference_plane(port, delay_seconds, loss_model, -1.0)
+    }
+
+    /// Scale row and column `port` by the line response, raised to `direction` (`1.0` to add the
+    /// line and `-1.0` to remove it).
+    fn shift_reference_plane(
+        &self,
+        port: usize,
+        delay_seconds: f64,
+        loss_model: LossModel,
+        direction: f64,
+    ) -> Result<Network, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+        let rank = self.rank as usize;
+        validate_port(port, rank)?;
+        if !delay_seconds.is_finite() || delay_seconds < 0.0 {
+            return Err(TouchstoneError::InvalidPortExtension {
+                setting: "delay".to_string(),
+                value: delay_seconds,
+            });
+        }
+        loss_model.validate()?;
+
+        let k = port - 1;
+        let mut network = self.clone();
+        for (point_index, data_line) in network.s.iter_mut().enumerate() {
+            let frequency = data_line.frequency;
+            let magnitude = 10.0_f64.powf(-direction * loss_model.db_at(frequency) / 20.0);
+            let angle = -direction * 2.0 * PI * frequency * delay_seconds;
+            let line = Complex {
+                re: magnitude * angle.cos(),
+                im: magnitude * angle.sin(),
+            };
+
+            let mut s = self.s_matrix_at(point_index)?;
+            for (row, values) in s.data.iter_mut().enumerate() {
+                for (column, value) in values.iter_mut().enumerate() {
+                    if row == k {
+                        *value = *value * line;
+                    }
+                    if column == k {
+                        *value = *value * line;
+                    }
+                }
+            }
+            *data_line = parsed_data_line_from_matrix(frequency, &s);
+        }
+        Ok(network)
+    }
+}
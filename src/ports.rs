@@ -0,0 +1,381 @@
+use crate::data_line;
+use crate::data_pairs::{RealImaginary, RealImaginaryMatrix};
+use crate::network_builder::parsed_data_line_from_matrix;
+use crate::{
+    Complex, Extrapolation, FrequencyRI, Interpolation, Network, ParameterMatrix,
+    ReferenceImpedance, SMatrix, TouchstoneError, WaveDefinition, PARAMETER_CONVERSION_TOLERANCE,
+};
+
+/// Load connected to a port by [`Network::terminate`].
+///
+/// Reflection coefficients and impedances are applied at every frequency point. Power-wave
+/// definitions are used when a load has to be referred to the terminated port's reference
+/// impedance; for real reference impedances power and pseudo waves give the same result.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum Termination<'a> {
+    /// Reflection coefficient referenced to the terminated port's reference impedance.
+    Reflection(Complex),
+    /// Load impedance in ohms. Use [`Termination::Reflection`] for an ideal open circuit.
+    Impedance(Complex),
+    /// 1-port S-parameter network, linearly interpolated onto the terminated network's frequency
+    /// grid and renormalized to the terminated port's reference impedance.
+    Network(&'a Network),
+}
+
+impl Network {
+    /// Extract a sub-network containing only the listed ports.
+    ///
+    /// `ports` uses 1-based RF port indexes. The order of `ports` defines the port order of the
+    /// returned network, so `subnetwork(&[3, 1])` returns a 2-port whose port 1 is the original
+    /// port 3. Per-port reference impedances are sliced the same way. The remaining ports are
+    /// dropped as if they were terminated in their reference impedances, so the network must
+    /// contain S-parameter data.
+    ///
+    /// Returns [`TouchstoneError::EmptyPortList`] when `ports` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let network = Network::new("files/Agilent_E5071B.s4p")?;
+    /// let path = network.subnetwork(&[1, 3])?;
+    ///
+    /// assert_eq!(path.rank, 2);
+    /// assert_eq!(path.try_s_ri_at(0, 2, 1)?, network.try_s_ri_at(0, 3, 1)?);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "extract ports")]
+    pub fn subnetwork(&self, ports: &[usize]) -> Result<Network, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+        let rank = self.rank as usize;
+        if ports.is_empty() {
+            return Err(TouchstoneError::EmptyPortList);
+        }
+
+        for (index, port) in ports.iter().copied().enumerate() {
+            validate_port(port, rank)?;
+            if ports[..index].contains(&port) {
+                return Err(TouchstoneError::DuplicatePort { port });
+            }
+        }
+
+        self.select_ports(ports)
+    }
+
+    /// Return a copy of this network with ports renumbered.
+    ///
+    /// Port `from[i]` of this network becomes port `to[i]` of the returned network. `from` and
+    /// `to` must contain the same set of 1-based port indexes, and ports not listed keep their
+    /// numbers. Per-port reference impedances move with their ports.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let network = Network::new("files/ntwk1.s2p")?;
+    /// let flipped = network.renumber(&[1, 2], &[2, 1])?;
+    ///
+    /// assert_eq!(flipped.try_s_ri_at(0, 1, 1)?, network.try_s_ri_at(0, 2, 2)?);
+    /// assert_eq!(flipped.try_s_ri_at(0, 2, 1)?, network.try_s_ri_at(0, 1, 2)?);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "permute ports")]
+    #[doc(alias = "flip")]
+    pub fn renumber(&self, from: &[usize], to: &[usize]) -> Result<Network, TouchstoneError> {
+        let rank = self.rank as usize;
+
+        let mut sorted_from = from.to_vec();
+        let mut sorted_to = to.to_vec();
+        sorted_from.sort_unstable();
+        sorted_to.sort_unstable();
+        if from.len() != to.len() || sorted_from != sorted_to {
+            return Err(TouchstoneError::InvalidPortRenumbering {
+                from: from.to_vec(),
+                to: to.to_vec(),
+            });
+        }
+
+        for (index, port) in sorted_from.iter().copied().enumerate() {
+            validate_port(port, rank)?;
+            if index > 0 && sorted_from[index - 1] == port {
+                return Err(TouchstoneError::DuplicatePort { port });
+            }
+        }
+
+        // order[new_port - 1] is the original port that lands on new_port.
+        let mut order = (1..=rank).collect::<Vec<_>>();
+        for (from_port, to_port) in from.iter().zip(to) {
+            order[to_port - 1] = *from_port;
+        }
+
+        self.select_ports(&order)
+    }
+
+    /// Terminate one port in a load and return the remaining reduced-rank network.
+    ///
+    /// `port` uses a 1-based RF port index. The remaining ports keep their relative order and
+    /// reference impedances, so terminating port 2 of a 3-port returns a 2-port whose port 2 is
+    /// the original port 3. With load reflection `G` at port `k`, each remaining entry becomes
+    /// `S'ij = Sij + Sik G Skj / (1 - Skk G)`.
+    ///
+    /// A [`Termination::Network`] load must be a 1-port covering the frequency range of this
+    /// network; frequencies outside its range return [`TouchstoneError::FrequencyOutOfRange`].
+    /// Terminating a 1-port returns [`TouchstoneError::CannotTerminateOnlyPort`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Complex, Network, Termination};
+    ///
+    /// let network = Network::new("files/hfss_18.2.s3p")?;
+    /// let matched = network.terminate(3, Termination::Reflection(Complex { re: 0.0, im: 0.0 }))?;
+    ///
+    /// assert_eq!(matched.rank, 2);
+    /// assert_eq!(matched.try_s_ri_at(0, 2, 1)?, network.try_s_ri_at(0, 2, 1)?);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "load")]
+    pub fn terminate(
+        &self,
+        port: usize,
+        load: Termination<'_>,
+    ) -> Result<Network, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+        let rank = self.rank as usize;
+        validate_port(port, rank)?;
+        if rank == 1 {
+            return Err(TouchstoneError::CannotTerminateOnlyPort);
+        }
+
+        let reflections = self.load_reflections(port, load)?;
+        let remaining = (1..=rank).filter(|p| *p != port).collect::<Vec<_>>();
+        let mut reduced = self.select_ports(&remaining)?;
+        let k = port - 1;
+
+        reduced.s = self
+            .s
+            .iter()
+            .enumerate()
+            .map(|(point_index, data_line)| {
+                let gamma = reflections[point_index];
+                let s = self.s_matrix_at(point_index)?;
+                let denominator = Complex::one() - s.data[k][k] * gamma;
+                crate::ensure_non_singular_value(
+                    "port termination",
+                    k,
+                    denominator,
+                    PARAMETER_CONVERSION_TOLERANCE,
+                )?;
+
+                let data = remaining
+                    .iter()
+                    .map(|to_port| {
+                        remaining
+                            .iter()
+                            .map(|from_port| {
+                                let (i, j) = (to_port - 1, from_port - 1);
+                                s.data[i][j] + s.data[i][k] * gamma * s.data[k][j] / denominator
+                            })
+                            .collect()
+                    })
+                    .collect();
+                Ok(parsed_data_line_from_matrix(
+                    data_line.frequency,
+                    &SMatrix {
+                        rank: remaining.len(),
+                        data,
+                    },
+                ))
+            })
+            .collect::<Result<Vec<_>, TouchstoneError>>()?;
+
+        Ok(reduced)
+    }
+
+    /// Return the reflection coefficient looking into one port of a 2-port while the other port
+    /// is terminated in `load`.
+    ///
+    /// `port` is the 1-based input port. Values are referenced to that port's reference impedance
+    /// and are returned at every frequency of this network.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Complex, Network, Termination};
+    ///
+    /// let through = Network::from_str(
+    ///     "thru.s2p",
+    ///     "# GHz S RI R 50\n1.0 0.0 0.0 1.0 0.0 1.0 0.0 0.0 0.0\n",
+    /// )?;
+    /// let load = Termination::Impedance(Complex { re: 75.0, im: 0.0 });
+    /// let gamma_in = through.input_reflection(1, load)?;
+    ///
+    /// assert!((gamma_in[0].s_ri.0 - 0.2).abs() < 1e-12);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "gamma in")]
+    pub fn input_reflection(
+        &self,
+        port: usize,
+        load: Termination<'_>,
+    ) -> Result<Vec<FrequencyRI>, TouchstoneError> {
+        if self.rank != 2 {
+            return Err(TouchstoneError::InvalidNetworkRank {
+                rank: self.rank as usize,
+            });
+        }
+        validate_port(port, 2)?;
+
+        Ok(self.terminate(3 - port, load)?.s_ri(1, 1))
+    }
+
+    /// Resolve a termination to a reflection coefficient at every frequency point, referenced to
+    /// the reference impedance of `port`.
+    fn load_reflections(
+        &self,
+        port: usize,
+        load: Termination<'_>,
+    ) -> Result<Vec<Complex>, TouchstoneError> {
+        let port_references = (0..self.s.len())
+            .map(|point_index| Ok(self.reference_impedance_at(point_index)?[port - 1]))
+            .collect::<Result<Vec<_>, TouchstoneError>>()?;
+
+        match load {
+            Termination::Reflection(gamma) => Ok(vec![gamma; port_references.len()]),
+            Termination::Impedance(z) => port_references
+                .iter()
+                .map(|reference| {
+                    let load = ParameterMatrix {
+                        rank: 1,
+                        data: vec![vec![z]],
+                    };
+                    Ok(SMatrix::try_from_z_matrix_with_reference(
+                        &load,
+                        &[*reference],
+                        WaveDefinition::Power,
+                    )?
+                    .data[0][0])
+                })
+                .collect(),
+            Termination::Network(network) => {
+                if network.rank != 1 {
+                    return Err(TouchstoneError::InvalidNetworkRank {
+                        rank: network.rank as usize,
+                    });
+                }
+                network.ensure_s_parameter_data()?;
+
+                let resampled = network.resample(
+                    self.f.iter().copied(),
+                    Interpolation::Linear,
+                    Extrapolation::Error,
+                )?;
+                port_references
+                    .iter()
+                    .enumerate()
+                    .map(|(point_index, reference)| {
+                        Ok(resampled
+                            .s_matrix_at(point_index)?
+                            .renormalize(
+                                &resampled.reference_impedance_at(point_index)?,
+                                &[*reference],
+                                WaveDefinition::Power,
+                            )?
+                            .data[0][0])
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn select_ports(&self, ports: &[usize]) -> Result<Network, TouchstoneError> {
+        let reference_impedance = self.reference_impedance();
+        reference_impedance.validate_shape(self.rank as usize, self.s.len())?;
+        let reference_impedance =
+            select_reference_impedance(&reference_impedance, ports, self.rank as usize)?;
+
+        let s = self
+            .s
+            .iter()
+            .map(|data_line| {
+                let data = ports
+                    .iter()
+                    .map(|to_port| {
+                        ports
+                            .iter()
+                            .map(|from_port| data_line.s_ri.get(*to_port, *from_port))
+                            .collect::<Vec<RealImaginary>>()
+                    })
+                    .collect::<Vec<_>>();
+                data_line::parsed_data_line_from_ri_matrix(
+                    data_line.frequency,
+                    RealImaginaryMatrix::from_vec(data),
+                )
+            })
+            .collect();
+
+        Ok(Network {
+            name: self.name.clone(),
+            rank: ports.len() as i32,
+            frequency_unit: self.frequency_unit.clone(),
+            parameter: self.parameter.clone(),
+            format: self.format.clone(),
+            resistance_string: self.resistance_string.clone(),
+            z0: reference_impedance.scalar_compatibility_value()?,
+            reference_impedance,
+            comments: self.comments.clone(),
+            comments_after_option_line: self.comments_after_option_line.clone(),
+            warnings: self.warnings.clone(),
+            f: self.f.clone(),
+            s,
+        })
+    }
+}
+
+pub(crate) fn validate_port(port: usize, rank: usize) -> Result<(), TouchstoneError> {
+    if port == 0 || port > rank {
+        Err(TouchstoneError::InvalidPort { port, rank })
+    } else {
+        Ok(())
+    }
+}
+
+fn select_reference_impedance(
+    reference_impedance: &ReferenceImpedance,
+    ports: &[usize],
+    rank: usize,
+) -> Result<ReferenceImpedance, TouchstoneError> {
+    fn select<T: Copy>(
+        values: &[T],
+        ports: &[usize],
+        rank: usize,
+    ) -> Result<Vec<T>, TouchstoneError> {
+        if values.len() != rank {
+            return Err(TouchstoneError::InvalidReferenceImpedanceCount {
+                ports: rank,
+                actual: values.len(),
+            });
+        }
+
+        Ok(ports.iter().map(|port| values[port - 1]).collect())
+    }
+
+    Ok(match reference_impedance {
+        ReferenceImpedance::Common(z0) => ReferenceImpedance::Common(*z0),
+        ReferenceImpedance::PerPort(values) => {
+            ReferenceImpedance::PerPort(select(values, ports, rank)?)
+        }
+        ReferenceImpedance::PerPortComplex(values) => {
+            ReferenceImpedance::PerPortComplex(select(values, ports, rank)?)
+        }
+        ReferenceImpedance::FrequencyDependent(values) => ReferenceImpedance::FrequencyDependent(
+            values
+                .iter()
+                .map(|point_values| select(point_values, ports, rank))
+                .collect::<Result<_, _>>()?,
+        ),
+    })
+}
@@ -0,0 +1,210 @@
+use crate::ports::validate_port;
+use crate::{Complex, Network, TouchstoneError};
+
+/// Voltage standing wave ratio at a single frequency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyVswr {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// `(1 + |Γ|) / (1 − |Γ|)`. Infinite for total reflection.
+    pub vswr: f64,
+}
+
+/// Loss in positive dB at a single frequency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyLoss {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Loss in dB; larger values mean less power reaches its destination.
+    pub loss_db: f64,
+}
+
+/// Complex impedance in ohms at a single frequency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyImpedance {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Impedance in ohms.
+    pub impedance: Complex,
+}
+
+/// Complex admittance in siemens at a single frequency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyAdmittance {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Admittance in siemens.
+    pub admittance: Complex,
+}
+
+impl Network {
+    /// Return the VSWR at `port` at every frequency, with every other port matched.
+    ///
+    /// `port` is 1-indexed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::from_str("load.s1p", "# GHz S RI R 50\n1.0 0.5 0.0\n")?;
+    ///
+    /// assert!((net.vswr(1)?[0].vswr - 3.0).abs() < 1e-12);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn vswr(&self, port: usize) -> Result<Vec<FrequencyVswr>, TouchstoneError> {
+        Ok(self
+            .port_reflections(port)?
+            .into_iter()
+            .map(|(frequency, gamma)| {
+                let magnitude = gamma.magnitude();
+                FrequencyVswr {
+                    frequency,
+                    vswr: (1.0 + magnitude) / (1.0 - magnitude),
+                }
+            })
+            .collect())
+    }
+
+    /// Return the return loss `−20·log10|S(port,port)|` at every frequency.
+    ///
+    /// The result is positive for passive ports, unlike [`s_db`](Self::s_db). `port` is
+    /// 1-indexed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let return_loss = net.return_loss(1)?;
+    ///
+    /// assert!((return_loss[0].loss_db + net.s_db(1, 1)[0].s_db.decibel()).abs() < 1e-9);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn return_loss(&self, port: usize) -> Result<Vec<FrequencyLoss>, TouchstoneError> {
+        Ok(self
+            .port_reflections(port)?
+            .into_iter()
+            .map(|(frequency, gamma)| FrequencyLoss {
+                frequency,
+                loss_db: -20.0 * gamma.magnitude().log10(),
+            })
+            .collect())
+    }
+
+    /// Return the mismatch loss `−10·log10(1 − |S(port,port)|²)` at every frequency.
+    ///
+    /// This is the power lost to reflection at `port` compared with a matched port. `port` is
+    /// 1-indexed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::from_str("load.s1p", "# GHz S RI R 50\n1.0 0.5 0.0\n")?;
+    /// let mismatch_loss = net.mismatch_loss(1)?;
+    ///
+    /// // A quarter of the power is reflected.
+    /// assert!((mismatch_loss[0].loss_db + 10.0 * 0.75_f64.log10()).abs() < 1e-12);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn mismatch_loss(&self, port: usize) -> Result<Vec<FrequencyLoss>, TouchstoneError> {
+        Ok(self
+            .port_reflections(port)?
+            .into_iter()
+            .map(|(frequency, gamma)| FrequencyLoss {
+                frequency,
+                loss_db: -10.0 * (1.0 - gamma.magnitude().powi(2)).log10(),
+            })
+            .collect())
+    }
+
+    /// Return the impedance looking into `port` at every frequency, with every other port
+    /// matched.
+    ///
+    /// The impedance is referenced to the port's [`ReferenceImpedance`](crate::ReferenceImpedance)
+    /// at each frequency using power waves, `Zin = (Zr* + S·Zr) / (1 − S)`, which reduces to
+    /// `Zr·(1 + S) / (1 − S)` for real references. `port` is 1-indexed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::from_str("load.s1p", "# GHz S RI R 50\n1.0 0.2 0.0\n")?;
+    /// let z_in = net.input_impedance(1)?;
+    ///
+    /// assert!((z_in[0].impedance.re - 75.0).abs() < 1e-9);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "zin")]
+    pub fn input_impedance(&self, port: usize) -> Result<Vec<FrequencyImpedance>, TouchstoneError> {
+        let reflections = self.port_reflections(port)?;
+
+        reflections
+            .into_iter()
+            .enumerate()
+            .map(|(point_index, (frequency, gamma))| {
+                let z0 = self.reference_impedance_at(point_index)?[port - 1];
+                Ok(FrequencyImpedance {
+                    frequency,
+                    impedance: (z0.conj() + gamma * z0) / (Complex::one() - gamma),
+                })
+            })
+            .collect()
+    }
+
+    /// Return the admittance looking into `port` at every frequency, with every other port
+    /// matched.
+    ///
+    /// This is the reciprocal of [`input_impedance`](Self::input_impedance). `port` is 1-indexed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::from_str("load.s1p", "# GHz S RI R 50\n1.0 0.2 0.0\n")?;
+    /// let y_in = net.input_admittance(1)?;
+    ///
+    /// assert!((y_in[0].admittance.re - 1.0 / 75.0).abs() < 1e-12);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "yin")]
+    pub fn input_admittance(
+        &self,
+        port: usize,
+    ) -> Result<Vec<FrequencyAdmittance>, TouchstoneError> {
+        Ok(self
+            .input_impedance(port)?
+            .into_iter()
+            .map(|point| FrequencyAdmittance {
+                frequency: point.frequency,
+                admittance: Complex::one() / point.impedance,
+            })
+            .collect())
+    }
+
+    /// Return `(frequency, S(port,port))` at every frequency after validating `port`.
+    fn port_reflections(&self, port: usize) -> Result<Vec<(f64, Complex)>, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+        validate_port(port, self.rank as usize)?;
+
+        Ok(self
+            .s
+            .iter()
+            .map(|data_line| {
+                let value = data_line.s_ri.get(port, port);
+                (
+                    data_line.frequency,
+                    Complex {
+                        re: value.0,
+                        im: value.1,
+                    },
+                )
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,632 @@
+use crate::network_builder::parsed_data_line_from_matrix;
+use crate::{
+    invert_matrix, validate_matrix_data, Complex, Interpolation, Network, ParameterMatrix,
+    ReferenceImpedance, SMatrix, TouchstoneError, WaveDefinition, PARAMETER_CONVERSION_TOLERANCE,
+};
+
+/// Port voltage and current expressed from incident and reflected waves.
+///
+/// `V = p a + q b` and `I = r a + t b`.
+#[derive(Debug, Clone, Copy)]
+struct WaveToVoltageCurrent {
+    p: Complex,
+    q: Complex,
+    r: Complex,
+    t: Complex,
+}
+
+/// Incident and reflected waves expressed from port voltage and current.
+///
+/// `a = x V + y I` and `b = u V + w I`.
+#[derive(Debug, Clone, Copy)]
+struct VoltageCurrentToWave {
+    x: Complex,
+    y: Complex,
+    u: Complex,
+    w: Complex,
+}
+
+impl SMatrix {
+    /// Convert this S-parameter matrix to an impedance-parameter matrix using per-port complex
+    /// reference impedances.
+    ///
+    /// `reference` holds one reference impedance in ohms per port. `definition` selects the wave
+    /// definition the S-parameters are referenced to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Complex, SMatrix, WaveDefinition};
+    ///
+    /// let matched = SMatrix {
+    ///     rank: 1,
+    ///     data: vec![vec![Complex { re: 0.0, im: 0.0 }]],
+    /// };
+    /// let reference = [Complex { re: 50.0, im: 10.0 }];
+    ///
+    /// // A power-wave match is the conjugate of the reference impedance.
+    /// let z = matched.to_z_matrix_with_reference(&reference, WaveDefinition::Power)?;
+    /// assert!((z.get(1, 1)?.im + 10.0).abs() < 1e-9);
+    ///
+    /// // A pseudo-wave match is the reference impedance itself.
+    /// let z = matched.to_z_matrix_with_reference(&reference, WaveDefinition::Pseudo)?;
+    /// assert!((z.get(1, 1)?.im - 10.0).abs() < 1e-9);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn to_z_matrix_with_reference(
+        &self,
+        reference: &[Complex],
+        definition: WaveDefinition,
+    ) -> Result<ParameterMatrix, TouchstoneError> {
+        validate_matrix_data("S", self.rank, &self.data)?;
+        validate_complex_reference_impedance(reference, self.rank)?;
+
+        let waves = reference
+            .iter()
+            .map(|z0| wave_to_voltage_current(*z0, definition))
+            .collect::<Vec<_>>();
+        let p = waves.iter().map(|wave| wave.p).collect::<Vec<_>>();
+        let q = waves.iter().map(|wave| wave.q).collect::<Vec<_>>();
+        let r = waves.iter().map(|wave| wave.r).collect::<Vec<_>>();
+        let t = waves.iter().map(|wave| wave.t).collect::<Vec<_>>();
+
+        // V = (P + QS) a and I = (R + TS) a, so Z = (P + QS)(R + TS)^-1.
+        let voltage = diagonal_plus_scaled_rows(&p, &q, &self.data);
+        let current = diagonal_plus_scaled_rows(&r, &t, &self.data);
+        let current_inverse = invert_matrix(
+            current,
+            "S to Z matrix inversion",
+            PARAMETER_CONVERSION_TOLERANCE,
+        )?;
+
+        Ok(ParameterMatrix {
+            rank: self.rank,
+            data: crate::matrix_mul(&voltage, &current_inverse),
+        })
+    }
+
+    /// Convert this S-parameter matrix to an admittance-parameter matrix using per-port complex
+    /// reference impedances.
+    ///
+    /// `reference` holds one reference impedance in ohms per port. `definition` selects the wave
+    /// definition the S-parameters are referenced to.
+    pub fn to_y_matrix_with_reference(
+        &self,
+        reference: &[Complex],
+        definition: WaveDefinition,
+    ) -> Result<ParameterMatrix, TouchstoneError> {
+        validate_matrix_data("S", self.rank, &self.data)?;
+        validate_complex_reference_impedance(reference, self.rank)?;
+
+        let waves = reference
+            .iter()
+            .map(|z0| wave_to_voltage_current(*z0, definition))
+            .collect::<Vec<_>>();
+        let p = waves.iter().map(|wave| wave.p).collect::<Vec<_>>();
+        let q = waves.iter().map(|wave| wave.q).collect::<Vec<_>>();
+        let r = waves.iter().map(|wave| wave.r).collect::<Vec<_>>();
+        let t = waves.iter().map(|wave| wave.t).collect::<Vec<_>>();
+
+        // Y = (R + TS)(P + QS)^-1.
+        let voltage = diagonal_plus_scaled_rows(&p, &q, &self.data);
+        let current = diagonal_plus_scaled_rows(&r, &t, &self.data);
+        let voltage_inverse = invert_matrix(
+            voltage,
+            "S to Y matrix inversion",
+            PARAMETER_CONVERSION_TOLERANCE,
+        )?;
+
+        Ok(ParameterMatrix {
+            rank: self.rank,
+            data: crate::matrix_mul(&current, &voltage_inverse),
+        })
+    }
+
+    /// Convert an impedance-parameter matrix to an S-parameter matrix using per-port complex
+    /// reference impedances.
+    ///
+    /// `reference` holds one reference impedance in ohms per port. `definition` selects the wave
+    /// definition of the returned S-parameters.
+    pub fn try_from_z_matrix_with_reference(
+        matrix: &ParameterMatrix,
+        reference: &[Complex],
+        definition: WaveDefinition,
+    ) -> Result<Self, TouchstoneError> {
+        validate_matrix_data("Z", matrix.rank, &matrix.data)?;
+        validate_complex_reference_impedance(reference, matrix.rank)?;
+
+        let waves = reference
+            .iter()
+            .map(|z0| voltage_current_to_wave(*z0, definition))
+            .collect::<Vec<_>>();
+        let x = waves.iter().map(|wave| wave.x).collect::<Vec<_>>();
+        let y = waves.iter().map(|wave| wave.y).collect::<Vec<_>>();
+        let u = waves.iter().map(|wave| wave.u).collect::<Vec<_>>();
+        let w = waves.iter().map(|wave| wave.w).collect::<Vec<_>>();
+
+        // a = (XZ + Y) I and b = (UZ + W) I, so S = (UZ + W)(XZ + Y)^-1.
+        let incident = diagonal_plus_scaled_rows(&y, &x, &matrix.data);
+        let reflected = diagonal_plus_scaled_rows(&w, &u, &matrix.data);
+        let incident_inverse = invert_matrix(
+            incident,
+            "Z to S matrix inversion",
+            PARAMETER_CONVERSION_TOLERANCE,
+        )?;
+
+        Ok(SMatrix {
+            rank: matrix.rank,
+            data: crate::matrix_mul(&reflected, &incident_inverse),
+        })
+    }
+
+    /// Convert an admittance-parameter matrix to an S-parameter matrix using per-port complex
+    /// reference impedances.
+    ///
+    /// `reference` holds one reference impedance in ohms per port. `definition` selects the wave
+    /// definition of the returned S-parameters.
+    pub fn try_from_y_matrix_with_reference(
+        matrix: &ParameterMatrix,
+        reference: &[Complex],
+        definition: WaveDefinition,
+    ) -> Result<Self, TouchstoneError> {
+        validate_matrix_data("Y", matrix.rank, &matrix.data)?;
+        validate_complex_reference_impedance(reference, matrix.rank)?;
+
+        let waves = reference
+            .iter()
+            .map(|z0| voltage_current_to_wave(*z0, definition))
+            .collect::<Vec<_>>();
+        let x = waves.iter().map(|wave| wave.x).collect::<Vec<_>>();
+        let y = waves.iter().map(|wave| wave.y).collect::<Vec<_>>();
+        let u = waves.iter().map(|wave| wave.u).collect::<Vec<_>>();
+        let w = waves.iter().map(|wave| wave.w).collect::<Vec<_>>();
+
+        // a = (X + YY) V and b = (U + WY) V, so S = (U + WY)(X + YY)^-1.
+        let incident = diagonal_plus_scaled_rows(&x, &y, &matrix.data);
+        let reflected = diagonal_plus_scaled_rows(&u, &w, &matrix.data);
+        let incident_inverse = invert_matrix(
+            incident,
+            "Y to S matrix inversion",
+            PARAMETER_CONVERSION_TOLERANCE,
+        )?;
+
+        Ok(SMatrix {
+            rank: matrix.rank,
+            data: crate::matrix_mul(&reflected, &incident_inverse),
+        })
+    }
+
+    /// Renormalize this S-parameter matrix from one set of per-port reference impedances to
+    /// another.
+    ///
+    /// The transformation is applied directly to the port waves, so matrices without a Z or Y
+    /// representation, such as an ideal through, can still be renormalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Complex, SMatrix, WaveDefinition};
+    ///
+    /// // A 75 ohm load reflects 0.2 in a 50 ohm system and is matched in a 75 ohm system.
+    /// let load = SMatrix {
+    ///     rank: 1,
+    ///     data: vec![vec![Complex { re: 0.2, im: 0.0 }]],
+    /// };
+    /// let renormalized = load.renormalize(
+    ///     &[Complex { re: 50.0, im: 0.0 }],
+    ///     &[Complex { re: 75.0, im: 0.0 }],
+    ///     WaveDefinition::Power,
+    /// )?;
+    ///
+    /// assert!(renormalized.get(1, 1)?.re.abs() < 1e-12);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "reference impedance")]
+    pub fn renormalize(
+        &self,
+        from: &[Complex],
+        to: &[Complex],
+        definition: WaveDefinition,
+    ) -> Result<Self, TouchstoneError> {
+        validate_matrix_data("S", self.rank, &self.data)?;
+        validate_complex_reference_impedance(from, self.rank)?;
+        validate_complex_reference_impedance(to, self.rank)?;
+
+        let mut alpha = Vec::with_capacity(self.rank);
+        let mut beta = Vec::with_capacity(self.rank);
+        let mut gamma = Vec::with_capacity(self.rank);
+        let mut delta = Vec::with_capacity(self.rank);
+
+        for (from_z0, to_z0) in from.iter().zip(to) {
+            let old = wave_to_voltage_current(*from_z0, definition);
+            let new = voltage_current_to_wave(*to_z0, definition);
+            alpha.push(new.x * old.p + new.y * old.r);
+            beta.push(new.x * old.q + new.y * old.t);
+            gamma.push(new.u * old.p + new.w * old.r);
+            delta.push(new.u * old.q + new.w * old.t);
+        }
+
+        // a' = (A + BS) a and b' = (C + DS) a, so S' = (C + DS)(A + BS)^-1.
+        let incident = diagonal_plus_scaled_rows(&alpha, &beta, &self.data);
+        let reflected = diagonal_plus_scaled_rows(&gamma, &delta, &self.data);
+        let incident_inverse = invert_matrix(
+            incident,
+            "S renormalization",
+            PARAMETER_CONVERSION_TOLERANCE,
+        )?;
+
+        Ok(SMatrix {
+            rank: self.rank,
+            data: crate::matrix_mul(&reflected, &incident_inverse),
+        })
+    }
+}
+
+impl Network {
+    /// Return the per-port complex reference impedances in ohms at one frequency point.
+    ///
+    /// `point_index` is 0-based. Common and real per-port reference impedances are returned with
+    /// zero imaginary parts.
+    pub fn reference_impedance_at(
+        &self,
+        point_index: usize,
+    ) -> Result<Vec<Complex>, TouchstoneError> {
+        self.data_line_at(point_index)?;
+        self.reference_impedance()
+            .values_at(point_index, self.rank as usize, self.s.len())
+    }
+
+    /// Return the impedance-parameter matrix at one frequency point using the network's complete
+    /// reference impedance metadata.
+    ///
+    /// `point_index` is 0-based. Unlike [`Network::z_matrix_at`], per-port, complex, and
+    /// frequency-dependent reference impedances are supported. `definition` selects the wave
+    /// definition the stored S-parameters are referenced to.
+    pub fn z_matrix_at_with_definition(
+        &self,
+        point_index: usize,
+        definition: WaveDefinition,
+    ) -> Result<ParameterMatrix, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+        let reference = self.reference_impedance_at(point_index)?;
+        self.s_matrix_at(point_index)?
+            .to_z_matrix_with_reference(&reference, definition)
+    }
+
+    /// Return the admittance-parameter matrix at one frequency point using the network's complete
+    /// reference impedance metadata.
+    ///
+    /// `point_index` is 0-based. Unlike [`Network::y_matrix_at`], per-port, complex, and
+    /// frequency-dependent reference impedances are supported. `definition` selects the wave
+    /// definition the stored S-parameters are referenced to.
+    pub fn y_matrix_at_with_definition(
+        &self,
+        point_index: usize,
+        definition: WaveDefinition,
+    ) -> Result<ParameterMatrix, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+        let reference = self.reference_impedance_at(point_index)?;
+        self.s_matrix_at(point_index)?
+            .to_y_matrix_with_reference(&reference, definition)
+    }
+
+    /// Return a copy of this network renormalized to new reference impedances.
+    ///
+    /// Every frequency point is renormalized from the network's current reference impedance to
+    /// `reference` using `definition`. The returned network stores `reference` as its reference
+    /// impedance metadata and updates [`Network::z0`] to the matching scalar compatibility value.
+    /// Per-port values must cover every port, and frequency-dependent values every frequency
+    /// point, or an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Network, ReferenceImpedance, WaveDefinition};
+    ///
+    /// let network = Network::from_str("load.s1p", "# GHz S RI R 50\n1.0 0.2 0.0\n")?;
+    /// let renormalized =
+    ///     network.renormalize(ReferenceImpedance::Common(75.0), WaveDefinition::Power)?;
+    ///
+    /// assert_eq!(renormalized.z0, 75.0);
+    /// assert!(renormalized.try_s_ri_at(0, 1, 1)?.re.abs() < 1e-12);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "reference impedance")]
+    pub fn renormalize(
+        &self,
+        reference: ReferenceImpedance,
+        definition: WaveDefinition,
+    ) -> Result<Network, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+        let rank = self.rank as usize;
+        let point_count = self.s.len();
+        reference.validate_shape(rank, point_count)?;
+
+        let s = self
+            .s
+            .iter()
+            .enumerate()
+            .map(|(point_index, data_line)| {
+                let from = self.reference_impedance_at(point_index)?;
+                let to = reference.values_at(point_index, rank, point_count)?;
+                let renormalized = self
+                    .s_matrix_at(point_index)?
+                    .renormalize(&from, &to, definition)?;
+                Ok(parsed_data_line_from_matrix(
+                    data_line.frequency,
+                    &renormalized,
+                ))
+            })
+            .collect::<Result<Vec<_>, TouchstoneError>>()?;
+
+        Ok(Network {
+            name: self.name.clone(),
+            rank: self.rank,
+            frequency_unit: self.frequency_unit.clone(),
+            parameter: self.parameter.clone(),
+            format: self.format.clone(),
+            resistance_string: self.resistance_string.clone(),
+            z0: reference.scalar_compatibility_value()?,
+            reference_impedance: reference,
+            comments: self.comments.clone(),
+            comments_after_option_line: self.comments_after_option_line.clone(),
+            warnings: self.warnings.clone(),
+            f: self.f.clone(),
+            s,
+        })
+    }
+
+    pub(crate) fn resampled_reference_impedance(
+        &self,
+        frequencies: &[f64],
+        interpolation: Interpolation,
+    ) -> Result<ReferenceImpedance, TouchstoneError> {
+        let ReferenceImpedance::FrequencyDependent(values) = &self.reference_impedance else {
+            return Ok(self.reference_impedance());
+        };
+
+        if values.len() != self.f.len() {
+            return Err(TouchstoneError::ReferenceImpedanceFrequencyCountMismatch {
+                expected: self.f.len(),
+                actual: values.len(),
+            });
+        }
+
+        let last_index = self.f.len() - 1;
+        let resampled = frequencies
+            .iter()
+            .map(|frequency| {
+                let upper_index = self.f.partition_point(|value| value < frequency);
+                if upper_index == 0 {
+                    return values[0].clone();
+                }
+                if upper_index > last_index {
+                    return values[last_index].clone();
+                }
+
+                let lower_index = upper_index - 1;
+                let lower_frequency = self.f[lower_index];
+                let upper_frequency = self.f[upper_index];
+                let t = (frequency - lower_frequency) / (upper_frequency - lower_frequency);
+
+                match interpolation {
+                    Interpolation::Nearest => {
+                        if t <= 0.5 {
+                            values[lower_index].clone()
+                        } else {
+                            values[upper_index].clone()
+                        }
+                    }
+                    _ => values[lower_index]
+                        .iter()
+                        .zip(&values[upper_index])
+                        .map(|(lower, upper)| *lower + (*upper - *lower) * t)
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Ok(ReferenceImpedance::FrequencyDependent(resampled))
+    }
+
+    pub(crate) fn ensure_s_parameter_data(&self) -> Result<(), TouchstoneError> {
+        if self.parameter == "S" {
+            Ok(())
+        } else {
+            Err(TouchstoneError::UnsupportedNetworkParameter {
+                parameter: self.parameter.clone(),
+            })
+        }
+    }
+}
+
+impl ReferenceImpedance {
+    /// Check that per-port values cover `rank` ports and frequency-dependent values cover
+    /// `point_count` points of `rank` ports each.
+    pub(crate) fn validate_shape(
+        &self,
+        rank: usize,
+        point_count: usize,
+    ) -> Result<(), TouchstoneError> {
+        let port_counts = match self {
+            Self::Common(_) => return Ok(()),
+            Self::PerPort(values) => vec![values.len()],
+            Self::PerPortComplex(values) => vec![values.len()],
+            Self::FrequencyDependent(values) => {
+                if values.len() != point_count {
+                    return Err(TouchstoneError::ReferenceImpedanceFrequencyCountMismatch {
+                        expected: point_count,
+                        actual: values.len(),
+                    });
+                }
+                values.iter().map(Vec::len).collect()
+            }
+        };
+
+        match port_counts.into_iter().find(|count| *count != rank) {
+            Some(actual) => Err(TouchstoneError::InvalidReferenceImpedanceCount {
+                ports: rank,
+                actual,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Resolve per-port complex reference impedances at one 0-based frequency point.
+    pub(crate) fn values_at(
+        &self,
+        point_index: usize,
+        rank: usize,
+        point_count: usize,
+    ) -> Result<Vec<Complex>, TouchstoneError> {
+        let values = match self {
+            Self::Common(z0) => {
+                crate::validate_reference_impedance(*z0)?;
+                vec![real(*z0); rank]
+            }
+            Self::PerPort(values) => {
+                for z0 in values {
+                    crate::validate_reference_impedance(*z0)?;
+                }
+                values.iter().copied().map(real).collect()
+            }
+            Self::PerPortComplex(values) => values.clone(),
+            Self::FrequencyDependent(values) => {
+                if values.len() != point_count {
+                    return Err(TouchstoneError::ReferenceImpedanceFrequencyCountMismatch {
+                        expected: point_count,
+                        actual: values.len(),
+                    });
+                }
+                values[point_index].clone()
+            }
+        };
+
+        validate_complex_reference_impedance(&values, rank)?;
+        Ok(values)
+    }
+
+    /// Return real per-port values when complex metadata has no reactive or frequency-dependent
+    /// part.
+    pub(crate) fn real_per_port_values(&self) -> Option<Vec<f64>> {
+        let values = match self {
+            Self::Common(_) => return None,
+            Self::PerPort(values) => return Some(values.clone()),
+            Self::PerPortComplex(values) => values,
+            Self::FrequencyDependent(values) => {
+                let first = values.first()?;
+                if values.iter().any(|point_values| point_values != first) {
+                    return None;
+                }
+                first
+            }
+        };
+
+        values
+            .iter()
+            .map(|value| (value.im == 0.0).then_some(value.re))
+            .collect()
+    }
+}
+
+fn real(value: f64) -> Complex {
+    Complex { re: value, im: 0.0 }
+}
+
+fn validate_complex_reference_impedance(
+    reference: &[Complex],
+    rank: usize,
+) -> Result<(), TouchstoneError> {
+    if reference.len() != rank {
+        return Err(TouchstoneError::InvalidReferenceImpedanceCount {
+            ports: rank,
+            actual: reference.len(),
+        });
+    }
+
+    for (index, z0) in reference.iter().enumerate() {
+        if !z0.is_finite() || z0.re <= 0.0 {
+            return Err(TouchstoneError::InvalidComplexReferenceImpedance {
+                port: index + 1,
+                re: z0.re,
+                im: z0.im,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn wave_to_voltage_current(z0: Complex, definition: WaveDefinition) -> WaveToVoltageCurrent {
+    match definition {
+        WaveDefinition::Power => {
+            let f = 1.0 / (2.0 * z0.re.sqrt());
+            let r = real(1.0 / (2.0 * f * z0.re));
+            WaveToVoltageCurrent {
+                p: real(1.0 / f) - z0 * r,
+                q: z0 * r,
+                r,
+                t: -r,
+            }
+        }
+        WaveDefinition::Pseudo => {
+            let k = z0.re.sqrt() / (2.0 * z0.magnitude());
+            let voltage = real(1.0 / (2.0 * k));
+            let current = voltage / z0;
+            WaveToVoltageCurrent {
+                p: voltage,
+                q: voltage,
+                r: current,
+                t: -current,
+            }
+        }
+    }
+}
+
+fn voltage_current_to_wave(z0: Complex, definition: WaveDefinition) -> VoltageCurrentToWave {
+    match definition {
+        WaveDefinition::Power => {
+            let f = 1.0 / (2.0 * z0.re.sqrt());
+            VoltageCurrentToWave {
+                x: real(f),
+                y: z0 * f,
+                u: real(f),
+                w: -(z0.conj() * f),
+            }
+        }
+        WaveDefinition::Pseudo => {
+            let k = z0.re.sqrt() / (2.0 * z0.magnitude());
+            VoltageCurrentToWave {
+                x: real(k),
+                y: z0 * k,
+                u: real(k),
+                w: -(z0 * k),
+            }
+        }
+    }
+}
+
+/// Return `diag(diagonal) + diag(row_scale) * matrix`.
+fn diagonal_plus_scaled_rows(
+    diagonal: &[Complex],
+    row_scale: &[Complex],
+    matrix: &[Vec<Complex>],
+) -> Vec<Vec<Complex>> {
+    matrix
+        .iter()
+        .enumerate()
+        .map(|(row_index, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(column_index, value)| {
+                    let scaled = row_scale[row_index] * *value;
+                    if row_index == column_index {
+                        scaled + diagonal[row_index]
+                    } else {
+                        scaled
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,488 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::network_builder::canonical_frequency_unit;
+use crate::utils::try_str_to_f64;
+use crate::{FrequencyValue, Network, Quantity, TouchstoneError, TouchstoneErrorContext};
+
+/// Side of a limit line that measured values must stay on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitKind {
+    /// Values must be at or below the line.
+    Upper,
+    /// Values must be at or above the line.
+    Lower,
+}
+
+/// Piecewise-linear upper or lower bound on one quantity of one S-parameter.
+///
+/// The line is defined between its first and last frequency and is linear in between. Only
+/// frequency points of the network inside that span are checked.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::{LimitLine, Quantity};
+///
+/// // S11 below -15 dB from 0.8 to 2.2 GHz.
+/// let limit = LimitLine::upper(1, 1, Quantity::Decibel, [(0.8e9, -15.0), (2.2e9, -15.0)])?;
+///
+/// assert_eq!(limit.to_string(), "S11 dB < 800000000 -15 2200000000 -15");
+/// # Ok::<(), touchstone::TouchstoneError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimitLine {
+    /// Destination port `j` of S(j,k), 1-indexed.
+    pub to_port: usize,
+    /// Source port `k` of S(j,k), 1-indexed.
+    pub from_port: usize,
+    /// Quantity the limit applies to, in that quantity's units.
+    pub quantity: Quantity,
+    /// Whether values must stay below or above the line.
+    pub kind: LimitKind,
+    /// `(frequency_hz, value)` vertices in strictly increasing frequency order.
+    pub points: Vec<(f64, f64)>,
+}
+
+impl LimitLine {
+    /// Create a limit line from at least two `(frequency_hz, value)` vertices.
+    ///
+    /// Vertices must be finite and strictly increasing in frequency, and ports must be at least
+    /// 1; otherwise [`TouchstoneError::InvalidLimitLine`] is returned.
+    pub fn new<I>(
+        to_port: usize,
+        from_port: usize,
+        quantity: Quantity,
+        kind: LimitKind,
+        points: I,
+    ) -> Result<Self, TouchstoneError>
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        let points = points.into_iter().collect::<Vec<_>>();
+        if to_port == 0 || from_port == 0 {
+            return Err(invalid_limit("ports are 1-indexed"));
+        }
+        if points.len() < 2 {
+            return Err(invalid_limit(
+                "at least two frequency/value points are required",
+            ));
+        }
+        if points
+            .iter()
+            .any(|(frequency, value)| !frequency.is_finite() || !value.is_finite())
+        {
+            return Err(invalid_limit("frequencies and values must be finite"));
+        }
+        if points.windows(2).any(|pair| pair[1].0 <= pair[0].0) {
+            return Err(invalid_limit("frequencies must be strictly increasing"));
+        }
+
+        Ok(Self {
+            to_port,
+            from_port,
+            quantity,
+            kind,
+            points,
+        })
+    }
+
+    /// Create an upper limit: values of S(j,k) must be at or below the line.
+    pub fn upper<I>(
+        to_port: usize,
+        from_port: usize,
+        quantity: Quantity,
+        points: I,
+    ) -> Result<Self, TouchstoneError>
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        Self::new(to_port, from_port, quantity, LimitKind::Upper, points)
+    }
+
+    /// Create a lower limit: values of S(j,k) must be at or above the line.
+    pub fn lower<I>(
+        to_port: usize,
+        from_port: usize,
+        quantity: Quantity,
+        points: I,
+    ) -> Result<Self, TouchstoneError>
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        Self::new(to_port, from_port, quantity, LimitKind::Lower, points)
+    }
+
+    /// Return the limit value at `frequency`, or `None` outside the line's span.
+    #[must_use]
+    pub fn value_at(&self, frequency: f64) -> Option<f64> {
+        let upper = self.points.partition_point(|(point, _)| *point < frequency);
+        if upper == self.points.len() {
+            return None;
+        }
+        let (upper_frequency, upper_value) = self.points[upper];
+        if upper_frequency == frequency {
+            return Some(upper_value);
+        }
+        if upper == 0 {
+            return None;
+        }
+
+        let (lower_frequency, lower_value) = self.points[upper - 1];
+        let t = (frequency - lower_frequency) / (upper_frequency - lower_frequency);
+        Some(lower_value + t * (upper_value - lower_value))
+    }
+
+    /// Return how far `value` is inside the limit; negative values are violations.
+    fn margin(&self, limit: f64, value: f64) -> f64 {
+        match self.kind {
+            LimitKind::Upper => limit - value,
+            LimitKind::Lower => value - limit,
+        }
+    }
+}
+
+impl fmt::Display for LimitLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let quantity = match self.quantity {
+            Quantity::Decibel => "dB",
+            Quantity::Magnitude => "mag",
+            Quantity::Phase => "phase",
+            Quantity::GroupDelay => "delay",
+        };
+        let kind = match self.kind {
+            LimitKind::Upper => "<",
+            LimitKind::Lower => ">",
+        };
+        write!(f, "{} {quantity} {kind}", port_label(self))?;
+        for (frequency, value) in &self.points {
+            write!(f, " {frequency} {value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Set of limit lines that a network must satisfy.
+///
+/// A specification is built in code with [`Spec::limit`] or loaded from text. The text format is
+/// line-based like Touchstone: `!` starts a comment, an optional `# <unit>` line (`Hz`, `kHz`,
+/// `MHz`, `GHz`, or `THz`; default `Hz`) sets the frequency unit for the lines after it, and
+/// every other line is one limit:
+///
+/// ```text
+/// <parameter> <quantity> <bound> <frequency> <value> <frequency> <value> ...
+/// ```
+///
+/// `parameter` is `Sjk`, or `Sj,k` for ports above 9. `quantity` is `dB`, `mag`, `phase`
+/// (unwrapped degrees), or `delay` (group delay in seconds). `bound` is `<` or `upper` for an
+/// upper limit, and `>` or `lower` for a lower limit.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::{Network, Spec};
+///
+/// let spec = Spec::from_str(
+///     "filter.spec",
+///     "! Passband\n# GHz\nS21 dB > 1.0 -1.5 2.0 -1.5\nS11 dB < 0.8 -15 2.2 -15\n",
+/// )?;
+///
+/// assert_eq!(spec.limits.len(), 2);
+/// # Ok::<(), touchstone::TouchstoneError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Spec {
+    /// Limit lines in the order they were added.
+    pub limits: Vec<LimitLine>,
+}
+
+impl Spec {
+    /// Create an empty specification.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a limit line.
+    #[must_use]
+    pub fn limit(mut self, limit: LimitLine) -> Self {
+        self.limits.push(limit);
+        self
+    }
+
+    /// Parse a specification from text in the format described on [`Spec`].
+    ///
+    /// `source_name` is used in error context. Malformed lines return a
+    /// [`TouchstoneError::Parse`] error with the line number.
+    pub fn from_str(source_name: &str, text: &str) -> Result<Self, TouchstoneError> {
+        let mut spec = Self::new();
+        let mut multiplier = 1.0;
+
+        for (index, line) in text.lines().enumerate() {
+            let content = line.split('!').next().unwrap_or("").trim();
+            if content.is_empty() {
+                continue;
+            }
+
+            let parsed = match content.strip_prefix('#') {
+                Some(unit) => frequency_multiplier(unit).map(|value| {
+                    multiplier = value;
+                }),
+                None => parse_limit_line(content, multiplier).map(|limit| {
+                    spec.limits.push(limit);
+                }),
+            };
+            parsed.map_err(|error| {
+                error.with_context(TouchstoneErrorContext {
+                    source_name: source_name.to_string(),
+                    line_number: Some(index + 1),
+                    line: Some(line.to_string()),
+                })
+            })?;
+        }
+
+        Ok(spec)
+    }
+
+    /// Read and parse a specification file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use touchstone::{Network, Spec};
+    ///
+    /// let spec = Spec::from_file("limits/filter.spec")?;
+    /// let report = spec.evaluate(&Network::new("files/ntwk1.s2p")?)?;
+    /// println!("{report}");
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, TouchstoneError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        Self::from_str(&path.display().to_string(), &text)
+    }
+
+    /// Check every limit line against `network`.
+    ///
+    /// Each limit is checked at the network's frequency points inside its span. Limits whose
+    /// span contains no frequency points return [`TouchstoneError::InvalidFrequencyBand`], and
+    /// limits on ports the network does not have return
+    /// [`TouchstoneError::InvalidPortIndex`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{LimitLine, Network, Quantity, Spec};
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let spec = Spec::new().limit(LimitLine::upper(
+    ///     1,
+    ///     1,
+    ///     Quantity::Decibel,
+    ///     [(1.0e9, 0.0), (10.0e9, 0.0)],
+    /// )?);
+    /// let report = spec.evaluate(&net)?;
+    ///
+    /// assert!(report.passed());
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn evaluate(&self, network: &Network) -> Result<SpecReport, TouchstoneError> {
+        let results = self
+            .limits
+            .iter()
+            .map(|limit| {
+                let values = network.quantity(limit.to_port, limit.from_port, limit.quantity)?;
+                check_limit(limit, &values).ok_or(TouchstoneError::InvalidFrequencyBand {
+                    start: limit.points[0].0,
+                    stop: limit.points[limit.points.len() - 1].0,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(SpecReport {
+            network_name: network.name.clone(),
+            results,
+        })
+    }
+}
+
+/// Outcome of checking one [`LimitLine`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimitResult {
+    /// Limit line that was checked.
+    pub limit: LimitLine,
+    /// Smallest distance inside the limit, in the quantity's units. Negative values are
+    /// violations.
+    pub worst_margin: f64,
+    /// Frequency in Hz of the worst margin.
+    pub worst_frequency: f64,
+    /// Measured value at the worst margin.
+    pub worst_value: f64,
+    /// Number of frequency points checked.
+    pub points_checked: usize,
+}
+
+impl LimitResult {
+    /// Return whether every checked point is within the limit.
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.worst_margin >= 0.0
+    }
+}
+
+/// Pass/fail report from [`Spec::evaluate`].
+///
+/// The `Display` output lists one line per limit with its status and worst margin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecReport {
+    /// Name of the evaluated network.
+    pub network_name: String,
+    /// One result per limit line, in specification order.
+    pub results: Vec<LimitResult>,
+}
+
+impl SpecReport {
+    /// Return whether every limit passed.
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.results.iter().all(LimitResult::passed)
+    }
+
+    /// Return the result with the smallest margin, if there are any limits.
+    #[must_use]
+    pub fn worst(&self) -> Option<&LimitResult> {
+        self.results
+            .iter()
+            .min_by(|a, b| a.worst_margin.total_cmp(&b.worst_margin))
+    }
+}
+
+impl fmt::Display for SpecReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.passed() { "PASS" } else { "FAIL" };
+        write!(f, "{status} {}", self.network_name)?;
+        for result in &self.results {
+            let status = if result.passed() { "PASS" } else { "FAIL" };
+            write!(
+                f,
+                "\n  {status} {}: worst margin {} at {} Hz (measured {})",
+                result.limit, result.worst_margin, result.worst_frequency, result.worst_value
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Check `values` against `limit`, or return `None` when no value lies inside its span.
+fn check_limit(limit: &LimitLine, values: &[FrequencyValue]) -> Option<LimitResult> {
+    let mut result: Option<LimitResult> = None;
+
+    for point in values {
+        let Some(limit_value) = limit.value_at(point.frequency) else {
+            continue;
+        };
+        let margin = limit.margin(limit_value, point.value);
+        let result = result.get_or_insert_with(|| LimitResult {
+            limit: limit.clone(),
+            worst_margin: margin,
+            worst_frequency: point.frequency,
+            worst_value: point.value,
+            points_checked: 0,
+        });
+        result.points_checked += 1;
+        if margin < result.worst_margin {
+            result.worst_margin = margin;
+            result.worst_frequency = point.frequency;
+            result.worst_value = point.value;
+        }
+    }
+
+    result
+}
+
+fn parse_limit_line(content: &str, multiplier: f64) -> Result<LimitLine, TouchstoneError> {
+    let tokens = content.split_whitespace().collect::<Vec<_>>();
+    if tokens.len() < 3 {
+        return Err(invalid_limit(
+            "expected a parameter, quantity, bound, and frequency/value pairs",
+        ));
+    }
+
+    let (to_port, from_port) = parse_parameter(tokens[0])?;
+    let quantity = match tokens[1].to_ascii_lowercase().as_str() {
+        "db" => Quantity::Decibel,
+        "mag" => Quantity::Magnitude,
+        "phase" => Quantity::Phase,
+        "delay" => Quantity::GroupDelay,
+        other => return Err(invalid_limit(&format!("unknown quantity {other}"))),
+    };
+    let kind = match tokens[2].to_ascii_lowercase().as_str() {
+        "<" | "upper" => LimitKind::Upper,
+        ">" | "lower" => LimitKind::Lower,
+        other => return Err(invalid_limit(&format!("unknown bound {other}"))),
+    };
+
+    let numbers = tokens[3..]
+        .iter()
+        .map(|token| try_str_to_f64(token))
+        .collect::<Result<Vec<_>, _>>()?;
+    if numbers.len() % 2 != 0 {
+        return Err(invalid_limit("frequencies and values must come in pairs"));
+    }
+
+    LimitLine::new(
+        to_port,
+        from_port,
+        quantity,
+        kind,
+        numbers
+            .chunks(2)
+            .map(|pair| (pair[0] * multiplier, pair[1])),
+    )
+}
+
+/// Parse `Sjk` or `Sj,k` into 1-based `(j, k)`.
+fn parse_parameter(token: &str) -> Result<(usize, usize), TouchstoneError> {
+    let invalid = || invalid_limit(&format!("unknown parameter {token}"));
+    let ports = token
+        .strip_prefix('S')
+        .or_else(|| token.strip_prefix('s'))
+        .ok_or_else(invalid)?;
+
+    let (to_port, from_port) = match ports.split_once(',') {
+        Some(pair) => pair,
+        None if ports.len() == 2 => ports.split_at(1),
+        None => return Err(invalid()),
+    };
+    match (to_port.parse(), from_port.parse()) {
+        (Ok(to_port), Ok(from_port)) => Ok((to_port, from_port)),
+        _ => Err(invalid()),
+    }
+}
+
+fn frequency_multiplier(unit: &str) -> Result<f64, TouchstoneError> {
+    match canonical_frequency_unit(unit) {
+        Some("Hz") => Ok(1.0),
+        Some("kHz") => Ok(1.0e3),
+        Some("MHz") => Ok(1.0e6),
+        Some("GHz") => Ok(1.0e9),
+        Some("THz") => Ok(1.0e12),
+        _ => Err(TouchstoneError::UnsupportedFrequencyUnit {
+            unit: unit.trim().to_string(),
+        }),
+    }
+}
+
+fn port_label(limit: &LimitLine) -> String {
+    if limit.to_port < 10 && limit.from_port < 10 {
+        format!("S{}{}", limit.to_port, limit.from_port)
+    } else {
+        format!("S{},{}", limit.to_port, limit.from_port)
+    }
+}
+
+fn invalid_limit(reason: &str) -> TouchstoneError {
+    TouchstoneError::InvalidLimitLine {
+        reason: reason.to_string(),
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{Complex, Network, NetworkPoint, SMatrix, TouchstoneError};
+
+/// Two-port stability factors at a single frequency.
+///
+/// A two-port is unconditionally stable when `mu > 1` (equivalently `mu_prime > 1`), or when
+/// `k > 1` and `delta < 1`. Unilateral networks (`S12·S21 = 0`) report an infinite `k`.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::Network;
+///
+/// let net = Network::new("files/fet.s2p").unwrap();
+/// let factors = net.stability().unwrap();
+/// let point = &factors[0];
+/// println!(
+///     "f = {} Hz, K = {}, |Δ| = {}, μ = {}",
+///     point.frequency, point.k, point.delta, point.mu
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StabilityFactors {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Rollett stability factor K.
+    pub k: f64,
+    /// Magnitude of the S-matrix determinant, |Δ| = |S11·S22 − S12·S21|.
+    pub delta: f64,
+    /// Edwards-Sinsky load-side stability factor μ.
+    pub mu: f64,
+    /// Edwards-Sinsky source-side stability factor μ′.
+    pub mu_prime: f64,
+}
+
+impl StabilityFactors {
+    /// Return whether the two-port is unconditionally stable at this frequency (`mu > 1`).
+    #[must_use]
+    pub fn is_unconditionally_stable(&self) -> bool {
+        self.mu > 1.0
+    }
+}
+
+impl Network {
+    /// Return the Rollett K, |Δ|, and Edwards-Sinsky μ and μ′ stability factors at every
+    /// frequency.
+    ///
+    /// The network must be a two-port; other ranks return
+    /// [`TouchstoneError::InvalidNetworkRank`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/fet.s2p")?;
+    /// let factors = net.stability()?;
+    ///
+    /// assert_eq!(factors.len(), net.f.len());
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "rollett")]
+    #[doc(alias = "mu")]
+    pub fn stability(&self) -> Result<Vec<StabilityFactors>, TouchstoneError> {
+        Ok(self
+            .two_port_points()?
+            .iter()
+            .map(|point| stability_factors(point.frequency, &point.s))
+            .collect())
+    }
+
+    /// Return the frequencies in Hz where the two-port is potentially unstable (`mu <= 1`).
+    ///
+    /// An empty result means the device is unconditionally stable across the parsed band.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/fet.s2p")?;
+    /// for frequency in net.potentially_unstable_frequencies()? {
+    ///     println!("potentially unstable at {frequency} Hz");
+    /// }
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn potentially_unstable_frequencies(&self) -> Result<Vec<f64>, TouchstoneError> {
+        Ok(self
+            .stability()?
+            .into_iter()
+            .filter(|factors| !factors.is_unconditionally_stable())
+            .map(|factors| factors.frequency)
+            .collect())
+    }
+
+    pub(crate) fn two_port_points(&self) -> Result<Vec<NetworkPoint>, TouchstoneError> {
+        if self.rank != 2 {
+            return Err(TouchstoneError::InvalidNetworkRank {
+                rank: self.rank as usize,
+            });
+        }
+        self.points()
+    }
+}
+
+pub(crate) fn stability_factors(frequency: f64, s: &SMatrix) -> StabilityFactors {
+    let [s11, s12, s21, s22] = two_port_entries(s);
+    let delta = s11 * s22 - s12 * s21;
+    let loop_gain = (s12 * s21).magnitude();
+    let s11_squared = s11.magnitude().powi(2);
+    let s22_squared = s22.magnitude().powi(2);
+
+    StabilityFactors {
+        frequency,
+        k: (1.0 - s11_squared - s22_squared + delta.magnitude().powi(2)) / (2.0 * loop_gain),
+        delta: delta.magnitude(),
+        mu: (1.0 - s11_squared) / ((s22 - delta * s11.conj()).magnitude() + loop_gain),
+        mu_prime: (1.0 - s22_squared) / ((s11 - delta * s22.conj()).magnitude() + loop_gain),
+    }
+}
+
+/// Return `[S11, S12, S21, S22]` from a two-port matrix.
+pub(crate) fn two_port_entries(s: &SMatrix) -> [Complex; 4] {
+    [s.data[0][0], s.data[0][1], s.data[1][0], s.data[1][1]]
+}
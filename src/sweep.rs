@@ -0,0 +1,205 @@
+use crate::interpolation::{extrapolate_linear, filled_data_line, sample_data_lines};
+use crate::{Complex, Extrapolation, Interpolation, Network, NetworkSet, TouchstoneError};
+
+/// Networks measured or simulated at several values of one scalar parameter, such as temperature
+/// or bias voltage.
+///
+/// Every network has the same rank, frequency grid, and reference impedance, as required by
+/// [`NetworkSet`]. Conditions are kept sorted by parameter value.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::{Extrapolation, Interpolation, Network, ParameterSweep};
+///
+/// let sweep = ParameterSweep::new(
+///     "temperature",
+///     vec![
+///         (25.0, Network::new("files/LFCN-2352+_Plus25degC.s2p")?),
+///         (125.0, Network::new("files/LFCN-2352+_Plus125degC.s2p")?),
+///     ],
+/// )?;
+/// let hot = sweep.at(85.0, Interpolation::Linear, Extrapolation::Error)?;
+///
+/// assert_eq!(hot.f, sweep.frequencies());
+/// # Ok::<(), touchstone::TouchstoneError>(())
+/// ```
+#[doc(alias = "temperature")]
+#[doc(alias = "bias")]
+#[derive(Debug, Clone)]
+pub struct ParameterSweep {
+    parameter: String,
+    values: Vec<f64>,
+    networks: Vec<Network>,
+}
+
+impl ParameterSweep {
+    /// Create a sweep of `parameter` from `(value, network)` conditions in any order.
+    ///
+    /// Values must be finite and distinct, otherwise
+    /// [`TouchstoneError::InvalidSweepValue`] is returned. Networks must be compatible as
+    /// described in [`NetworkSet::new`]; resample them first with [`NetworkSet::resampled`] when
+    /// their frequency grids differ.
+    pub fn new<S: Into<String>>(
+        parameter: S,
+        mut conditions: Vec<(f64, Network)>,
+    ) -> Result<Self, TouchstoneError> {
+        let parameter = parameter.into();
+        if let Some((value, _)) = conditions.iter().find(|(value, _)| !value.is_finite()) {
+            return Err(TouchstoneError::InvalidSweepValue {
+                parameter,
+                value: *value,
+            });
+        }
+        conditions.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        if let Some(pair) = conditions.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            return Err(TouchstoneError::InvalidSweepValue {
+                parameter,
+                value: pair[1].0,
+            });
+        }
+
+        let (values, networks): (Vec<f64>, Vec<Network>) = conditions.into_iter().unzip();
+        let networks = NetworkSet::new(networks)?.into_networks();
+        Ok(Self {
+            parameter,
+            values,
+            networks,
+        })
+    }
+
+    /// Return the name of the swept parameter.
+    #[must_use]
+    pub fn parameter(&self) -> &str {
+        &self.parameter
+    }
+
+    /// Return the swept parameter values in increasing order.
+    #[must_use]
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Return the networks in the same order as [`values`](Self::values).
+    #[must_use]
+    pub fn networks(&self) -> &[Network] {
+        &self.networks
+    }
+
+    /// Return the number of conditions in the sweep.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.networks.len()
+    }
+
+    /// Return whether the sweep contains no conditions.
+    ///
+    /// Sweeps are never empty once created; this exists for API symmetry with
+    /// [`len`](Self::len).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.networks.is_empty()
+    }
+
+    /// Return the frequency grid in Hz shared by every condition.
+    #[must_use]
+    pub fn frequencies(&self) -> &[f64] {
+        &self.networks[0].f
+    }
+
+    /// Estimate the network at parameter `value` from the neighboring conditions.
+    ///
+    /// Each frequency point is interpolated between the two conditions around `value` the same
+    /// way [`Network::resample`] interpolates between frequency points: [`Interpolation::Linear`]
+    /// blends the real and imaginary parts and [`Interpolation::Nearest`] takes the closest
+    /// condition, preferring the lower one on ties. [`Interpolation::DelayCompensated`] only
+    /// applies along frequency, so between conditions it interpolates magnitude and phase like
+    /// [`Interpolation::MagnitudePhase`].
+    ///
+    /// Values outside the swept range follow `extrapolation` along the parameter axis:
+    /// [`Extrapolation::Clamp`] holds the boundary condition, [`Extrapolation::Linear`] extends
+    /// the two nearest conditions, and [`Extrapolation::Zero`] and [`Extrapolation::Identity`]
+    /// fill every frequency point. [`Extrapolation::Error`] and the frequency-only
+    /// [`Extrapolation::Dc`] return [`TouchstoneError::ParameterOutOfRange`].
+    ///
+    /// The returned network keeps the metadata of the condition at or below `value` and records
+    /// the parameter value in a comment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Extrapolation, Interpolation, Network, ParameterSweep};
+    ///
+    /// let sweep = ParameterSweep::new(
+    ///     "bias",
+    ///     vec![
+    ///         (1.0, Network::from_str("low.s1p", "# GHz S RI R 50\n1.0 0.2 0.0\n")?),
+    ///         (3.0, Network::from_str("high.s1p", "# GHz S RI R 50\n1.0 0.6 0.0\n")?),
+    ///     ],
+    /// )?;
+    /// let mid = sweep.at(2.0, Interpolation::Linear, Extrapolation::Error)?;
+    ///
+    /// assert!((mid.try_s_ri_at(0, 1, 1)?.re - 0.4).abs() < 1e-12);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "interpolate")]
+    pub fn at(
+        &self,
+        value: f64,
+        interpolation: Interpolation,
+        extrapolation: Extrapolation,
+    ) -> Result<Network, TouchstoneError> {
+        let min = self.values[0];
+        let max = self.values[self.values.len() - 1];
+        let out_of_range = || TouchstoneError::ParameterOutOfRange {
+            parameter: self.parameter.clone(),
+            value,
+            min,
+            max,
+        };
+        if !value.is_finite() {
+            return Err(out_of_range());
+        }
+        let in_range = (min..=max).contains(&value);
+        let lookup = match extrapolation {
+            _ if in_range => value,
+            Extrapolation::Clamp => value.clamp(min, max),
+            Extrapolation::Linear | Extrapolation::Zero | Extrapolation::Identity => value,
+            _ => return Err(out_of_range()),
+        };
+
+        let interpolation = match interpolation {
+            Interpolation::DelayCompensated => Interpolation::MagnitudePhase,
+            interpolation => interpolation,
+        };
+
+        let base = self.values.partition_point(|swept| *swept <= value).max(1) - 1;
+        let mut network = self.networks[base].clone();
+        let rank = network.rank as usize;
+        for (point_index, data_line) in network.s.iter_mut().enumerate() {
+            let frequency = data_line.frequency;
+            let conditions = self
+                .networks
+                .iter()
+                .map(|network| network.s[point_index].clone())
+                .collect::<Vec<_>>();
+            *data_line = match extrapolation {
+                _ if (min..=max).contains(&lookup) => sample_data_lines(
+                    &self.values,
+                    &conditions,
+                    &[(lookup, frequency)],
+                    interpolation,
+                )
+                .remove(0),
+                Extrapolation::Zero => filled_data_line(frequency, rank, Complex::zero()),
+                Extrapolation::Identity => filled_data_line(frequency, rank, Complex::one()),
+                _ => extrapolate_linear(&self.values, &conditions, lookup, frequency),
+            };
+        }
+        network.name = format!("{}_{value}.s{}p", self.parameter, network.rank);
+        network
+            .comments
+            .push(format!("! {} = {value}", self.parameter));
+        Ok(network)
+    }
+}
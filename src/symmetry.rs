@@ -0,0 +1,231 @@
+use crate::network_builder::parsed_data_line_from_matrix;
+use crate::{Complex, Network, SMatrix, TouchstoneError};
+
+/// Deviation of S-parameter data from the value it should match, at a single frequency.
+///
+/// Reciprocity and symmetry checks report the largest deviation over all port pairs; the
+/// causality check reports the deviation of one S-parameter from its causal part.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::Network;
+///
+/// let net = Network::new("files/ntwk1.s2p").unwrap();
+/// let errors = net.reciprocity_error().unwrap();
+/// let point = &errors[0];
+/// println!("f = {} Hz, max |Sij − Sji| = {}", point.frequency, point.deviation);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyDeviation {
+    /// Frequency in Hz.
+    pub frequency: f64,
+    /// Magnitude of the complex difference.
+    pub deviation: f64,
+}
+
+impl Network {
+    /// Return whether `|Sij − Sji| <= tolerance` for every port pair at every frequency.
+    ///
+    /// Passive networks without magnetized materials are reciprocal; measurements of them
+    /// deviate only by noise. Networks of any rank are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    ///
+    /// assert!(net.is_reciprocal(1e-6)?);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn is_reciprocal(&self, tolerance: f64) -> Result<bool, TouchstoneError> {
+        Ok(self
+            .reciprocity_error()?
+            .iter()
+            .all(|point| point.deviation <= tolerance))
+    }
+
+    /// Return the largest `|Sij − Sji|` over all port pairs at every frequency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/Agilent_E5071B.s4p")?;
+    /// let worst = net
+    ///     .reciprocity_error()?
+    ///     .into_iter()
+    ///     .map(|point| point.deviation)
+    ///     .fold(0.0, f64::max);
+    ///
+    /// println!("worst reciprocity error: {worst}");
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn reciprocity_error(&self) -> Result<Vec<FrequencyDeviation>, TouchstoneError> {
+        self.max_deviation(|_, to_port, from_port| (from_port, to_port))
+    }
+
+    /// Return a copy of the network with `Sij` and `Sji` both replaced by their average.
+    ///
+    /// Averaging removes the non-reciprocal part of measurement noise. Reflection terms are not
+    /// changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/Agilent_E5071B.s4p")?;
+    /// let reciprocal = net.make_reciprocal()?;
+    ///
+    /// assert!(reciprocal.is_reciprocal(0.0)?);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn make_reciprocal(&self) -> Result<Network, TouchstoneError> {
+        self.averaged_over(
+            "! Made reciprocal: Sij and Sji averaged",
+            |_, to_port, from_port| vec![(to_port, from_port), (from_port, to_port)],
+        )
+    }
+
+    /// Return whether the network is mirror-symmetric within `tolerance` at every frequency.
+    ///
+    /// Mirror symmetry maps port `i` to port `N + 1 − i`, so a symmetric 2-port has `S11 = S22`
+    /// and `S21 = S12`, and a symmetric 4-port has `S11 = S44`, `S21 = S34`, and so on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::from_str(
+    ///     "line.s2p",
+    ///     "# GHz S RI R 50\n1.0 0.1 0.0 0.9 0.0 0.9 0.0 0.1 0.0\n",
+    /// )?;
+    ///
+    /// assert!(net.is_symmetric(1e-12)?);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn is_symmetric(&self, tolerance: f64) -> Result<bool, TouchstoneError> {
+        Ok(self
+            .symmetry_error()?
+            .iter()
+            .all(|point| point.deviation <= tolerance))
+    }
+
+    /// Return the largest `|Sij − S(N+1−i)(N+1−j)|` over all port pairs at every frequency.
+    ///
+    /// See [`is_symmetric`](Self::is_symmetric) for the port mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let errors = net.symmetry_error()?;
+    ///
+    /// assert_eq!(errors.len(), net.f.len());
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn symmetry_error(&self) -> Result<Vec<FrequencyDeviation>, TouchstoneError> {
+        self.max_deviation(|rank, to_port, from_port| (rank - 1 - to_port, rank - 1 - from_port))
+    }
+
+    /// Return a mirror-symmetric and reciprocal copy of the network.
+    ///
+    /// Every `Sij` is replaced by the average of `Sij`, `Sji`, `S(N+1−i)(N+1−j)` and
+    /// `S(N+1−j)(N+1−i)`, so a 2-port ends up with `S11 = S22` and `S21 = S12`. This removes the
+    /// asymmetric part of measurement noise from a nominally symmetric device.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::Network;
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let symmetric = net.make_symmetric()?;
+    ///
+    /// assert!(symmetric.is_symmetric(1e-12)?);
+    /// assert!(symmetric.is_reciprocal(1e-12)?);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "symmetrize")]
+    pub fn make_symmetric(&self) -> Result<Network, TouchstoneError> {
+        self.averaged_over(
+            "! Made symmetric: mirrored and transposed S-parameters averaged",
+            |rank, to_port, from_port| {
+                let (mirror_to, mirror_from) = (rank - 1 - to_port, rank - 1 - from_port);
+                vec![
+                    (to_port, from_port),
+                    (from_port, to_port),
+                    (mirror_to, mirror_from),
+                    (mirror_from, mirror_to),
+                ]
+            },
+        )
+    }
+
+    /// Replace every 0-based entry `(to, from)` by the average of the entries at
+    /// `counterparts(rank, to, from)`, and record `comment` on the copy.
+    fn averaged_over(
+        &self,
+        comment: &str,
+        counterparts: impl Fn(usize, usize, usize) -> Vec<(usize, usize)>,
+    ) -> Result<Network, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+
+        let mut network = self.clone();
+        for (data_line, point) in network.s.iter_mut().zip(self.points()?) {
+            let mut matrix = point.s.clone();
+            for to_port in 0..matrix.rank {
+                for from_port in 0..matrix.rank {
+                    let entries = counterparts(matrix.rank, to_port, from_port);
+                    let count = entries.len() as f64;
+                    matrix.data[to_port][from_port] = entries
+                        .into_iter()
+                        .map(|(other_to, other_from)| point.s.data[other_to][other_from])
+                        .fold(Complex::zero(), |sum, value| sum + value)
+                        / count;
+                }
+            }
+            *data_line = parsed_data_line_from_matrix(point.frequency, &matrix);
+        }
+        network.comments.push(comment.to_string());
+        Ok(network)
+    }
+
+    /// Compare every 0-based entry `(to, from)` with the entry at `counterpart(rank, to, from)`.
+    fn max_deviation(
+        &self,
+        counterpart: impl Fn(usize, usize, usize) -> (usize, usize),
+    ) -> Result<Vec<FrequencyDeviation>, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+
+        Ok(self
+            .points()?
+            .iter()
+            .map(|point| FrequencyDeviation {
+                frequency: point.frequency,
+                deviation: max_entry_deviation(&point.s, &counterpart),
+            })
+            .collect())
+    }
+}
+
+fn max_entry_deviation(
+    s: &SMatrix,
+    counterpart: &impl Fn(usize, usize, usize) -> (usize, usize),
+) -> f64 {
+    let rank = s.rank;
+    (0..rank)
+        .flat_map(|to_port| (0..rank).map(move |from_port| (to_port, from_port)))
+        .map(|(to_port, from_port)| {
+            let (other_to, other_from) = counterpart(rank, to_port, from_port);
+            (s.data[to_port][from_port] - s.data[other_to][other_from]).magnitude()
+        })
+        .fold(0.0, f64::max)
+}
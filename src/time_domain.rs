@@ -0,0 +1,846 @@
+use std::f64::consts::PI;
+
+use crate::network_builder::parsed_data_line_from_matrix;
+use crate::{validate_port_indexes, Complex, Network, ReferenceImpedance, TouchstoneError};
+
+/// Frequency-to-time transform mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeDomainMode {
+    /// Resample onto a harmonic grid, extrapolate to DC, and mirror to negative frequencies.
+    ///
+    /// The response is real, so impulse responses keep their sign and step responses and TDR
+    /// impedance profiles are available.
+    #[default]
+    LowPass,
+    /// Transform only the measured band.
+    ///
+    /// The response is complex; impulse responses return the magnitude of its envelope. Use this
+    /// mode for band-limited data that cannot be extrapolated to DC.
+    BandPass,
+}
+
+/// Window applied to the frequency data before the inverse transform.
+///
+/// Wider windows lower the sidelobes around each response at the cost of a wider main lobe.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum Window {
+    /// No windowing. Narrowest response with the highest sidelobes.
+    Rectangular,
+    /// Hann (raised-cosine) window.
+    Hann,
+    /// Kaiser window with shape parameter `beta`. `beta = 0` is rectangular; `6` is a common
+    /// default on network analyzers.
+    Kaiser {
+        /// Kaiser shape parameter. Must be finite and non-negative.
+        beta: f64,
+    },
+}
+
+impl Default for Window {
+    fn default() -> Self {
+        Self::Kaiser { beta: 6.0 }
+    }
+}
+
+/// Policy used to fill the DC point in [`TimeDomainMode::LowPass`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[non_exhaustive]
+pub enum DcExtrapolation {
+    /// Extrapolate magnitude and unwrapped phase linearly from the two lowest frequency points,
+    /// then round the phase to 0° or 180° so the DC value is real.
+    ///
+    /// When the network already contains a 0 Hz point, its real part is used instead.
+    #[default]
+    Linear,
+    /// Use a known real DC value, such as `-1` for a short or `1` for a DC-coupled through.
+    Value(f64),
+}
+
+/// Settings for [`Network::impulse_response`], [`Network::step_response`], and
+/// [`Network::tdr_impedance`].
+///
+/// By default the transform uses [`TimeDomainMode::LowPass`], a Kaiser window with `beta = 6`,
+/// linear DC extrapolation, and `4 * N` time points spanning one alias-free period centered on
+/// `t = 0`, where `N` is the number of frequency points.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TimeDomainOptions {
+    mode: TimeDomainMode,
+    window: Window,
+    dc_extrapolation: DcExtrapolation,
+    points: Option<usize>,
+    time_span: Option<(f64, f64)>,
+}
+
+impl TimeDomainOptions {
+    /// Create default time-domain settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the transform mode.
+    #[must_use]
+    pub fn mode(mut self, mode: TimeDomainMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the frequency-domain window.
+    #[must_use]
+    pub fn window(mut self, window: Window) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Set the DC extrapolation policy used in low-pass mode.
+    #[must_use]
+    pub fn dc_extrapolation(mut self, dc_extrapolation: DcExtrapolation) -> Self {
+        self.dc_extrapolation = dc_extrapolation;
+        self
+    }
+
+    /// Set the number of returned time points. At least 2 points are required.
+    #[must_use]
+    pub fn points(mut self, points: usize) -> Self {
+        self.points = Some(points);
+        self
+    }
+
+    /// Set the returned time range in seconds, including both endpoints.
+    ///
+    /// Times outside the alias-free period `1 / Δf` repeat the response.
+    #[must_use]
+    pub fn time_span(mut self, start_s: f64, stop_s: f64) -> Self {
+        self.time_span = Some((start_s, stop_s));
+        self
+    }
+
+    fn validate(&self) -> Result<(), TouchstoneError> {
+        if let Window::Kaiser { beta } = self.window {
+            if !beta.is_finite() || beta < 0.0 {
+                return Err(invalid_setting("Kaiser beta", beta));
+            }
+        }
+        if let DcExtrapolation::Value(value) = self.dc_extrapolation {
+            if !value.is_finite() {
+                return Err(invalid_setting("DC value", value));
+            }
+        }
+        if let Some(points) = self.points {
+            if points < 2 {
+                return Err(invalid_setting("point count", points as f64));
+            }
+        }
+        if let Some((start, stop)) = self.time_span {
+            if !start.is_finite() {
+                return Err(invalid_setting("time span start", start));
+            }
+            if !stop.is_finite() || stop <= start {
+                return Err(invalid_setting("time span stop", stop));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Time-domain response value at a single time.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::{Network, TimeDomainOptions};
+///
+/// let net = Network::new("files/ntwk1.s2p")?;
+/// let impulse = net.impulse_response(2, 1, &TimeDomainOptions::new())?;
+/// let point = &impulse[0];
+/// println!("t = {} s, h = {}", point.time, point.value);
+/// # Ok::<(), touchstone::TouchstoneError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimePoint {
+    /// Time in seconds.
+    pub time: f64,
+    /// Response value at `time`.
+    pub value: f64,
+}
+
+/// Whether a [`TimeGate`] keeps or removes the response inside the gate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GateMode {
+    /// Keep the response inside the gate and remove everything outside it.
+    #[default]
+    BandPass,
+    /// Remove the response inside the gate and keep everything outside it.
+    Notch,
+}
+
+/// Time gate applied by [`Network::time_gate`].
+///
+/// The gate is centered on `center_s` and is `span_s` wide, both in seconds. Its shape is a
+/// [`Window`] stretched over the span: [`Window::Rectangular`] is a hard gate, while Hann and
+/// Kaiser shapes roll off smoothly towards the gate edges. By default the gate is
+/// [`GateMode::BandPass`] with a Kaiser `beta = 6` shape, and the frequency data are windowed with
+/// a Kaiser `beta = 6` window before the transform.
+///
+/// # Examples
+///
+/// ```
+/// use touchstone::{GateMode, TimeGate, Window};
+///
+/// // Remove a connector reflection between 0.1 ns and 0.5 ns.
+/// let gate = TimeGate::new(0.3e-9, 0.4e-9)
+///     .mode(GateMode::Notch)
+///     .shape(Window::Hann);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeGate {
+    center_s: f64,
+    span_s: f64,
+    shape: Window,
+    mode: GateMode,
+    window: Window,
+}
+
+impl TimeGate {
+    /// Create a band-pass gate centered on `center_s` and `span_s` wide, both in seconds.
+    #[must_use]
+    pub fn new(center_s: f64, span_s: f64) -> Self {
+        Self {
+            center_s,
+            span_s,
+            shape: Window::default(),
+            mode: GateMode::default(),
+            window: Window::default(),
+        }
+    }
+
+    /// Set the gate shape.
+    #[must_use]
+    pub fn shape(mut self, shape: Window) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Set whether the gate keeps or removes the response inside it.
+    #[must_use]
+    pub fn mode(mut self, mode: GateMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the frequency-domain window applied before the transform and removed afterwards.
+    #[must_use]
+    pub fn window(mut self, window: Window) -> Self {
+        self.window = window;
+        self
+    }
+
+    fn validate(&self) -> Result<(), TouchstoneError> {
+        if !self.center_s.is_finite() {
+            return Err(invalid_setting("gate center", self.center_s));
+        }
+        if !self.span_s.is_finite() || self.span_s <= 0.0 {
+            return Err(invalid_setting("gate span", self.span_s));
+        }
+        if let Window::Kaiser { beta } = self.shape {
+            if !beta.is_finite() || beta < 0.0 {
+                return Err(invalid_setting("gate Kaiser beta", beta));
+            }
+        }
+        Ok(())
+    }
+
+    /// Gate weight at `time` seconds.
+    fn weight(&self, time: f64) -> f64 {
+        let x = (time - self.center_s) / (0.5 * self.span_s);
+        let inside = if x.abs() <= 1.0 {
+            self.shape.weight(x)
+        } else {
+            0.0
+        };
+        match self.mode {
+            GateMode::BandPass => inside,
+            GateMode::Notch => 1.0 - inside,
+        }
+    }
+}
+
+/// Windowed spectrum on a uniform frequency grid, ready for the inverse transform.
+///
+/// In low-pass mode `values[0]` is the DC point and `values[k]` sits at `k * df`; negative
+/// frequencies are the conjugate mirror. In band-pass mode `values[k]` sits at
+/// `f_min + k * df`.
+struct Spectrum {
+    mode: TimeDomainMode,
+    start: f64,
+    df: f64,
+    values: Vec<Complex>,
+    weights: Vec<f64>,
+}
+
+impl Spectrum {
+    /// Alias-free time period `1 / df` in seconds.
+    fn period(&self) -> f64 {
+        1.0 / self.df
+    }
+
+    /// Sum of window weights over every frequency used, including the low-pass negative mirror.
+    fn weight_sum(&self) -> f64 {
+        let sum = self.weights.iter().sum::<f64>();
+        match self.mode {
+            TimeDomainMode::LowPass => 2.0 * sum - self.weights[0],
+            TimeDomainMode::BandPass => sum,
+        }
+    }
+
+    /// Inverse transform at one time, normalized so a flat spectrum peaks at its own value.
+    fn impulse_at(&self, time: f64) -> Complex {
+        let sum = phased_sum(&self.values, self.df, time);
+        match self.mode {
+            TimeDomainMode::LowPass => {
+                let dc = self.values[0];
+                Complex {
+                    re: (2.0 * sum.re - dc.re) / self.weight_sum(),
+                    im: 0.0,
+                }
+            }
+            TimeDomainMode::BandPass => sum / self.weight_sum(),
+        }
+    }
+
+    /// Gate the band-pass time response and return the un-windowed spectrum on the same grid.
+    fn gated(&self, gate: &TimeGate) -> Vec<Complex> {
+        let count = self.values.len();
+        let size = (4 * count).next_power_of_two();
+        let mut buffer = vec![Complex { re: 0.0, im: 0.0 }; size];
+        buffer[..count].copy_from_slice(&self.values);
+
+        fft(&mut buffer, true);
+        let period = self.period();
+        let dt = period / size as f64;
+        for (index, value) in buffer.iter_mut().enumerate() {
+            // The upper half of the transform holds negative times.
+            let time = if index < size / 2 {
+                index as f64 * dt
+            } else {
+                index as f64 * dt - period
+            };
+            *value = *value * gate.weight(time);
+        }
+        fft(&mut buffer, false);
+
+        buffer[..count]
+            .iter()
+            .zip(&self.weights)
+            .map(|(value, weight)| *value / (size as f64 * weight))
+            .collect()
+    }
+
+    /// Running integral of the low-pass impulse density from the start of the alias-free period.
+    fn step_at(&self, time: f64) -> f64 {
+        // Each harmonic integrates in closed form from -T/2, where exp(j 2 pi k df t) = (-1)^k.
+        let rotation = phase(2.0 * PI * self.df * time);
+        let mut phasor = rotation;
+        let mut step = self.values[0].re * (self.df * time + 0.5);
+        for (k, value) in self.values.iter().enumerate().skip(1) {
+            let start = if k % 2 == 0 { 1.0 } else { -1.0 };
+            let integral = (phasor - Complex { re: start, im: 0.0 })
+                / Complex {
+                    re: 0.0,
+                    im: 2.0 * PI * k as f64,
+                };
+            step += 2.0 * (*value * integral).re;
+            phasor = phasor * rotation;
+        }
+        step
+    }
+}
+
+impl Network {
+    /// Return the time-domain impulse response of S(j,k).
+    ///
+    /// Port indices `j` and `k` are 1-indexed. Values are normalized so a frequency-flat
+    /// reflection or transmission of magnitude `A` peaks at `A`. In
+    /// [`TimeDomainMode::BandPass`] the magnitude of the complex response envelope is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Network, TimeDomainOptions, Window};
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let options = TimeDomainOptions::new().window(Window::Hann).points(512);
+    /// let impulse = net.impulse_response(1, 1, &options)?;
+    ///
+    /// assert_eq!(impulse.len(), 512);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "time domain")]
+    #[doc(alias = "inverse FFT")]
+    pub fn impulse_response(
+        &self,
+        j: usize,
+        k: usize,
+        options: &TimeDomainOptions,
+    ) -> Result<Vec<TimePoint>, TouchstoneError> {
+        let spectrum = self.spectrum(j, k, options)?;
+        Ok(time_axis(&spectrum, options)
+            .into_iter()
+            .map(|time| {
+                let value = spectrum.impulse_at(time);
+                TimePoint {
+                    time,
+                    value: match spectrum.mode {
+                        TimeDomainMode::LowPass => value.re,
+                        TimeDomainMode::BandPass => value.magnitude(),
+                    },
+                }
+            })
+            .collect())
+    }
+
+    /// Return the time-domain step response of S(j,k).
+    ///
+    /// Port indices `j` and `k` are 1-indexed. The step response is the running integral of the
+    /// impulse response and settles at the DC value of S(j,k). Only
+    /// [`TimeDomainMode::LowPass`] is supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Network, TimeDomainOptions};
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let step = net.step_response(2, 1, &TimeDomainOptions::new())?;
+    ///
+    /// assert!(step.iter().all(|point| point.value.is_finite()));
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "time domain")]
+    pub fn step_response(
+        &self,
+        j: usize,
+        k: usize,
+        options: &TimeDomainOptions,
+    ) -> Result<Vec<TimePoint>, TouchstoneError> {
+        if options.mode != TimeDomainMode::LowPass {
+            return Err(TouchstoneError::UnsupportedTimeDomainMode {
+                operation: "step response".to_string(),
+            });
+        }
+
+        let spectrum = self.spectrum(j, k, options)?;
+        Ok(time_axis(&spectrum, options)
+            .into_iter()
+            .map(|time| TimePoint {
+                time,
+                value: spectrum.step_at(time),
+            })
+            .collect())
+    }
+
+    /// Return the TDR impedance profile in ohms seen looking into `port`.
+    ///
+    /// The profile is `Z(t) = z0 (1 + rho(t)) / (1 - rho(t))`, where `rho(t)` is the step
+    /// response of S(port,port) and `z0` is the port's real reference impedance (for a common
+    /// reference, [`Network::z0`]). Only [`TimeDomainMode::LowPass`] is supported.
+    ///
+    /// Where `rho(t)` reaches 1, as for an open circuit, the impedance is [`f64::INFINITY`];
+    /// where it reaches −1, as for a short circuit, the impedance is 0. Step response ripple
+    /// beyond these limits is clamped rather than reported as a negative impedance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Network, TimeDomainOptions};
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let options = TimeDomainOptions::new().time_span(0.0, 2.0e-9).points(201);
+    /// let profile = net.tdr_impedance(1, &options)?;
+    ///
+    /// assert_eq!(profile.len(), 201);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "TDR")]
+    #[doc(alias = "time domain reflectometry")]
+    pub fn tdr_impedance(
+        &self,
+        port: usize,
+        options: &TimeDomainOptions,
+    ) -> Result<Vec<TimePoint>, TouchstoneError> {
+        if options.mode != TimeDomainMode::LowPass {
+            return Err(TouchstoneError::UnsupportedTimeDomainMode {
+                operation: "TDR impedance".to_string(),
+            });
+        }
+
+        validate_port_indexes(port, port, self.rank as usize)?;
+        let z0 = self.real_port_reference_impedance(port)?;
+
+        Ok(self
+            .step_response(port, port, options)?
+            .into_iter()
+            .map(|point| TimePoint {
+                time: point.time,
+                value: if point.value >= 1.0 {
+                    f64::INFINITY
+                } else {
+                    (z0 * (1.0 + point.value) / (1.0 - point.value)).max(0.0)
+                },
+            })
+            .collect())
+    }
+
+    /// Return a copy of this network with every S-parameter time gated.
+    ///
+    /// Each S(j,k) is windowed, transformed to time in band-pass mode, multiplied by the gate,
+    /// transformed back, and divided by the window again, so ungated responses pass through
+    /// unchanged. The returned network uses the same frequency grid and metadata, with a comment
+    /// recording the gate; data near the band edges are the least accurate. Pass the raw and
+    /// gated networks to [`generate_plot_from_networks()`](crate::generate_plot_from_networks)
+    /// to overlay them in one HTML plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{generate_plot_from_networks, GateMode, Network, TimeGate};
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let mut gated = net.time_gate(&TimeGate::new(0.0, 1.0e-9).mode(GateMode::Notch))?;
+    /// assert_eq!(gated.f, net.f);
+    ///
+    /// // Overlay the raw and gated reflections.
+    /// gated.name = "ntwk1_gated.s2p".to_string();
+    /// let plot = std::env::temp_dir().join("ntwk1_gated.html");
+    /// generate_plot_from_networks(&[net, gated], plot.to_str().unwrap())?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[doc(alias = "gating")]
+    #[doc(alias = "time domain")]
+    pub fn time_gate(&self, gate: &TimeGate) -> Result<Network, TouchstoneError> {
+        gate.validate()?;
+        let rank = self.rank as usize;
+        let options = TimeDomainOptions::new()
+            .mode(TimeDomainMode::BandPass)
+            .window(gate.window);
+
+        let mut matrices = (0..self.s.len())
+            .map(|point_index| self.s_matrix_at(point_index))
+            .collect::<Result<Vec<_>, _>>()?;
+        for j in 1..=rank {
+            for k in 1..=rank {
+                let spectrum = self.spectrum(j, k, &options)?;
+                let frequencies = (0..spectrum.values.len())
+                    .map(|index| spectrum.start + index as f64 * spectrum.df)
+                    .collect::<Vec<_>>();
+                // Subtract what the gate removed so ungated data pass through unchanged on
+                // non-uniform grids too.
+                let removed = spectrum
+                    .values
+                    .iter()
+                    .zip(&spectrum.weights)
+                    .zip(spectrum.gated(gate))
+                    .map(|((value, weight), gated)| *value / *weight - gated)
+                    .collect::<Vec<_>>();
+
+                for (matrix, frequency) in matrices.iter_mut().zip(&self.f) {
+                    let value = &mut matrix.data[j - 1][k - 1];
+                    *value = *value - interpolate_linear(&frequencies, &removed, *frequency);
+                }
+            }
+        }
+
+        let mut network = self.clone();
+        network.s = self
+            .f
+            .iter()
+            .zip(&matrices)
+            .map(|(frequency, matrix)| parsed_data_line_from_matrix(*frequency, matrix))
+            .collect();
+        network.comments.push(format!(
+            "! Time gated: {:?} gate, center {} s, span {} s, {:?} shape",
+            gate.mode, gate.center_s, gate.span_s, gate.shape
+        ));
+        Ok(network)
+    }
+
+    /// Build the windowed spectrum of S(j,k) for the requested transform mode.
+    fn spectrum(
+        &self,
+        j: usize,
+        k: usize,
+        options: &TimeDomainOptions,
+    ) -> Result<Spectrum, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+        options.validate()?;
+        self.validate_frequency_data()?;
+        validate_port_indexes(j, k, self.rank as usize)?;
+
+        let count = self.f.len();
+        if count < 2 {
+            return Err(TouchstoneError::InsufficientFrequencyPoints {
+                operation: "time-domain transform".to_string(),
+                required: 2,
+                actual: count,
+            });
+        }
+
+        let mut frequencies = self.f.clone();
+        let mut values = self
+            .s
+            .iter()
+            .map(|data_line| {
+                let value = data_line.s_ri.get(j, k);
+                Complex {
+                    re: value.0,
+                    im: value.1,
+                }
+            })
+            .collect::<Vec<_>>();
+        let max = frequencies[count - 1];
+
+        match options.mode {
+            TimeDomainMode::LowPass => {
+                if frequencies[0] < 0.0 {
+                    return Err(invalid_setting("low-pass start frequency", frequencies[0]));
+                }
+
+                let dc = match options.dc_extrapolation {
+                    DcExtrapolation::Value(value) => value,
+                    DcExtrapolation::Linear if frequencies[0] == 0.0 => values[0].re,
+                    DcExtrapolation::Linear => linear_dc_value(&frequencies, &values),
+                };
+                if frequencies[0] == 0.0 {
+                    values[0] = Complex { re: dc, im: 0.0 };
+                } else {
+                    frequencies.insert(0, 0.0);
+                    values.insert(0, Complex { re: dc, im: 0.0 });
+                }
+
+                let df = max / count as f64;
+                let weights = (0..=count)
+                    .map(|index| options.window.weight(index as f64 / (count + 1) as f64))
+                    .collect::<Vec<_>>();
+                let values = weights
+                    .iter()
+                    .enumerate()
+                    .map(|(index, weight)| {
+                        interpolate_linear(&frequencies, &values, index as f64 * df) * *weight
+                    })
+                    .collect();
+
+                Ok(Spectrum {
+                    mode: TimeDomainMode::LowPass,
+                    start: 0.0,
+                    df,
+                    values,
+                    weights,
+                })
+            }
+            TimeDomainMode::BandPass => {
+                let start = frequencies[0];
+                let df = (max - start) / (count - 1) as f64;
+                let weights = (0..count)
+                    .map(|index| {
+                        let position =
+                            (2.0 * index as f64 - (count - 1) as f64) / (count + 1) as f64;
+                        options.window.weight(position)
+                    })
+                    .collect::<Vec<_>>();
+                let values = weights
+                    .iter()
+                    .enumerate()
+                    .map(|(index, weight)| {
+                        interpolate_linear(&frequencies, &values, start + index as f64 * df)
+                            * *weight
+                    })
+                    .collect();
+
+                Ok(Spectrum {
+                    mode: TimeDomainMode::BandPass,
+                    start,
+                    df,
+                    values,
+                    weights,
+                })
+            }
+        }
+    }
+
+    pub(crate) fn real_port_reference_impedance(
+        &self,
+        port: usize,
+    ) -> Result<f64, TouchstoneError> {
+        let reference_impedance = self.reference_impedance();
+        if let ReferenceImpedance::Common(z0) = reference_impedance {
+            crate::validate_reference_impedance(z0)?;
+            return Ok(z0);
+        }
+
+        let values = reference_impedance
+            .real_per_port_values()
+            .ok_or(TouchstoneError::UnsupportedComplexReferenceImpedance)?;
+        let rank = self.rank as usize;
+        if values.len() != rank {
+            return Err(TouchstoneError::InvalidReferenceImpedanceCount {
+                ports: rank,
+                actual: values.len(),
+            });
+        }
+
+        crate::validate_reference_impedance(values[port - 1])?;
+        Ok(values[port - 1])
+    }
+}
+
+impl Window {
+    /// Window weight at normalized position `x`, where `x = 0` is the center and `|x| = 1` the
+    /// edge.
+    fn weight(self, x: f64) -> f64 {
+        match self {
+            Self::Rectangular => 1.0,
+            Self::Hann => 0.5 * (1.0 + (PI * x).cos()),
+            Self::Kaiser { beta } => {
+                bessel_i0(beta * (1.0 - x * x).max(0.0).sqrt()) / bessel_i0(beta)
+            }
+        }
+    }
+}
+
+/// Return the requested time grid, or one alias-free period centered on `t = 0`.
+fn time_axis(spectrum: &Spectrum, options: &TimeDomainOptions) -> Vec<f64> {
+    let frequency_points = match spectrum.mode {
+        TimeDomainMode::LowPass => spectrum.values.len() - 1,
+        TimeDomainMode::BandPass => spectrum.values.len(),
+    };
+    let points = options.points.unwrap_or(4 * frequency_points);
+
+    match options.time_span {
+        Some((start, stop)) => {
+            let step = (stop - start) / (points - 1) as f64;
+            (0..points)
+                .map(|index| start + index as f64 * step)
+                .collect()
+        }
+        None => {
+            let period = spectrum.period();
+            let step = period / points as f64;
+            (0..points)
+                .map(|index| -0.5 * period + index as f64 * step)
+                .collect()
+        }
+    }
+}
+
+/// In-place radix-2 FFT without scaling. `inverse` selects the `exp(+j ...)` kernel. The length
+/// must be a power of two.
+pub(crate) fn fft(values: &mut [Complex], inverse: bool) {
+    let size = values.len();
+
+    let mut reversed = 0;
+    for index in 1..size {
+        let mut bit = size >> 1;
+        while reversed & bit != 0 {
+            reversed ^= bit;
+            bit >>= 1;
+        }
+        reversed |= bit;
+        if index < reversed {
+            values.swap(index, reversed);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut length = 2;
+    while length <= size {
+        let rotation = phase(sign * 2.0 * PI / length as f64);
+        for chunk in values.chunks_mut(length) {
+            let (lower, upper) = chunk.split_at_mut(length / 2);
+            let mut twiddle = Complex { re: 1.0, im: 0.0 };
+            for (even, odd) in lower.iter_mut().zip(upper) {
+                let product = *odd * twiddle;
+                *odd = *even - product;
+                *even = *even + product;
+                twiddle = twiddle * rotation;
+            }
+        }
+        length <<= 1;
+    }
+}
+
+/// Return `sum(values[k] * exp(j 2 pi k df t))`.
+fn phased_sum(values: &[Complex], df: f64, time: f64) -> Complex {
+    let rotation = phase(2.0 * PI * df * time);
+    let mut phasor = Complex { re: 1.0, im: 0.0 };
+    let mut sum = Complex { re: 0.0, im: 0.0 };
+    for value in values {
+        sum = sum + *value * phasor;
+        phasor = phasor * rotation;
+    }
+    sum
+}
+
+fn phase(radians: f64) -> Complex {
+    Complex {
+        re: radians.cos(),
+        im: radians.sin(),
+    }
+}
+
+/// Linearly interpolate real/imaginary values on a strictly increasing grid, holding the boundary
+/// values outside it.
+pub(crate) fn interpolate_linear(
+    frequencies: &[f64],
+    values: &[Complex],
+    frequency: f64,
+) -> Complex {
+    let upper_index = frequencies.partition_point(|value| *value < frequency);
+    if upper_index == 0 {
+        return values[0];
+    }
+    if upper_index == frequencies.len() {
+        return values[values.len() - 1];
+    }
+
+    let lower_index = upper_index - 1;
+    let t = (frequency - frequencies[lower_index])
+        / (frequencies[upper_index] - frequencies[lower_index]);
+    values[lower_index] + (values[upper_index] - values[lower_index]) * t
+}
+
+/// Extrapolate magnitude and unwrapped phase from the first two points to a real DC value.
+pub(crate) fn linear_dc_value(frequencies: &[f64], values: &[Complex]) -> f64 {
+    let scale = frequencies[0] / (frequencies[1] - frequencies[0]);
+
+    let magnitude = values[0].magnitude() - scale * (values[1].magnitude() - values[0].magnitude());
+    let first_phase = values[0].im.atan2(values[0].re);
+    let mut phase_step = values[1].im.atan2(values[1].re) - first_phase;
+    phase_step -= 2.0 * PI * (phase_step / (2.0 * PI)).round();
+    let dc_phase = first_phase - scale * phase_step;
+
+    magnitude.max(0.0).copysign(dc_phase.cos())
+}
+
+/// Modified Bessel function of the first kind, order zero.
+fn bessel_i0(x: f64) -> f64 {
+    let quarter_x_squared = 0.25 * x * x;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut k = 1.0;
+    while term > sum * 1.0e-16 {
+        term *= quarter_x_squared / (k * k);
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
+
+fn invalid_setting(setting: &str, value: f64) -> TouchstoneError {
+    TouchstoneError::InvalidTimeDomainSetting {
+        setting: setting.to_string(),
+        value,
+    }
+}
@@ -0,0 +1,279 @@
+use crate::network_builder::NetworkBuilder;
+use crate::{
+    invert_matrix, matrix_mul, matrix_sub, validate_matrix_data, validate_port_indexes, Complex,
+    Network, SMatrix, TouchstoneError, PARAMETER_CONVERSION_TOLERANCE,
+};
+
+/// How the ports of a 2N-port are split into N input ports and N output ports for transfer
+/// (T) parameters.
+///
+/// Both groupings list the input ports and the output ports in increasing order, so input port
+/// `k` is connected to output port `k` of the previous network when T-matrices are chained.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PortGrouping {
+    /// Ports `1..=N` are inputs and ports `N+1..=2N` are outputs.
+    #[default]
+    Sequential,
+    /// Odd ports are inputs and even ports are outputs, as in `1 → 2`, `3 → 4`.
+    Interleaved,
+}
+
+impl PortGrouping {
+    /// Return the 1-based input ports followed by the 1-based output ports of a `rank`-port.
+    fn order(self, rank: usize) -> Vec<usize> {
+        let half = rank / 2;
+        match self {
+            Self::Sequential => (1..=rank).collect(),
+            Self::Interleaved => (0..half)
+                .map(|index| 2 * index + 1)
+                .chain((0..half).map(|index| 2 * index + 2))
+                .collect(),
+        }
+    }
+}
+
+/// Stable scattering transfer (T) parameter matrix for one frequency point.
+///
+/// With the ports split into inputs `1` and outputs `2` by a [`PortGrouping`], the matrix maps
+/// the output waves to the input waves as `[b1; a1] = T [a2; b2]`, so cascading networks
+/// multiplies their T-matrices in order. Rows and columns follow that block layout rather than
+/// port numbers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TMatrix {
+    /// Number of ports of the network, which is also the size of this square matrix.
+    pub rank: usize,
+    /// Matrix values in row-major order.
+    pub data: Vec<Vec<Complex>>,
+}
+
+impl TMatrix {
+    /// Return a T-matrix entry using 1-based row and column indexes.
+    pub fn get(&self, row: usize, column: usize) -> Result<Complex, TouchstoneError> {
+        validate_port_indexes(row, column, self.rank)?;
+        self.data
+            .get(row - 1)
+            .and_then(|values| values.get(column - 1))
+            .copied()
+            .ok_or(TouchstoneError::InvalidPortIndex {
+                to_port: row,
+                from_port: column,
+                rank: self.rank,
+            })
+    }
+
+    /// Convert this T-matrix to an S-parameter matrix whose ports are split by `grouping`.
+    ///
+    /// Uses `S21 = T22^-1`, `S22 = -T22^-1 T21`, `S11 = T12 T22^-1`, and
+    /// `S12 = T11 - T12 T22^-1 T21` on the N-by-N blocks.
+    pub fn to_s_matrix(&self, grouping: PortGrouping) -> Result<SMatrix, TouchstoneError> {
+        validate_matrix_data("T", self.rank, &self.data)?;
+        validate_even_rank(self.rank)?;
+
+        let [t11, t12, t21, t22] = split_blocks(&self.data);
+        let t22_inverse = invert_matrix(
+            t22,
+            "T to S matrix inversion",
+            PARAMETER_CONVERSION_TOLERANCE,
+        )?;
+        let t12_t22_inverse = matrix_mul(&t12, &t22_inverse);
+        let s11 = t12_t22_inverse.clone();
+        let s12 = matrix_sub(&t11, &matrix_mul(&t12_t22_inverse, &t21));
+        let s22 = block_neg(&matrix_mul(&t22_inverse, &t21));
+        let s21 = t22_inverse;
+
+        let grouped = join_blocks([s11, s12, s21, s22]);
+        let order = grouping.order(self.rank);
+        let mut data = vec![vec![Complex::zero(); self.rank]; self.rank];
+        for (row, to_port) in order.iter().enumerate() {
+            for (column, from_port) in order.iter().enumerate() {
+                data[to_port - 1][from_port - 1] = grouped[row][column];
+            }
+        }
+
+        Ok(SMatrix {
+            rank: self.rank,
+            data,
+        })
+    }
+}
+
+impl SMatrix {
+    /// Convert this S-parameter matrix to scattering transfer (T) parameters.
+    ///
+    /// The rank must be even; `grouping` selects which ports are inputs and which are outputs.
+    /// Returns [`TouchstoneError::InvalidNetworkRank`] for odd ranks and
+    /// [`TouchstoneError::SingularMatrix`] when the input-to-output transmission block `S21` cannot
+    /// be inverted, as for a network with no transmission.
+    ///
+    /// Uses `T11 = S12 - S11 S21^-1 S22`, `T12 = S11 S21^-1`, `T21 = -S21^-1 S22`, and
+    /// `T22 = S21^-1` on the N-by-N blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Complex, PortGrouping, SMatrix};
+    ///
+    /// // A matched attenuator with a transmission of 0.5 in both directions.
+    /// let half = Complex { re: 0.5, im: 0.0 };
+    /// let zero = Complex { re: 0.0, im: 0.0 };
+    /// let attenuator = SMatrix {
+    ///     rank: 2,
+    ///     data: vec![vec![zero, half], vec![half, zero]],
+    /// };
+    ///
+    /// let t = attenuator.to_t_matrix(PortGrouping::Sequential)?;
+    /// assert_eq!(t.get(2, 2)?.re, 2.0);
+    /// assert_eq!(SMatrix::try_from_t_matrix(&t, PortGrouping::Sequential)?, attenuator);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    #[doc(alias = "transfer parameters")]
+    #[doc(alias = "scattering transfer")]
+    pub fn to_t_matrix(&self, grouping: PortGrouping) -> Result<TMatrix, TouchstoneError> {
+        validate_matrix_data("S", self.rank, &self.data)?;
+        validate_even_rank(self.rank)?;
+
+        let order = grouping.order(self.rank);
+        let grouped = order
+            .iter()
+            .map(|to_port| {
+                order
+                    .iter()
+                    .map(|from_port| self.data[to_port - 1][from_port - 1])
+                    .collect()
+            })
+            .collect::<Vec<Vec<Complex>>>();
+
+        let [s11, s12, s21, s22] = split_blocks(&grouped);
+        let s21_inverse = invert_matrix(
+            s21,
+            "S to T matrix inversion",
+            PARAMETER_CONVERSION_TOLERANCE,
+        )?;
+        let s11_s21_inverse = matrix_mul(&s11, &s21_inverse);
+        let t11 = matrix_sub(&s12, &matrix_mul(&s11_s21_inverse, &s22));
+        let t21 = block_neg(&matrix_mul(&s21_inverse, &s22));
+
+        Ok(TMatrix {
+            rank: self.rank,
+            data: join_blocks([t11, s11_s21_inverse, t21, s21_inverse]),
+        })
+    }
+
+    /// Convert scattering transfer (T) parameters to an S-parameter matrix.
+    ///
+    /// `grouping` must match the one used to build `matrix`. Returns
+    /// [`TouchstoneError::SingularMatrix`] when the `T22` block cannot be inverted.
+    pub fn try_from_t_matrix(
+        matrix: &TMatrix,
+        grouping: PortGrouping,
+    ) -> Result<Self, TouchstoneError> {
+        matrix.to_s_matrix(grouping)
+    }
+}
+
+impl Network {
+    /// Return the scattering transfer (T) parameters at every frequency point.
+    ///
+    /// The network must contain S-parameter data with an even number of ports. Matrices are
+    /// returned in the order of [`Network::f`]; see [`SMatrix::to_t_matrix`] for the convention.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use touchstone::{Network, PortGrouping};
+    ///
+    /// let net = Network::new("files/ntwk1.s2p")?;
+    /// let t = net.to_t(PortGrouping::Sequential)?;
+    /// let round_trip =
+    ///     Network::from_t("round_trip.s2p", &net.f, &t, net.z0, PortGrouping::Sequential)?;
+    ///
+    /// assert_eq!(t.len(), net.f.len());
+    /// let error = round_trip.try_s_ri_at(0, 2, 1)? - net.try_s_ri_at(0, 2, 1)?;
+    /// assert!(error.re.abs() < 1e-9 && error.im.abs() < 1e-9);
+    /// # Ok::<(), touchstone::TouchstoneError>(())
+    /// ```
+    pub fn to_t(&self, grouping: PortGrouping) -> Result<Vec<TMatrix>, TouchstoneError> {
+        self.ensure_s_parameter_data()?;
+
+        (0..self.s.len())
+            .map(|point_index| self.s_matrix_at(point_index)?.to_t_matrix(grouping))
+            .collect()
+    }
+
+    /// Build an S-parameter network from one T-matrix per frequency.
+    ///
+    /// `frequencies` are in Hz and must have the same length as `matrices`, which must not be
+    /// empty. `z0` is the common reference impedance in ohms the T-parameters were derived with.
+    pub fn from_t<S: Into<String>>(
+        name: S,
+        frequencies: &[f64],
+        matrices: &[TMatrix],
+        z0: f64,
+        grouping: PortGrouping,
+    ) -> Result<Network, TouchstoneError> {
+        if frequencies.len() != matrices.len() {
+            return Err(TouchstoneError::FrequencyDataLengthMismatch {
+                frequency_count: frequencies.len(),
+                data_count: matrices.len(),
+            });
+        }
+
+        let rank = matrices
+            .first()
+            .ok_or(TouchstoneError::EmptyNetworkData)?
+            .rank;
+        let mut builder = NetworkBuilder::new(name, rank).z0(z0);
+        for (frequency, matrix) in frequencies.iter().zip(matrices) {
+            builder.push_point(*frequency, matrix.to_s_matrix(grouping)?);
+        }
+        builder.build()
+    }
+}
+
+// `usize::is_multiple_of` needs Rust 1.87, newer than the crate otherwise requires.
+#[allow(clippy::manual_is_multiple_of)]
+fn validate_even_rank(rank: usize) -> Result<(), TouchstoneError> {
+    if rank % 2 == 0 {
+        Ok(())
+    } else {
+        Err(TouchstoneError::InvalidNetworkRank { rank })
+    }
+}
+
+/// Split a 2N-by-2N matrix into its `[11, 12, 21, 22]` N-by-N blocks.
+fn split_blocks(matrix: &[Vec<Complex>]) -> [Vec<Vec<Complex>>; 4] {
+    let half = matrix.len() / 2;
+    let block = |rows: std::ops::Range<usize>, columns: std::ops::Range<usize>| {
+        matrix[rows]
+            .iter()
+            .map(|row| row[columns.clone()].to_vec())
+            .collect::<Vec<_>>()
+    };
+    [
+        block(0..half, 0..half),
+        block(0..half, half..2 * half),
+        block(half..2 * half, 0..half),
+        block(half..2 * half, half..2 * half),
+    ]
+}
+
+/// Join `[11, 12, 21, 22]` N-by-N blocks into one 2N-by-2N matrix.
+fn join_blocks([b11, b12, b21, b22]: [Vec<Vec<Complex>>; 4]) -> Vec<Vec<Complex>> {
+    let top = b11
+        .into_iter()
+        .zip(b12)
+        .map(|(left, right)| [left, right].concat());
+    let bottom = b21
+        .into_iter()
+        .zip(b22)
+        .map(|(left, right)| [left, right].concat());
+    top.chain(bottom).collect()
+}
+
+fn block_neg(matrix: &[Vec<Complex>]) -> Vec<Vec<Complex>> {
+    matrix
+        .iter()
+        .map(|row| row.iter().map(|value| -*value).collect())
+        .collect()
+}
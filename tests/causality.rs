@@ -0,0 +1,122 @@
+use std::f64::consts::PI;
+
+use touchstone::{Complex, Network, NetworkBuilder, SMatrix, TouchstoneError};
+
+/// Sample `response` as a 2-port with S21 = S12 = response and matched ports, 10 MHz to 10 GHz.
+fn two_port(response: impl Fn(f64) -> Complex) -> Network {
+    let mut builder = NetworkBuilder::new("model.s2p", 2);
+    for index in 0..201 {
+        let frequency = 10.0e6 + index as f64 * (10.0e9 - 10.0e6) / 200.0;
+        let value = response(frequency);
+        let zero = Complex { re: 0.0, im: 0.0 };
+        builder.push_point(
+            frequency,
+            SMatrix {
+                rank: 2,
+                data: vec![vec![zero, value], vec![value, zero]],
+            },
+        );
+    }
+    builder.build().unwrap()
+}
+
+fn delay(frequency: f64, seconds: f64) -> Complex {
+    let radians = -2.0 * PI * frequency * seconds;
+    Complex {
+        re: 0.9 * radians.cos(),
+        im: 0.9 * radians.sin(),
+    }
+}
+
+/// First-order low-pass response `1 / (1 + j·sign·f/f0)`; `sign = -1` puts the pole in the
+/// non-causal half-plane.
+fn single_pole(frequency: f64, sign: f64) -> Complex {
+    Complex { re: 1.0, im: 0.0 }
+        / Complex {
+            re: 1.0,
+            im: sign * frequency / 1.0e9,
+        }
+}
+
+fn max_deviation(network: &Network, j: usize, k: usize) -> f64 {
+    network
+        .causality_error(j, k)
+        .unwrap()
+        .iter()
+        .map(|point| point.deviation)
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn delay_line_is_causal() {
+    let network = two_port(|frequency| delay(frequency, 0.5e-9));
+
+    let errors = network.causality_error(2, 1).unwrap();
+
+    assert_eq!(errors.len(), network.f.len());
+    assert_eq!(errors[0].frequency, network.f[0]);
+    assert!(max_deviation(&network, 2, 1) < 0.01);
+    assert!(network.is_causal(0.01).unwrap());
+}
+
+#[test]
+fn time_advance_is_entirely_non_causal() {
+    let network = two_port(|frequency| delay(frequency, -0.5e-9));
+
+    for point in network.causality_error(2, 1).unwrap() {
+        assert!((point.deviation - 0.9).abs() < 0.01, "{point:?}");
+    }
+    assert!(!network.is_causal(0.1).unwrap());
+}
+
+#[test]
+fn non_causal_pole_is_detected_in_band() {
+    let causal = two_port(|frequency| single_pole(frequency, 1.0));
+    let non_causal = two_port(|frequency| single_pole(frequency, -1.0));
+
+    // Compare the middle of the band, away from the truncation error at the band edge.
+    let mid_band = |network: &Network| network.causality_error(2, 1).unwrap()[100].deviation;
+
+    assert!(mid_band(&causal) < 0.03);
+    assert!(mid_band(&non_causal) > 0.15);
+}
+
+#[test]
+fn measured_multiport_is_nearly_causal_in_band() {
+    let network = Network::new("files/Agilent_E5071B.s4p").unwrap();
+
+    let mut errors = network
+        .causality_error(2, 1)
+        .unwrap()
+        .into_iter()
+        .map(|point| point.deviation)
+        .collect::<Vec<_>>();
+    errors.sort_by(f64::total_cmp);
+
+    assert!(errors[errors.len() / 2] < 0.01);
+}
+
+#[test]
+fn causality_validates_inputs() {
+    let network = Network::new("files/ntwk1.s2p").unwrap();
+    let single_point = Network::from_str("single.s1p", "# GHz S RI R 50\n1.0 0.5 0.0\n").unwrap();
+    let admittance =
+        Network::from_str("uploaded.s1p", "# Hz Y RI R 50\n1000000000 0.02 0.0\n").unwrap();
+
+    assert!(matches!(
+        network.causality_error(3, 1).unwrap_err(),
+        TouchstoneError::InvalidPortIndex { .. }
+    ));
+    assert!(matches!(
+        single_point.causality_error(1, 1).unwrap_err(),
+        TouchstoneError::InsufficientFrequencyPoints {
+            required: 2,
+            actual: 1,
+            ..
+        }
+    ));
+    assert!(matches!(
+        admittance.is_causal(0.01).unwrap_err(),
+        TouchstoneError::UnsupportedNetworkParameter { .. }
+    ));
+}
@@ -0,0 +1,160 @@
+use touchstone::{Complex, Network, NoiseParameters, StabilityCircle, TouchstoneError};
+
+mod common;
+
+use common::REAL_AMPLIFIER;
+
+fn magnitude(value: Complex) -> f64 {
+    value.re.hypot(value.im)
+}
+
+fn on_circle(center: Complex, radius: f64, angle: f64) -> Complex {
+    center
+        + Complex {
+            re: radius * angle.cos(),
+            im: radius * angle.sin(),
+        }
+}
+
+fn angles() -> impl Iterator<Item = f64> {
+    (0..8).map(|step| step as f64 * std::f64::consts::FRAC_PI_4)
+}
+
+fn entries(network: &Network, point_index: usize) -> [Complex; 4] {
+    let s = network.s_matrix_at(point_index).unwrap();
+    [
+        s.get(1, 1).unwrap(),
+        s.get(1, 2).unwrap(),
+        s.get(2, 1).unwrap(),
+        s.get(2, 2).unwrap(),
+    ]
+}
+
+#[test]
+fn stability_circles_bound_unit_reflection_at_opposite_port() {
+    let network = Network::new("files/fet.s2p").unwrap();
+    let one = Complex { re: 1.0, im: 0.0 };
+
+    let input = network.input_stability_circles().unwrap();
+    let output = network.output_stability_circles().unwrap();
+
+    assert_eq!(input.len(), network.f.len());
+    for point_index in 0..network.f.len() {
+        let [s11, s12, s21, s22] = entries(&network, point_index);
+        for angle in angles() {
+            let gamma_s = on_circle(input[point_index].center, input[point_index].radius, angle);
+            let gamma_out = s22 + s12 * s21 * gamma_s / (one - s11 * gamma_s);
+            assert!((magnitude(gamma_out) - 1.0).abs() < 1.0e-9, "{gamma_out:?}");
+
+            let gamma_l = on_circle(
+                output[point_index].center,
+                output[point_index].radius,
+                angle,
+            );
+            let gamma_in = s11 + s12 * s21 * gamma_l / (one - s22 * gamma_l);
+            assert!((magnitude(gamma_in) - 1.0).abs() < 1.0e-9, "{gamma_in:?}");
+        }
+    }
+}
+
+#[test]
+fn stable_region_contains_matched_termination_when_ports_are_passive() {
+    let network = Network::from_str("amp.s2p", REAL_AMPLIFIER).unwrap();
+    let matched = Complex { re: 0.0, im: 0.0 };
+
+    let input: StabilityCircle = network.input_stability_circles().unwrap()[0];
+    let output: StabilityCircle = network.output_stability_circles().unwrap()[0];
+
+    assert!(input.is_stable(matched));
+    assert!(output.is_stable(matched));
+    // Unconditionally stable: the whole unit disk is stable, so the circles lie outside it.
+    assert!(!input.stable_inside);
+    assert!(magnitude(input.center) - input.radius > 1.0);
+}
+
+#[test]
+fn gain_circles_trace_requested_gain() {
+    let network = Network::from_str("amp.s2p", REAL_AMPLIFIER).unwrap();
+    let gain_db = 7.0;
+
+    let available = network.available_gain_circles(gain_db).unwrap()[0];
+    let operating = network.operating_gain_circles(gain_db).unwrap()[0];
+
+    assert_eq!(available.frequency, 1.0e9);
+    for angle in angles() {
+        let gamma_s = on_circle(available.center, available.radius, angle);
+        let g_a = network.available_gain(gamma_s).unwrap()[0];
+        assert!((g_a.gain_db() - gain_db).abs() < 1.0e-9, "{g_a:?}");
+
+        let gamma_l = on_circle(operating.center, operating.radius, angle);
+        let g_p = network.operating_gain(gamma_l).unwrap()[0];
+        assert!((g_p.gain_db() - gain_db).abs() < 1.0e-9, "{g_p:?}");
+    }
+}
+
+#[test]
+fn gain_circle_above_mag_does_not_exist() {
+    let network = Network::from_str("amp.s2p", REAL_AMPLIFIER).unwrap();
+    let mag_db = network.gain_metrics().unwrap()[0].max_gain_db();
+
+    let below_mag = network.available_gain_circles(mag_db - 0.01).unwrap()[0];
+    let above_mag = network.available_gain_circles(mag_db + 1.0).unwrap()[0];
+
+    // The circle shrinks to the simultaneous-match point as the gain approaches MAG.
+    assert!(below_mag.radius > 0.0 && below_mag.radius < 0.1);
+    assert!(above_mag.radius.is_nan());
+}
+
+#[test]
+fn noise_circles_trace_requested_noise_figure() {
+    let network = Network::from_str("amp.s2p", REAL_AMPLIFIER).unwrap();
+    let parameters = NoiseParameters {
+        frequency: 1.0e9,
+        min_noise_figure_db: 0.7,
+        gamma_opt: Complex { re: 0.3, im: -0.4 },
+        noise_resistance: 8.0,
+    };
+    let noise_figure_db = 1.5;
+
+    let circles = network
+        .noise_circles(&[parameters], noise_figure_db)
+        .unwrap();
+    let at_minimum = network
+        .noise_circles(&[parameters], parameters.min_noise_figure_db)
+        .unwrap();
+
+    assert_eq!(circles[0].frequency, 1.0e9);
+    assert_eq!(at_minimum[0].center, parameters.gamma_opt);
+    assert_eq!(at_minimum[0].radius, 0.0);
+
+    let f_min = 10.0_f64.powf(parameters.min_noise_figure_db / 10.0);
+    let rn = parameters.noise_resistance / 50.0;
+    let one = Complex { re: 1.0, im: 0.0 };
+    for angle in angles() {
+        let gamma_s = on_circle(circles[0].center, circles[0].radius, angle);
+        let noise_factor = f_min
+            + 4.0 * rn * magnitude(gamma_s - parameters.gamma_opt).powi(2)
+                / ((1.0 - magnitude(gamma_s).powi(2))
+                    * magnitude(one + parameters.gamma_opt).powi(2));
+        assert!((10.0 * noise_factor.log10() - noise_figure_db).abs() < 1.0e-9);
+    }
+}
+
+#[test]
+fn circles_require_two_port_network() {
+    let one_port = Network::new("files/hfss_oneport.s1p").unwrap();
+    let three_port = Network::new("files/hfss_18.2.s3p").unwrap();
+
+    assert!(matches!(
+        one_port.input_stability_circles().unwrap_err(),
+        TouchstoneError::InvalidNetworkRank { rank: 1 }
+    ));
+    assert!(matches!(
+        three_port.operating_gain_circles(3.0).unwrap_err(),
+        TouchstoneError::InvalidNetworkRank { rank: 3 }
+    ));
+    assert!(matches!(
+        one_port.noise_circles(&[], 1.0).unwrap_err(),
+        TouchstoneError::InvalidNetworkRank { rank: 1 }
+    ));
+}
@@ -0,0 +1,90 @@
+//! Assertions and fixtures shared by the integration tests.
+
+// Each test crate compiles this module separately and uses only some of the helpers.
+#![allow(dead_code)]
+
+use touchstone::{Complex, Network, NetworkBuilder, SMatrix};
+
+/// Absolute tolerance for values that are computed in closed form.
+pub const TOLERANCE: f64 = 1.0e-12;
+
+/// Assert that `actual` is within [`TOLERANCE`] of `expected`.
+pub fn assert_close(actual: f64, expected: f64) {
+    assert_close_within(actual, expected, TOLERANCE);
+}
+
+/// Assert that `actual` is within `tolerance` of `expected`.
+pub fn assert_close_within(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "expected {actual} to be within {tolerance} of {expected}"
+    );
+}
+
+/// S11 = 0.5, S21 = 2, S12 = 0.1, S22 = 0.4 (all real, Δ = 0, K = 1.475, MAG ≈ 8.9 dB).
+pub const REAL_AMPLIFIER: &str = "# GHz S RI R 50\n1.0 0.5 0.0 2.0 0.0 0.1 0.0 0.4 0.0\n";
+
+/// One-way delay of [`delay_line`] in seconds.
+pub const DELAY_S: f64 = 1.0e-9;
+
+/// Matched 2-port delay line with `S21 = S12 = exp(-j 2 pi f tau)` from 0.1 to 10 GHz.
+pub fn delay_line() -> Network {
+    let mut builder = NetworkBuilder::new("delay.s2p", 2);
+    for index in 1..=100 {
+        let frequency = index as f64 * 1.0e8;
+        let angle = -2.0 * std::f64::consts::PI * frequency * DELAY_S;
+        let through = Complex {
+            re: angle.cos(),
+            im: angle.sin(),
+        };
+        let zero = Complex { re: 0.0, im: 0.0 };
+        builder.push_point(
+            frequency,
+            SMatrix {
+                rank: 2,
+                data: vec![vec![zero, through], vec![through, zero]],
+            },
+        );
+    }
+    builder.build().unwrap()
+}
+
+/// Shorthand for a complex literal.
+pub fn c(re: f64, im: f64) -> Complex {
+    Complex { re, im }
+}
+
+/// Build a square S-matrix from its rows.
+pub fn s_matrix(data: Vec<Vec<Complex>>) -> SMatrix {
+    SMatrix {
+        rank: data.len(),
+        data,
+    }
+}
+
+/// Assert that both parts of `actual` are within [`TOLERANCE`] of `expected`.
+pub fn assert_complex_close(actual: Complex, expected: Complex) {
+    assert_complex_close_within(actual, expected, TOLERANCE);
+}
+
+/// Assert that both parts of `actual` are within `tolerance` of `expected`.
+pub fn assert_complex_close_within(actual: Complex, expected: Complex, tolerance: f64) {
+    assert!(
+        (actual.re - expected.re).abs() <= tolerance
+            && (actual.im - expected.im).abs() <= tolerance,
+        "expected {actual:?} to be within {tolerance} of {expected:?}"
+    );
+}
+
+/// Assert that every entry of `actual` is within [`TOLERANCE`] of `expected`.
+pub fn assert_s_matrix_close(actual: &SMatrix, expected: &SMatrix) {
+    assert_eq!(actual.rank, expected.rank);
+    for row in 1..=actual.rank {
+        for column in 1..=actual.rank {
+            assert_complex_close(
+                actual.get(row, column).unwrap(),
+                expected.get(row, column).unwrap(),
+            );
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use touchstone::{Complex, Network, ParameterMatrix, TouchstoneError};
+
+mod common;
+
+use common::assert_complex_close;
+
+/// A series resistor of `ohms` between the two ports of a 50 ohm 2-port.
+fn series_resistor(name: &str, ohms: f64) -> Network {
+    let s11 = ohms / (ohms + 100.0);
+    let s21 = 100.0 / (ohms + 100.0);
+    let text = format!("# GHz S RI R 50\n1.0 {s11} 0 {s21} 0 {s21} 0 {s11} 0\n2.0 {s11} 0 {s21} 0 {s21} 0 {s11} 0\n");
+    Network::from_str(name, &text).unwrap()
+}
+
+fn assert_sum(sum: &ParameterMatrix, first: &ParameterMatrix, second: &ParameterMatrix) {
+    for row in 1..=2 {
+        for column in 1..=2 {
+            let actual = sum.get(row, column).unwrap();
+            let expected = first.get(row, column).unwrap() + second.get(row, column).unwrap();
+            let scale = expected.re.abs().max(expected.im.abs()).max(1.0);
+            let error = actual - expected;
+            assert!(
+                error.re.abs() / scale < 1.0e-9 && error.im.abs() / scale < 1.0e-9,
+                "expected {actual:?} to be close to {expected:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn parallel_resistors_halve_the_resistance() {
+    let first = series_resistor("first.s2p", 50.0);
+    let second = series_resistor("second.s2p", 50.0);
+
+    let parallel = first.connect_parallel(&second).unwrap();
+
+    assert_eq!(parallel.name, "Parallel(first.s2p,second.s2p)");
+    assert_eq!(parallel.f, first.f);
+    assert_complex_close(
+        parallel.try_s_ri_at(1, 1, 1).unwrap(),
+        Complex { re: 0.2, im: 0.0 },
+    );
+    assert_complex_close(
+        parallel.try_s_ri_at(1, 2, 1).unwrap(),
+        Complex { re: 0.8, im: 0.0 },
+    );
+    assert_eq!(
+        parallel.comments[0],
+        "! Parallel-parallel connection of first.s2p and second.s2p"
+    );
+}
+
+#[test]
+fn connections_sum_the_matching_parameters() {
+    let net1 = Network::new("files/ntwk1.s2p").unwrap();
+    let net2 = Network::new("files/ntwk2.s2p").unwrap();
+    let point = net1.f.len() / 2;
+
+    let series = net1.connect_series(&net2).unwrap();
+    assert_sum(
+        &series.z_matrix_at(point).unwrap(),
+        &net1.z_matrix_at(point).unwrap(),
+        &net2.z_matrix_at(point).unwrap(),
+    );
+
+    let parallel = net1.connect_parallel(&net2).unwrap();
+    assert_sum(
+        &parallel.y_matrix_at(point).unwrap(),
+        &net1.y_matrix_at(point).unwrap(),
+        &net2.y_matrix_at(point).unwrap(),
+    );
+
+    let series_parallel = net1.connect_series_parallel(&net2).unwrap();
+    assert_sum(
+        &series_parallel.h_matrix_at(point).unwrap(),
+        &net1.h_matrix_at(point).unwrap(),
+        &net2.h_matrix_at(point).unwrap(),
+    );
+
+    let parallel_series = net1.connect_parallel_series(&net2).unwrap();
+    assert_sum(
+        &parallel_series.g_matrix_at(point).unwrap(),
+        &net1.g_matrix_at(point).unwrap(),
+        &net2.g_matrix_at(point).unwrap(),
+    );
+    assert!(parallel_series
+        .comments
+        .iter()
+        .any(|comment| comment.starts_with("! Comments from second network")));
+}
+
+#[test]
+fn connections_validate_networks() {
+    let net1 = Network::new("files/ntwk1.s2p").unwrap();
+    let resistor = series_resistor("resistor.s2p", 50.0);
+    assert!(matches!(
+        net1.connect_series(&resistor),
+        Err(TouchstoneError::FrequencyGridMismatch { .. })
+    ));
+
+    let mut other = resistor.clone();
+    other.z0 = 75.0;
+    other.reference_impedance = touchstone::ReferenceImpedance::Common(75.0);
+    assert!(matches!(
+        resistor.connect_parallel(&other),
+        Err(TouchstoneError::ReferenceImpedanceMismatch { .. })
+    ));
+
+    let one_port = Network::new("files/hfss_oneport.s1p").unwrap();
+    assert!(matches!(
+        one_port.connect_series_parallel(&net1),
+        Err(TouchstoneError::InvalidNetworkRank { rank: 1 })
+    ));
+
+    let z_parameters = Network::from_str(
+        "resistor_z.s2p",
+        "# GHz Z RI R 50\n1.0 50 0 0 0 0 0 50 0\n2.0 50 0 0 0 0 0 50 0\n",
+    )
+    .unwrap();
+    assert!(matches!(
+        resistor.connect_series(&z_parameters),
+        Err(TouchstoneError::UnsupportedNetworkParameter { parameter }) if parameter == "Z"
+    ));
+    assert!(matches!(
+        z_parameters.connect_parallel(&resistor),
+        Err(TouchstoneError::UnsupportedNetworkParameter { .. })
+    ));
+
+    // An ideal through has no Z-parameters.
+    let through = series_resistor("through.s2p", 0.0);
+    assert!(matches!(
+        through.connect_series(&resistor),
+        Err(TouchstoneError::SingularMatrix { .. })
+    ));
+}
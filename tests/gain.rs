@@ -0,0 +1,112 @@
+use touchstone::{Complex, Network, TouchstoneError};
+
+mod common;
+
+use common::{assert_close, REAL_AMPLIFIER};
+
+const MATCHED: Complex = Complex { re: 0.0, im: 0.0 };
+
+fn real(value: f64) -> Complex {
+    Complex { re: value, im: 0.0 }
+}
+
+#[test]
+fn gain_metrics_match_hand_calculation() {
+    let network = Network::from_str("amp.s2p", REAL_AMPLIFIER).unwrap();
+
+    let metrics = network.gain_metrics().unwrap()[0];
+
+    let k: f64 = 1.475;
+    let mag = 20.0 * (k - (k * k - 1.0).sqrt());
+    assert_close(metrics.max_stable_gain, 20.0);
+    assert_close(metrics.max_available_gain.unwrap(), mag);
+    assert_close(metrics.max_gain(), mag);
+    assert_close(metrics.max_gain_db(), 10.0 * mag.log10());
+    assert_close(
+        metrics.unilateral_figure_of_merit,
+        0.5 * 0.1 * 2.0 * 0.4 / (0.75 * 0.84),
+    );
+}
+
+#[test]
+fn simultaneous_conjugate_match_achieves_mag() {
+    let network = Network::from_str("amp.s2p", REAL_AMPLIFIER).unwrap();
+    // Γ_MS = (B1 − √(B1² − 4|C1|²)) / 2C1 with C1 = S11 and Γ_ML likewise with C2 = S22, as Δ = 0.
+    let b1: f64 = 1.0 + 0.25 - 0.16;
+    let b2: f64 = 1.0 + 0.16 - 0.25;
+    let gamma_s = real((b1 - (b1 * b1 - 4.0 * 0.25).sqrt()) / (2.0 * 0.5));
+    let gamma_l = real((b2 - (b2 * b2 - 4.0 * 0.16).sqrt()) / (2.0 * 0.4));
+
+    let mag = network.gain_metrics().unwrap()[0]
+        .max_available_gain
+        .unwrap();
+
+    assert_close(
+        network.transducer_gain(gamma_s, gamma_l).unwrap()[0].gain,
+        mag,
+    );
+    assert_close(network.available_gain(gamma_s).unwrap()[0].gain, mag);
+    assert_close(network.operating_gain(gamma_l).unwrap()[0].gain, mag);
+}
+
+#[test]
+fn matched_terminations_reduce_to_closed_forms() {
+    let network = Network::from_str("amp.s2p", REAL_AMPLIFIER).unwrap();
+
+    let transducer = network.transducer_gain(MATCHED, MATCHED).unwrap()[0];
+    let available = network.available_gain(MATCHED).unwrap()[0];
+    let operating = network.operating_gain(MATCHED).unwrap()[0];
+
+    assert_eq!(transducer.frequency, 1.0e9);
+    assert_close(transducer.gain, 4.0);
+    assert_close(transducer.gain_db(), 10.0 * 4.0_f64.log10());
+    assert_close(available.gain, 4.0 / (1.0 - 0.16));
+    assert_close(operating.gain, 4.0 / (1.0 - 0.25));
+}
+
+#[test]
+fn mag_is_undefined_where_device_is_potentially_unstable() {
+    let network = Network::new("files/fet.s2p").unwrap();
+
+    let metrics = network.gain_metrics().unwrap();
+    let stability = network.stability().unwrap();
+
+    assert_eq!(metrics.len(), network.f.len());
+    assert!(metrics
+        .iter()
+        .any(|point| point.max_available_gain.is_none()));
+    for (point, factors) in metrics.iter().zip(&stability) {
+        assert_eq!(
+            point.max_available_gain.is_some(),
+            factors.k >= 1.0 && factors.delta < 1.0,
+            "{point:?}"
+        );
+        match point.max_available_gain {
+            Some(mag) => assert!(mag <= point.max_stable_gain, "{point:?}"),
+            None => assert_eq!(point.max_gain(), point.max_stable_gain),
+        }
+    }
+}
+
+#[test]
+fn gain_requires_two_port_network() {
+    let one_port = Network::new("files/hfss_oneport.s1p").unwrap();
+    let three_port = Network::new("files/hfss_18.2.s3p").unwrap();
+
+    assert!(matches!(
+        one_port.gain_metrics().unwrap_err(),
+        TouchstoneError::InvalidNetworkRank { rank: 1 }
+    ));
+    assert!(matches!(
+        three_port.transducer_gain(MATCHED, MATCHED).unwrap_err(),
+        TouchstoneError::InvalidNetworkRank { rank: 3 }
+    ));
+    assert!(matches!(
+        three_port.available_gain(MATCHED).unwrap_err(),
+        TouchstoneError::InvalidNetworkRank { rank: 3 }
+    ));
+    assert!(matches!(
+        one_port.operating_gain(MATCHED).unwrap_err(),
+        TouchstoneError::InvalidNetworkRank { rank: 1 }
+    ));
+}
@@ -1,5 +1,9 @@
 use touchstone::{Complex, Network, SMatrix, TouchstoneError};
 
+mod common;
+
+use common::{assert_complex_close, assert_s_matrix_close, c, s_matrix};
+
 const ONE_PORT_RI: &str = "# Hz S RI R 50\n1000000000 0.5 -0.25\n2000000000 0.6 -0.35\n";
 
 const TWO_PORT_ASYMMETRIC_RI: &str =
@@ -12,46 +16,6 @@ const THREE_PORT_RI: &str = "\
 
 const THREE_PORT_VALUES: &str =
     "1000000000 0.11 0.0 0.12 0.0 0.13 0.0 0.21 0.0 0.22 0.0 0.23 0.0 0.31 0.0 0.32 0.0 0.33 0.0\n";
-
-fn c(re: f64, im: f64) -> Complex {
-    Complex { re, im }
-}
-
-fn s_matrix(data: Vec<Vec<Complex>>) -> SMatrix {
-    SMatrix {
-        rank: data.len(),
-        data,
-    }
-}
-
-fn assert_complex_close(actual: Complex, expected: Complex) {
-    let tolerance = 1.0e-9;
-    assert!(
-        (actual.re - expected.re).abs() <= tolerance,
-        "expected re {} to be within {tolerance} of {}",
-        actual.re,
-        expected.re
-    );
-    assert!(
-        (actual.im - expected.im).abs() <= tolerance,
-        "expected im {} to be within {tolerance} of {}",
-        actual.im,
-        expected.im
-    );
-}
-
-fn assert_s_matrix_close(actual: &SMatrix, expected: &SMatrix) {
-    assert_eq!(actual.rank, expected.rank);
-    for row in 1..=actual.rank {
-        for column in 1..=actual.rank {
-            assert_complex_close(
-                actual.get(row, column).unwrap(),
-                expected.get(row, column).unwrap(),
-            );
-        }
-    }
-}
-
 #[test]
 fn one_port_accessors_use_zero_based_frequency_indexes() {
     let network = Network::from_str("uploaded.s1p", ONE_PORT_RI).unwrap();
@@ -275,3 +239,59 @@ fn abcd_conversion_rejects_zero_forward_transmission() {
         } if operation == "S to ABCD denominator"
     ));
 }
+
+#[test]
+fn hybrid_parameters_match_impedance_parameters_and_round_trip() {
+    let network = Network::from_str("uploaded.s2p", TWO_PORT_ASYMMETRIC_RI).unwrap();
+    let s = network.s_matrix_at(0).unwrap();
+    let z = network.z_matrix_at(0).unwrap();
+    let h = network.h_matrix_at(0).unwrap();
+    let g = network.g_matrix_at(0).unwrap();
+
+    let z11 = z.get(1, 1).unwrap();
+    let z12 = z.get(1, 2).unwrap();
+    let z21 = z.get(2, 1).unwrap();
+    let z22 = z.get(2, 2).unwrap();
+    let one = c(1.0, 0.0);
+    assert_complex_close(h.get(1, 1).unwrap(), (z11 * z22 - z12 * z21) / z22);
+    assert_complex_close(h.get(1, 2).unwrap(), z12 / z22);
+    assert_complex_close(h.get(2, 1).unwrap(), -z21 / z22);
+    assert_complex_close(h.get(2, 2).unwrap(), one / z22);
+    assert_complex_close(g.get(1, 1).unwrap(), one / z11);
+    assert_complex_close(g.get(2, 2).unwrap(), (z11 * z22 - z12 * z21) / z11);
+
+    assert_s_matrix_close(&SMatrix::try_from_h_matrix(&h, 50.0).unwrap(), &s);
+    assert_s_matrix_close(&SMatrix::try_from_g_matrix(&g, 50.0).unwrap(), &s);
+}
+
+#[test]
+fn hybrid_conversions_reject_shorted_ports_and_other_ranks() {
+    let shorted_output = s_matrix(vec![
+        vec![c(0.0, 0.0), c(0.0, 0.0)],
+        vec![c(0.0, 0.0), c(-1.0, 0.0)],
+    ]);
+    assert!(matches!(
+        shorted_output.to_h_matrix(50.0).unwrap_err(),
+        TouchstoneError::SingularMatrix { operation, .. } if operation == "S to H denominator"
+    ));
+    assert!(shorted_output.to_g_matrix(50.0).is_ok());
+
+    let shorted_input = s_matrix(vec![
+        vec![c(-1.0, 0.0), c(0.0, 0.0)],
+        vec![c(0.0, 0.0), c(0.0, 0.0)],
+    ]);
+    assert!(matches!(
+        shorted_input.to_g_matrix(50.0).unwrap_err(),
+        TouchstoneError::SingularMatrix { operation, .. } if operation == "S to G denominator"
+    ));
+
+    let one_port = s_matrix(vec![vec![c(0.0, 0.0)]]);
+    assert!(matches!(
+        one_port.to_h_matrix(50.0).unwrap_err(),
+        TouchstoneError::UnsupportedConversionRank {
+            conversion,
+            rank: 1,
+            expected_rank: 2,
+        } if conversion == "S to H"
+    ));
+}
@@ -0,0 +1,132 @@
+use touchstone::{Interpolation, Network, Quantity, TouchstoneError};
+
+mod common;
+
+use common::assert_close;
+
+// |S21| = 0.5, 1.0, 0.25, 0.5 with phase −90° per GHz (group delay 0.25 ns).
+const THROUGH: &str = "\
+# GHz S MA R 50
+1.0 0.1 0.0 0.5 -90.0 0.5 -90.0 0.1 0.0
+2.0 0.1 0.0 1.0 -180.0 1.0 -180.0 0.1 0.0
+3.0 0.1 0.0 0.25 90.0 0.25 90.0 0.1 0.0
+4.0 0.1 0.0 0.5 0.0 0.5 0.0 0.1 0.0
+";
+
+fn db(magnitude: f64) -> f64 {
+    20.0 * magnitude.log10()
+}
+
+#[test]
+fn band_statistics_report_extremes_and_their_frequencies() {
+    let network = Network::from_str("thru.s2p", THROUGH).unwrap();
+
+    let all = network
+        .measure(2, 1, Quantity::Magnitude, 1.0e9, 4.0e9)
+        .unwrap();
+    let upper = network
+        .measure(2, 1, Quantity::Decibel, 2.5e9, 4.0e9)
+        .unwrap();
+
+    assert_eq!(all.max.frequency, 2.0e9);
+    assert_close(all.max.value, 1.0);
+    assert_eq!(all.min.frequency, 3.0e9);
+    assert_close(all.min.value, 0.25);
+    assert_close(all.mean, 2.25 / 4.0);
+    assert_close(all.peak_to_peak(), 0.75);
+    assert_eq!(upper.min.frequency, 3.0e9);
+    assert_eq!(upper.max.frequency, 4.0e9);
+    assert_close(upper.peak_to_peak(), db(0.5) - db(0.25));
+}
+
+#[test]
+fn phase_and_group_delay_quantities_are_unwrapped() {
+    let network = Network::from_str("thru.s2p", THROUGH).unwrap();
+
+    let phase = network.quantity(2, 1, Quantity::Phase).unwrap();
+    let delay = network
+        .measure(2, 1, Quantity::GroupDelay, 1.0e9, 4.0e9)
+        .unwrap();
+
+    let phases = phase.iter().map(|point| point.value).collect::<Vec<_>>();
+    assert_eq!(phases.len(), 4);
+    for (actual, expected) in phases.iter().zip([-90.0, -180.0, -270.0, -360.0]) {
+        assert_close(*actual, expected);
+    }
+    assert_close(delay.min.value, 0.25e-9);
+    assert_close(delay.max.value, 0.25e-9);
+}
+
+#[test]
+fn markers_follow_sample_at_interpolation() {
+    let network = Network::new("files/ntwk1.s2p").unwrap();
+    let frequency = 2.45e9;
+
+    for interpolation in [Interpolation::Linear, Interpolation::Nearest] {
+        let marker = network
+            .marker(2, 1, Quantity::Magnitude, frequency, interpolation)
+            .unwrap();
+        let sample = network
+            .sample_at(frequency, interpolation, touchstone::Extrapolation::Error)
+            .unwrap()
+            .s
+            .get(2, 1)
+            .unwrap();
+
+        assert_eq!(marker.frequency, frequency);
+        assert_close(marker.value, sample.re.hypot(sample.im));
+    }
+}
+
+#[test]
+fn markers_at_frequency_points_match_quantity() {
+    let network = Network::from_str("thru.s2p", THROUGH).unwrap();
+
+    for quantity in [
+        Quantity::Decibel,
+        Quantity::Magnitude,
+        Quantity::Phase,
+        Quantity::GroupDelay,
+    ] {
+        let values = network.quantity(2, 1, quantity).unwrap();
+        for point in &values {
+            let marker = network
+                .marker(2, 1, quantity, point.frequency, Interpolation::Linear)
+                .unwrap();
+            assert_close(marker.value, point.value);
+        }
+    }
+    // Between points the phase continues the unwrapped trace.
+    let phase = network
+        .marker(2, 1, Quantity::Phase, 3.5e9, Interpolation::Linear)
+        .unwrap();
+    assert!(phase.value < -270.0 && phase.value > -360.0, "{phase:?}");
+}
+
+#[test]
+fn measure_validates_band_and_markers_validate_range() {
+    let network = Network::from_str("thru.s2p", THROUGH).unwrap();
+
+    assert!(matches!(
+        network
+            .measure(2, 1, Quantity::Decibel, 4.0e9, 1.0e9)
+            .unwrap_err(),
+        TouchstoneError::InvalidFrequencyBand { .. }
+    ));
+    assert!(matches!(
+        network
+            .measure(2, 1, Quantity::Decibel, 1.2e9, 1.8e9)
+            .unwrap_err(),
+        TouchstoneError::InvalidFrequencyBand { .. }
+    ));
+    assert!(matches!(
+        network
+            .marker(2, 1, Quantity::Decibel, 5.0e9, Interpolation::Linear)
+            .unwrap_err(),
+        TouchstoneError::FrequencyOutOfRange { .. }
+    ));
+    assert!(matches!(
+        network.quantity(3, 1, Quantity::Decibel).unwrap_err(),
+        TouchstoneError::InvalidPortIndex { .. }
+    ));
+}
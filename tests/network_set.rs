@@ -0,0 +1,143 @@
+use touchstone::{Interpolation, Network, NetworkSet, Quantity, TouchstoneError};
+
+mod common;
+
+use common::assert_close;
+
+fn one_port(name: &str, values: &[(f64, f64)]) -> Network {
+    let mut text = String::from("# GHz S RI R 50\n");
+    for (index, (re, im)) in values.iter().enumerate() {
+        text.push_str(&format!("{} {re} {im}\n", index + 1));
+    }
+    Network::from_str(name, &text).unwrap()
+}
+
+#[test]
+fn mean_std_and_percentile_are_taken_per_component() {
+    let set = NetworkSet::new(vec![
+        one_port("a.s1p", &[(0.1, 0.0), (0.5, -0.2)]),
+        one_port("b.s1p", &[(0.3, 0.2), (0.5, -0.2)]),
+        one_port("c.s1p", &[(0.2, 0.4), (0.5, -0.2)]),
+        one_port("d.s1p", &[(0.4, 0.6), (0.5, -0.2)]),
+    ])
+    .unwrap();
+
+    let mean = set.mean();
+    assert_eq!(mean.name, "mean.s1p");
+    assert_close(mean.try_s_ri_at(0, 1, 1).unwrap().re, 0.25);
+    assert_close(mean.try_s_ri_at(0, 1, 1).unwrap().im, 0.3);
+    assert_close(mean.try_s_ri_at(1, 1, 1).unwrap().re, 0.5);
+
+    let std = set.std();
+    assert_close(std.try_s_ri_at(0, 1, 1).unwrap().re, 0.0125_f64.sqrt());
+    assert_close(std.try_s_ri_at(0, 1, 1).unwrap().im, 0.05_f64.sqrt());
+    assert_close(std.try_s_ri_at(1, 1, 1).unwrap().im, 0.0);
+
+    let median = set.percentile(50.0).unwrap();
+    assert_close(median.try_s_ri_at(0, 1, 1).unwrap().re, 0.25);
+    assert_close(median.try_s_ri_at(0, 1, 1).unwrap().im, 0.3);
+    let upper = set.percentile(100.0).unwrap();
+    assert_close(upper.try_s_ri_at(0, 1, 1).unwrap().re, 0.4);
+    assert!(upper.comments.last().unwrap().contains("of 4 networks"));
+}
+
+#[test]
+fn statistics_are_taken_in_quantity_space() {
+    let set = NetworkSet::new(vec![
+        one_port("a.s1p", &[(0.1, 0.0)]),
+        one_port("b.s1p", &[(0.0, 1.0)]),
+    ])
+    .unwrap();
+
+    let db = set.statistics(1, 1, Quantity::Decibel).unwrap();
+    assert_eq!(db[0].frequency, 1.0e9);
+    assert_close(db[0].mean, -10.0);
+    assert_close(db[0].std, 10.0);
+    assert_close(db[0].min, -20.0);
+    assert_close(db[0].max, 0.0);
+
+    let magnitude = set
+        .quantity_percentile(1, 1, Quantity::Magnitude, 25.0)
+        .unwrap();
+    assert_close(magnitude[0].value, 0.325);
+
+    // The mean network averages complex values, unlike the dB statistics.
+    let mean = set.mean().try_s_ri_at(0, 1, 1).unwrap();
+    assert_close(mean.re, 0.05);
+    assert_close(mean.im, 0.5);
+}
+
+#[test]
+fn new_rejects_empty_and_incompatible_networks() {
+    assert!(matches!(
+        NetworkSet::new(Vec::new()),
+        Err(TouchstoneError::EmptyNetworkSet)
+    ));
+
+    let error = NetworkSet::new(vec![
+        Network::new("files/ntwk1.s2p").unwrap(),
+        Network::new("files/hfss_oneport.s1p").unwrap(),
+    ])
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        TouchstoneError::IncompatibleNetwork { index: 1, .. }
+    ));
+
+    let error = NetworkSet::new(vec![
+        one_port("a.s1p", &[(0.1, 0.0), (0.2, 0.0)]),
+        one_port("b.s1p", &[(0.1, 0.0)]),
+    ])
+    .unwrap_err();
+    assert!(error.to_string().contains("frequency grid"));
+}
+
+#[test]
+fn new_accepts_grids_that_differ_by_unit_rounding() {
+    let gigahertz = Network::from_str("a.s1p", "# GHz S RI R 50\n4.1 0.1 0.0\n").unwrap();
+    let megahertz = Network::from_str("b.s1p", "# MHz S RI R 50\n4100 0.3 0.0\n").unwrap();
+    assert_ne!(gigahertz.f, megahertz.f);
+
+    let set = NetworkSet::new(vec![gigahertz, megahertz]).unwrap();
+
+    assert_eq!(set.len(), 2);
+    let s11 = set.statistics(1, 1, Quantity::Magnitude).unwrap();
+    assert_close(s11[0].mean, 0.2);
+}
+
+#[test]
+fn resampled_uses_the_overlapping_part_of_the_first_grid() {
+    let wide = one_port(
+        "wide.s1p",
+        &[(0.0, 0.0), (0.1, 0.0), (0.2, 0.0), (0.3, 0.0)],
+    );
+    let narrow =
+        Network::from_str("narrow.s1p", "# GHz S RI R 50\n1.5 0.0 0.2\n3.5 0.0 0.6\n").unwrap();
+
+    let set = NetworkSet::resampled(vec![wide, narrow], Interpolation::Linear).unwrap();
+
+    assert_eq!(set.frequencies(), &[2.0e9, 3.0e9]);
+    let narrow = set.networks()[1].try_s_ri_at(0, 1, 1).unwrap();
+    assert_close(narrow.im, 0.3);
+}
+
+#[test]
+fn from_directory_loads_touchstone_files_in_path_order() {
+    let set = NetworkSet::from_directory("files/test_plot_dir").unwrap();
+
+    let names = set
+        .networks()
+        .iter()
+        .map(|network| network.name.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(set.len(), 3);
+    assert!(names[0].ends_with("ntwk1.s2p"));
+    assert!(names[2].ends_with("ntwk3.s2p"));
+
+    let s21 = set.statistics(2, 1, Quantity::Magnitude).unwrap();
+    let low = set
+        .quantity_percentile(2, 1, Quantity::Magnitude, 0.0)
+        .unwrap();
+    assert_eq!(s21.len(), set.frequencies().len());
+    assert_close(low[0].value, s21[0].min);
+}
@@ -0,0 +1,184 @@
+use touchstone::{Complex, Network, NetworkBuilder, SMatrix, TouchstoneError, TouchstoneWarning};
+
+mod common;
+
+use common::assert_complex_close;
+
+// S21 = 1.2∠60°, S12 = 0.5, S11 = S22 = 0: singular values 1.2 and 0.5.
+const ACTIVE_TWO_PORT: &str = "\
+# GHz S MA R 50
+1.0 0.0 0.0 1.2 60.0 0.5 0.0 0.0 0.0
+2.0 0.1 -30.0 0.9 -30.0 0.9 -30.0 0.1 -30.0
+";
+
+/// Scale every S-parameter of `network` by `factor`.
+fn scaled(network: &Network, factor: f64) -> Network {
+    let mut builder = NetworkBuilder::new("scaled.s3p", network.rank as usize);
+    for point in network.points().unwrap() {
+        let data = point
+            .s
+            .data
+            .iter()
+            .map(|row| row.iter().map(|value| *value * factor).collect())
+            .collect();
+        builder.push_point(
+            point.frequency,
+            SMatrix {
+                rank: point.s.rank,
+                data,
+            },
+        );
+    }
+    builder.build().unwrap()
+}
+
+#[test]
+fn singular_values_match_closed_form() {
+    let network = Network::from_str("active.s2p", ACTIVE_TWO_PORT).unwrap();
+
+    let singular_values = network.passivity_violations().unwrap();
+
+    assert_eq!(singular_values.len(), 2);
+    assert_eq!(singular_values[0].frequency, 1.0e9);
+    assert!((singular_values[0].max_singular_value - 1.2).abs() < 1.0e-12);
+    assert!(!singular_values[0].is_passive());
+    // [[0.1, 0.9], [0.9, 0.1]] (common phase) has singular values 1.0 and 0.8.
+    assert!((singular_values[1].max_singular_value - 1.0).abs() < 1.0e-12);
+    assert!(singular_values[1].is_passive());
+}
+
+#[test]
+fn measured_passive_networks_have_no_violations() {
+    for path in [
+        "files/ntwk1.s2p",
+        "files/hfss_twoport.s2p",
+        "files/Agilent_E5071B.s4p",
+    ] {
+        let network = Network::new(path).unwrap();
+
+        let singular_values = network.passivity_violations().unwrap();
+
+        assert_eq!(singular_values.len(), network.f.len(), "{path}");
+        assert!(
+            singular_values.iter().all(|point| point.is_passive()),
+            "{path}"
+        );
+    }
+}
+
+#[test]
+fn enforcement_clips_only_the_violating_singular_value() {
+    let network = Network::from_str("active.s2p", ACTIVE_TWO_PORT).unwrap();
+
+    let passive = network.enforce_passivity().unwrap();
+
+    let s21 = network.try_s_ri_at(0, 2, 1).unwrap();
+    assert_complex_close(passive.try_s_ri_at(0, 2, 1).unwrap(), s21 / 1.2);
+    assert_complex_close(
+        passive.try_s_ri_at(0, 1, 2).unwrap(),
+        network.try_s_ri_at(0, 1, 2).unwrap(),
+    );
+    assert_complex_close(
+        passive.try_s_ri_at(0, 1, 1).unwrap(),
+        Complex { re: 0.0, im: 0.0 },
+    );
+    assert_eq!(
+        passive.s_matrix_at(1).unwrap(),
+        network.s_matrix_at(1).unwrap()
+    );
+}
+
+#[test]
+fn enforcement_restores_passivity_of_scaled_multiport() {
+    let original = Network::new("files/hfss_18.2.s3p").unwrap();
+    let active = scaled(&original, 1.1);
+    let violating = active
+        .passivity_violations()
+        .unwrap()
+        .into_iter()
+        .enumerate()
+        .filter(|(_, point)| !point.is_passive())
+        .collect::<Vec<_>>();
+    assert!(!violating.is_empty());
+
+    let passive = active.enforce_passivity().unwrap();
+
+    for point in passive.passivity_violations().unwrap() {
+        assert!(point.max_singular_value <= 1.0 + 1.0e-12, "{point:?}");
+    }
+    assert_eq!(passive.f, active.f);
+    assert_eq!(passive.warnings.len(), violating.len());
+    for (warning, (point_index, point)) in passive.warnings.iter().zip(&violating) {
+        assert_eq!(
+            warning,
+            &TouchstoneWarning::PassivityEnforced {
+                source_name: "scaled.s3p".to_string(),
+                point_index: *point_index,
+                max_singular_value: format!("{:.6}", point.max_singular_value),
+                clipped_to: "1.000000".to_string(),
+            }
+        );
+    }
+    assert!(passive
+        .comments
+        .last()
+        .unwrap()
+        .starts_with("! Passivity enforced"));
+}
+
+#[test]
+fn enforcement_removes_small_violations_in_simulated_data() {
+    // The HFSS export is slightly active (σmax ≈ 1.0002) at a few frequencies.
+    let network = Network::new("files/hfss_18.2.s3p").unwrap();
+    let before = network.passivity_violations().unwrap();
+    assert!(before.iter().any(|point| !point.is_passive()));
+
+    let passive = network.enforce_passivity().unwrap();
+
+    let after = passive.passivity_violations().unwrap();
+    for (old, new) in before.iter().zip(&after) {
+        assert!(new.is_passive(), "{new:?}");
+        if old.is_passive() {
+            assert_eq!(old, new);
+        }
+    }
+}
+
+#[test]
+fn enforcement_leaves_passive_network_unchanged() {
+    let network = Network::new("files/ntwk1.s2p").unwrap();
+
+    let enforced = network.enforce_passivity().unwrap();
+
+    assert_eq!(enforced.warnings, network.warnings);
+    assert_eq!(enforced.comments, network.comments);
+    assert_eq!(enforced.points().unwrap(), network.points().unwrap());
+}
+
+#[test]
+fn passivity_warning_display_names_point_and_clipped_value() {
+    let network = Network::from_str("active.s2p", ACTIVE_TWO_PORT).unwrap();
+
+    let passive = network.enforce_passivity().unwrap();
+
+    assert_eq!(
+        passive.warnings[0].to_string(),
+        "active.s2p: passivity enforced at frequency point 0; \
+         largest singular value 1.200000 clipped to 1.000000"
+    );
+}
+
+#[test]
+fn passivity_requires_s_parameter_data() {
+    let network =
+        Network::from_str("uploaded.s1p", "# Hz Y RI R 50\n1000000000 0.02 0.0\n").unwrap();
+
+    assert!(matches!(
+        network.passivity_violations().unwrap_err(),
+        TouchstoneError::UnsupportedNetworkParameter { .. }
+    ));
+    assert!(matches!(
+        network.enforce_passivity().unwrap_err(),
+        TouchstoneError::UnsupportedNetworkParameter { .. }
+    ));
+}
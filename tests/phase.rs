@@ -0,0 +1,78 @@
+use touchstone::{AngleUnit, FrequencyGroupDelay, Network};
+
+mod common;
+
+use common::{delay_line, DELAY_S};
+
+#[test]
+fn unwrapped_phase_of_delay_line_is_linear_in_frequency() {
+    let network = delay_line();
+
+    let phase = network.s_phase_unwrapped(2, 1, AngleUnit::Degrees);
+
+    assert_eq!(phase.len(), network.f.len());
+    for point in &phase {
+        let expected = -360.0 * point.frequency * DELAY_S;
+        assert!((point.phase - expected).abs() < 1.0e-9, "{point:?}");
+    }
+}
+
+#[test]
+fn radians_and_degrees_describe_the_same_phase() {
+    let network = Network::new("files/LFCN-2352+_Plus25degC.s2p").unwrap();
+
+    let degrees = network.s_phase_unwrapped(2, 1, AngleUnit::Degrees);
+    let radians = network.s_phase_unwrapped(2, 1, AngleUnit::Radians);
+
+    for (degree_point, radian_point) in degrees.iter().zip(&radians) {
+        assert_eq!(degree_point.frequency, radian_point.frequency);
+        assert!((degree_point.phase.to_radians() - radian_point.phase).abs() < 1.0e-12);
+    }
+    assert!(radians
+        .windows(2)
+        .all(|pair| (pair[1].phase - pair[0].phase).abs() <= std::f64::consts::PI));
+}
+
+#[test]
+fn group_delay_of_delay_line_matches_line_delay_for_any_aperture() {
+    let network = delay_line();
+
+    for aperture in [0, 1, 3, 10] {
+        let delay = network.group_delay(2, 1, aperture);
+
+        assert_eq!(delay.len(), network.f.len());
+        for point in delay {
+            assert!(
+                (point.group_delay - DELAY_S).abs() < 1.0e-18,
+                "aperture {aperture}: {point:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn wider_aperture_smooths_measured_group_delay() {
+    let network = Network::new("files/LFCN-2352+_Plus25degC.s2p").unwrap();
+
+    let fine = network.group_delay(2, 1, 1);
+    let smoothed = network.group_delay(2, 1, 5);
+
+    let roughness = |delay: &[FrequencyGroupDelay]| -> f64 {
+        delay
+            .windows(2)
+            .map(|pair| (pair[1].group_delay - pair[0].group_delay).abs())
+            .sum()
+    };
+    assert!(fine.iter().all(|point| point.group_delay.is_finite()));
+    assert!(roughness(&smoothed) <= roughness(&fine));
+}
+
+#[test]
+fn group_delay_of_single_point_is_nan() {
+    let single = Network::from_str("one.s1p", "# GHz S RI R 50\n1.0 0.2 0.0\n").unwrap();
+
+    let delay = single.group_delay(1, 1, 1);
+
+    assert_eq!(delay.len(), 1);
+    assert!(delay[0].group_delay.is_nan());
+}
@@ -0,0 +1,135 @@
+use std::f64::consts::PI;
+
+use touchstone::{Complex, LossModel, Network, TouchstoneError};
+
+mod common;
+
+use common::assert_complex_close;
+
+const THROUGH: &str =
+    "# GHz S RI R 50\n1.0 0.0 0.0 1.0 0.0 1.0 0.0 0.0 0.0\n2.0 0.2 0.0 1.0 0.0 1.0 0.0 0.2 0.0\n";
+
+fn rotation(magnitude: f64, angle: f64) -> Complex {
+    Complex {
+        re: magnitude * angle.cos(),
+        im: magnitude * angle.sin(),
+    }
+}
+
+#[test]
+fn extension_rotates_the_rows_and_columns_of_one_port() {
+    let through = Network::from_str("thru.s2p", THROUGH).unwrap();
+    let delay = 100.0e-12;
+
+    let extended = through.extend_port(2, delay, LossModel::Lossless).unwrap();
+
+    let angle = -2.0 * PI * 2.0e9 * delay;
+    assert_complex_close(
+        extended.try_s_ri_at(1, 1, 1).unwrap(),
+        Complex { re: 0.2, im: 0.0 },
+    );
+    assert_complex_close(extended.try_s_ri_at(1, 2, 1).unwrap(), rotation(1.0, angle));
+    assert_complex_close(extended.try_s_ri_at(1, 1, 2).unwrap(), rotation(1.0, angle));
+    assert_complex_close(
+        extended.try_s_ri_at(1, 2, 2).unwrap(),
+        rotation(0.2, 2.0 * angle),
+    );
+    assert_eq!(extended.f, through.f);
+}
+
+#[test]
+fn loss_models_attenuate_each_pass_through_the_line() {
+    let load =
+        Network::from_str("load.s1p", "# GHz S RI R 50\n1.0 0.5 0.0\n4.0 0.5 0.0\n").unwrap();
+
+    let constant = load
+        .extend_port(1, 0.0, LossModel::Constant { db: 3.0 })
+        .unwrap();
+    let round_trip = 10.0_f64.powf(-6.0 / 20.0);
+    assert_complex_close(
+        constant.try_s_ri_at(1, 1, 1).unwrap(),
+        Complex {
+            re: 0.5 * round_trip,
+            im: 0.0,
+        },
+    );
+
+    let skin = load
+        .extend_port(
+            1,
+            0.0,
+            LossModel::SquareRootFrequency {
+                db: 1.0,
+                reference_frequency: 1.0e9,
+            },
+        )
+        .unwrap();
+    // 1 dB at 1 GHz is 2 dB at 4 GHz, or 4 dB for the round trip.
+    assert_complex_close(
+        skin.try_s_ri_at(1, 1, 1).unwrap(),
+        Complex {
+            re: 0.5 * 10.0_f64.powf(-4.0 / 20.0),
+            im: 0.0,
+        },
+    );
+}
+
+#[test]
+fn removing_an_extension_restores_the_network() {
+    let network = Network::new("files/Agilent_E5071B.s4p").unwrap();
+    let loss = LossModel::SquareRootFrequency {
+        db: 0.5,
+        reference_frequency: 1.0e9,
+    };
+
+    let restored = network
+        .extend_port(3, 250.0e-12, loss)
+        .unwrap()
+        .remove_port_extension(3, 250.0e-12, loss)
+        .unwrap();
+
+    for point_index in [0, network.f.len() - 1] {
+        for to_port in 1..=4 {
+            for from_port in 1..=4 {
+                assert_complex_close(
+                    restored
+                        .try_s_ri_at(point_index, to_port, from_port)
+                        .unwrap(),
+                    network
+                        .try_s_ri_at(point_index, to_port, from_port)
+                        .unwrap(),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn invalid_extensions_are_rejected() {
+    let through = Network::from_str("thru.s2p", THROUGH).unwrap();
+
+    assert!(matches!(
+        through.extend_port(3, 1.0e-12, LossModel::Lossless),
+        Err(TouchstoneError::InvalidPort { port: 3, rank: 2 })
+    ));
+    assert!(matches!(
+        through.remove_port_extension(1, -1.0e-12, LossModel::Lossless),
+        Err(TouchstoneError::InvalidPortExtension { setting, .. }) if setting == "delay"
+    ));
+    assert!(matches!(
+        through.extend_port(1, 1.0e-12, LossModel::Constant { db: f64::NAN }),
+        Err(TouchstoneError::InvalidPortExtension { setting, .. }) if setting == "loss"
+    ));
+    assert!(matches!(
+        through.extend_port(
+            1,
+            1.0e-12,
+            LossModel::SquareRootFrequency {
+                db: 1.0,
+                reference_frequency: 0.0,
+            },
+        ),
+        Err(TouchstoneError::InvalidPortExtension { setting, .. })
+            if setting == "loss reference frequency"
+    ));
+}
@@ -0,0 +1,264 @@
+use touchstone::{
+    Complex, Extrapolation, Interpolation, Network, ReferenceImpedance, Termination,
+    TouchstoneError,
+};
+
+mod common;
+
+use common::assert_complex_close;
+
+const THREE_PORT_PER_PORT_REFERENCE: &str = "\
+[Version] 2.1
+# Hz S RI R 50
+[Number of Ports] 3
+[Reference] 45 50 55
+[Network Data]
+1000000000 11 0.1 12 0.2 13 0.3 21 0.4 22 0.5 23 0.6 31 0.7 32 0.8 33 0.9
+[End]
+";
+
+const THROUGH_500_TO_750_GHZ: &str = "\
+# GHz S RI R 50
+500 0.0 0.0 1.0 0.0 1.0 0.0 0.0 0.0
+750 0.0 0.0 1.0 0.0 1.0 0.0 0.0 0.0
+";
+
+#[test]
+fn subnetwork_extracts_s31_path_from_four_port_measurement() {
+    let network = Network::new("files/Agilent_E5071B.s4p").unwrap();
+
+    let path = network.subnetwork(&[1, 3]).unwrap();
+
+    assert_eq!(path.rank, 2);
+    assert_eq!(path.f, network.f);
+    assert_eq!(path.z0, 75.0);
+    assert_eq!(path.format, network.format);
+    for point_index in 0..network.f.len() {
+        for (new_to, old_to) in [(1, 1), (2, 3)] {
+            for (new_from, old_from) in [(1, 1), (2, 3)] {
+                assert_eq!(
+                    path.try_s_ri_at(point_index, new_to, new_from).unwrap(),
+                    network.try_s_ri_at(point_index, old_to, old_from).unwrap()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn subnetwork_order_defines_new_port_order_and_slices_reference_impedance() {
+    let network = Network::from_str("uploaded.s3p", THREE_PORT_PER_PORT_REFERENCE).unwrap();
+
+    let sub = network.subnetwork(&[3, 1]).unwrap();
+
+    assert_eq!(sub.rank, 2);
+    assert_eq!(
+        sub.reference_impedance(),
+        ReferenceImpedance::PerPort(vec![55.0, 45.0])
+    );
+    assert_eq!(sub.z0, 55.0);
+    assert_eq!(
+        sub.try_s_ri_at(0, 1, 1).unwrap(),
+        Complex { re: 33.0, im: 0.9 }
+    );
+    assert_eq!(
+        sub.try_s_ri_at(0, 1, 2).unwrap(),
+        Complex { re: 31.0, im: 0.7 }
+    );
+    assert_eq!(
+        sub.try_s_ri_at(0, 2, 1).unwrap(),
+        Complex { re: 13.0, im: 0.3 }
+    );
+}
+
+#[test]
+fn renumber_permutes_ports_and_reference_impedance() {
+    let network = Network::from_str("uploaded.s3p", THREE_PORT_PER_PORT_REFERENCE).unwrap();
+
+    // Rotate: port 1 -> 2, port 2 -> 3, port 3 -> 1.
+    let rotated = network.renumber(&[1, 2, 3], &[2, 3, 1]).unwrap();
+
+    assert_eq!(
+        rotated.reference_impedance(),
+        ReferenceImpedance::PerPort(vec![55.0, 45.0, 50.0])
+    );
+    assert_eq!(
+        rotated.try_s_ri_at(0, 2, 3).unwrap(),
+        network.try_s_ri_at(0, 1, 2).unwrap()
+    );
+    assert_eq!(
+        rotated.try_s_ri_at(0, 1, 2).unwrap(),
+        network.try_s_ri_at(0, 3, 1).unwrap()
+    );
+}
+
+#[test]
+fn renumber_leaves_unlisted_ports_in_place() {
+    let network = Network::from_str("uploaded.s3p", THREE_PORT_PER_PORT_REFERENCE).unwrap();
+
+    let swapped = network.renumber(&[1, 3], &[3, 1]).unwrap();
+
+    assert_eq!(
+        swapped.try_s_ri_at(0, 2, 2).unwrap(),
+        network.try_s_ri_at(0, 2, 2).unwrap()
+    );
+    assert_eq!(
+        swapped.try_s_ri_at(0, 1, 2).unwrap(),
+        network.try_s_ri_at(0, 3, 2).unwrap()
+    );
+}
+
+#[test]
+fn port_selection_errors_are_structured() {
+    let network = Network::new("files/ntwk1.s2p").unwrap();
+
+    assert!(matches!(
+        network.subnetwork(&[]).unwrap_err(),
+        TouchstoneError::EmptyPortList
+    ));
+    assert!(matches!(
+        network.subnetwork(&[1, 3]).unwrap_err(),
+        TouchstoneError::InvalidPort { port: 3, rank: 2 }
+    ));
+    assert!(matches!(
+        network.subnetwork(&[2, 2]).unwrap_err(),
+        TouchstoneError::DuplicatePort { port: 2 }
+    ));
+    let admittance = Network::from_str("shunt.s1p", "# GHz Y RI R 50\n1.0 0.02 0.0\n").unwrap();
+    assert!(matches!(
+        admittance.subnetwork(&[1]).unwrap_err(),
+        TouchstoneError::UnsupportedNetworkParameter { .. }
+    ));
+    assert!(matches!(
+        network.renumber(&[1], &[2]).unwrap_err(),
+        TouchstoneError::InvalidPortRenumbering { .. }
+    ));
+    assert!(matches!(
+        network.renumber(&[1, 1], &[1, 1]).unwrap_err(),
+        TouchstoneError::DuplicatePort { port: 1 }
+    ));
+}
+
+#[test]
+fn matched_termination_matches_subnetwork_of_remaining_ports() {
+    let network = Network::new("files/hfss_18.2.s3p").unwrap();
+
+    let terminated = network
+        .terminate(2, Termination::Reflection(Complex { re: 0.0, im: 0.0 }))
+        .unwrap();
+    let sub = network.subnetwork(&[1, 3]).unwrap();
+
+    assert_eq!(terminated.rank, 2);
+    assert_eq!(terminated.f, network.f);
+    for point_index in 0..network.f.len() {
+        assert_eq!(
+            terminated.s_matrix_at(point_index).unwrap(),
+            sub.s_matrix_at(point_index).unwrap()
+        );
+    }
+}
+
+#[test]
+fn terminate_applies_load_reflection_to_remaining_ports() {
+    let network = Network::new("files/hfss_18.2.s3p").unwrap();
+    let gamma = Complex { re: 0.3, im: -0.4 };
+
+    let terminated = network
+        .terminate(3, Termination::Reflection(gamma))
+        .unwrap();
+
+    let s = network.s_matrix_at(5).unwrap();
+    let s33 = s.get(3, 3).unwrap();
+    let loop_gain = Complex { re: 1.0, im: 0.0 } - s33 * gamma;
+    let expected =
+        s.get(2, 1).unwrap() + s.get(2, 3).unwrap() * gamma * s.get(3, 1).unwrap() / loop_gain;
+    assert_complex_close(terminated.try_s_ri_at(5, 2, 1).unwrap(), expected);
+}
+
+#[test]
+fn one_port_network_load_is_renormalized_to_port_reference() {
+    let network = Network::new("files/hfss_18.2.s3p").unwrap();
+    // A matched 75 ohm load spanning the whole 3-port frequency range.
+    let load =
+        Network::from_str("load.s1p", "# GHz S RI R 75\n0.001 0.0 0.0\n1000 0.0 0.0\n").unwrap();
+
+    let from_network = network.terminate(3, Termination::Network(&load)).unwrap();
+    let from_impedance = network
+        .terminate(3, Termination::Impedance(Complex { re: 75.0, im: 0.0 }))
+        .unwrap();
+
+    for point_index in 0..network.f.len() {
+        for (to_port, from_port) in [(1, 1), (1, 2), (2, 1), (2, 2)] {
+            assert_complex_close(
+                from_network
+                    .try_s_ri_at(point_index, to_port, from_port)
+                    .unwrap(),
+                from_impedance
+                    .try_s_ri_at(point_index, to_port, from_port)
+                    .unwrap(),
+            );
+        }
+    }
+}
+
+#[test]
+fn input_reflection_through_measured_one_port_returns_load_reflection() {
+    let load = Network::new("files/hfss_oneport.s1p").unwrap();
+    let through = Network::from_str("thru.s2p", THROUGH_500_TO_750_GHZ)
+        .unwrap()
+        .resample(
+            load.f.iter().copied(),
+            Interpolation::Linear,
+            Extrapolation::Error,
+        )
+        .unwrap();
+
+    let gamma_in = through
+        .input_reflection(2, Termination::Network(&load))
+        .unwrap();
+
+    assert_eq!(gamma_in.len(), load.f.len());
+    for (point_index, point) in gamma_in.iter().enumerate() {
+        assert_eq!(point.frequency, load.f[point_index]);
+        assert_complex_close(
+            Complex {
+                re: point.s_ri.0,
+                im: point.s_ri.1,
+            },
+            load.try_s_ri_at(point_index, 1, 1).unwrap(),
+        );
+    }
+}
+
+#[test]
+fn termination_errors_are_structured() {
+    let three_port = Network::new("files/hfss_18.2.s3p").unwrap();
+    let one_port = Network::new("files/hfss_oneport.s1p").unwrap();
+    let two_port = Network::new("files/ntwk1.s2p").unwrap();
+    let open = Termination::Reflection(Complex { re: 1.0, im: 0.0 });
+
+    assert!(matches!(
+        three_port
+            .terminate(3, Termination::Network(&one_port))
+            .unwrap_err(),
+        TouchstoneError::FrequencyOutOfRange { .. }
+    ));
+    assert!(matches!(
+        three_port
+            .terminate(3, Termination::Network(&two_port))
+            .unwrap_err(),
+        TouchstoneError::InvalidNetworkRank { rank: 2 }
+    ));
+    assert!(matches!(
+        three_port.terminate(4, open).unwrap_err(),
+        TouchstoneError::InvalidPort { port: 4, rank: 3 }
+    ));
+    assert!(matches!(
+        one_port.terminate(1, open).unwrap_err(),
+        TouchstoneError::CannotTerminateOnlyPort
+    ));
+    assert!(matches!(
+        three_port.input_reflection(1, open).unwrap_err(),
+        TouchstoneError::InvalidNetworkRank { rank: 3 }
+    ));
+}
@@ -1,6 +1,11 @@
 //! Integration tests matching every code example in README.md
 
-use touchstone::{Network, TouchstoneWarning};
+use touchstone::{
+    AngleUnit, Complex, Extrapolation, FrequencyAlignment, GateMode, Interpolation, LimitLine,
+    LossModel, Network, NetworkSet, NoiseParameters, ParameterSweep, PortGrouping, Quantity,
+    SMatrix, SmithChartCircles, Spec, Termination, TimeDomainMode, TimeDomainOptions, TimeGate,
+    TouchstoneError, TouchstoneWarning, Window,
+};
 
 // --- Section 2: Loading a Network ---
 
@@ -67,6 +72,21 @@ fn s_parameters_ma() {
     }
 }
 
+#[test]
+fn phase_and_group_delay() {
+    let ntwk = Network::new("files/LFCN-2352+_Plus25degC.s2p").unwrap();
+
+    let phase = ntwk.s_phase_unwrapped(2, 1, AngleUnit::Degrees);
+    let delay = ntwk.group_delay(2, 1, 3);
+    assert_eq!(phase.len(), ntwk.f.len());
+    assert_eq!(delay.len(), ntwk.f.len());
+    for (p, d) in phase.iter().zip(&delay) {
+        assert_eq!(p.frequency, d.frequency);
+        assert!(p.phase.is_finite());
+        assert!(d.group_delay.is_finite());
+    }
+}
+
 #[test]
 fn field_aliases_ri() {
     let ntwk = Network::new("files/ntwk1.s2p").unwrap();
@@ -175,3 +195,380 @@ fn load_4port() {
     let ntwk = Network::new("files/Agilent_E5071B.s4p").unwrap();
     assert_eq!(ntwk.rank, 4);
 }
+
+// --- Section 3: Sub-Networks and Port Order ---
+
+#[test]
+fn subnetwork_and_renumber() {
+    let four_port = Network::new("files/Agilent_E5071B.s4p").unwrap();
+
+    let path = four_port.subnetwork(&[1, 3]).unwrap();
+    let flipped = path.renumber(&[1, 2], &[2, 1]).unwrap();
+
+    assert_eq!(path.rank, 2);
+    assert_eq!(
+        flipped.try_s_ri_at(0, 1, 2).unwrap(),
+        four_port.try_s_ri_at(0, 3, 1).unwrap()
+    );
+}
+
+#[test]
+fn terminate_and_input_reflection() {
+    let three_port = Network::new("files/hfss_18.2.s3p").unwrap();
+    let loaded = three_port
+        .terminate(3, Termination::Impedance(Complex { re: 75.0, im: 0.0 }))
+        .unwrap();
+
+    let gamma_in = loaded
+        .input_reflection(1, Termination::Reflection(Complex { re: 1.0, im: 0.0 }))
+        .unwrap();
+
+    assert_eq!(loaded.rank, 2);
+    assert_eq!(gamma_in.len(), three_port.f.len());
+}
+
+#[test]
+fn time_domain_responses() {
+    let ntwk = Network::new("files/ntwk1.s2p").unwrap();
+
+    let options = TimeDomainOptions::new()
+        .window(Window::Hann)
+        .time_span(0.0, 2.0e-9)
+        .points(201);
+    let impulse = ntwk.impulse_response(1, 1, &options).unwrap();
+    let step = ntwk.step_response(1, 1, &options).unwrap();
+    let tdr = ntwk.tdr_impedance(1, &options).unwrap();
+    let envelope = ntwk
+        .impulse_response(2, 1, &options.mode(TimeDomainMode::BandPass))
+        .unwrap();
+
+    assert_eq!(impulse.len(), 201);
+    assert_eq!(step.len(), 201);
+    assert_eq!(tdr.len(), 201);
+    assert!(envelope.iter().all(|point| point.value >= 0.0));
+}
+
+#[test]
+fn time_gating() {
+    let ntwk = Network::new("files/ntwk1.s2p").unwrap();
+
+    let dut_only = ntwk.time_gate(&TimeGate::new(1.0e-9, 1.0e-9)).unwrap();
+    let without_connector = ntwk
+        .time_gate(
+            &TimeGate::new(0.0, 0.4e-9)
+                .mode(GateMode::Notch)
+                .shape(Window::Hann),
+        )
+        .unwrap();
+
+    assert_eq!(dut_only.f, ntwk.f);
+    assert_eq!(without_connector.f, ntwk.f);
+}
+
+#[test]
+fn two_port_stability() {
+    let fet = Network::new("files/fet.s2p").unwrap();
+
+    let factors = fet.stability().unwrap();
+    let unstable = fet.potentially_unstable_frequencies().unwrap();
+
+    assert_eq!(factors.len(), fet.f.len());
+    assert!(unstable.len() <= fet.f.len());
+}
+
+#[test]
+fn two_port_gain() {
+    let fet = Network::new("files/fet.s2p").unwrap();
+
+    let metrics = fet.gain_metrics().unwrap();
+    assert_eq!(metrics.len(), fet.f.len());
+
+    let gamma_s = Complex { re: 0.3, im: 0.1 };
+    let gamma_l = Complex { re: 0.2, im: -0.2 };
+    let g_t = fet.transducer_gain(gamma_s, gamma_l).unwrap();
+    let g_a = fet.available_gain(gamma_s).unwrap();
+    let g_p = fet.operating_gain(gamma_l).unwrap();
+    assert!(g_t[0].gain_db().is_finite());
+    assert_eq!(g_a.len(), fet.f.len());
+    assert_eq!(g_p.len(), fet.f.len());
+}
+
+#[test]
+fn smith_chart_circles() {
+    let fet = Network::new("files/fet.s2p").unwrap();
+
+    let source_stability = fet.input_stability_circles().unwrap();
+    let matched = Complex { re: 0.0, im: 0.0 };
+    assert!(source_stability[0].is_stable(matched));
+
+    let gain_circles = fet.available_gain_circles(10.0).unwrap();
+    let noise = [NoiseParameters {
+        frequency: fet.f[0],
+        min_noise_figure_db: 0.8,
+        gamma_opt: Complex { re: 0.4, im: 0.3 },
+        noise_resistance: 12.0,
+    }];
+    let noise_circles = fet.noise_circles(&noise, 1.5).unwrap();
+    assert_eq!(gain_circles.len(), fet.f.len());
+    assert!(noise_circles[0].radius > 0.0);
+
+    let overlays = SmithChartCircles {
+        available_gains_db: vec![10.0, 12.0],
+        operating_gains_db: vec![10.0],
+        noise: noise.to_vec(),
+        noise_figures_db: vec![1.0, 1.5],
+    };
+    let output_path = std::env::temp_dir()
+        .join("touchstone_readme_circles")
+        .join("fet_circles.html");
+    touchstone::generate_plot_with_circles(&[fet], output_path.to_str().unwrap(), &overlays)
+        .unwrap();
+    assert!(std::fs::read_to_string(&output_path)
+        .unwrap()
+        .contains("G_A 12 dB"));
+}
+
+#[test]
+fn passivity() {
+    let ntwk = Network::new("files/hfss_18.2.s3p").unwrap();
+
+    let violations = ntwk
+        .passivity_violations()
+        .unwrap()
+        .into_iter()
+        .filter(|point| !point.is_passive())
+        .count();
+    let passive = ntwk.enforce_passivity().unwrap();
+
+    assert!(violations > 0);
+    assert_eq!(passive.warnings.len(), ntwk.warnings.len() + violations);
+}
+
+#[test]
+fn reciprocity_and_symmetry() {
+    let ntwk = Network::new("files/Agilent_E5071B.s4p").unwrap();
+
+    let worst = ntwk
+        .reciprocity_error()
+        .unwrap()
+        .into_iter()
+        .map(|point| point.deviation)
+        .fold(0.0, f64::max);
+    let reciprocal = ntwk.make_reciprocal().unwrap();
+
+    assert!(ntwk.is_reciprocal(worst).unwrap());
+    assert!(reciprocal.is_reciprocal(0.0).unwrap());
+    assert_eq!(reciprocal.symmetry_error().unwrap().len(), ntwk.f.len());
+    assert!(ntwk.make_symmetric().unwrap().is_symmetric(1e-12).unwrap());
+}
+
+#[test]
+fn causality() {
+    let ntwk = Network::new("files/Agilent_E5071B.s4p").unwrap();
+
+    let errors = ntwk.causality_error(2, 1).unwrap();
+
+    assert_eq!(errors.len(), ntwk.f.len());
+    assert!(errors.iter().all(|point| point.deviation.is_finite()));
+    assert!(!ntwk.is_causal(0.0).unwrap());
+}
+
+#[test]
+fn port_reflection_metrics() {
+    let ntwk = Network::new("files/ntwk1.s2p").unwrap();
+
+    let vswr = ntwk.vswr(1).unwrap();
+    let return_loss = ntwk.return_loss(1).unwrap();
+    let mismatch_loss = ntwk.mismatch_loss(1).unwrap();
+    let z_in = ntwk.input_impedance(1).unwrap();
+
+    assert!(vswr[0].vswr >= 1.0);
+    assert!(return_loss[0].loss_db > 0.0);
+    assert!(mismatch_loss[0].loss_db >= 0.0);
+    assert!(z_in[0].impedance.re.is_finite());
+}
+
+#[test]
+fn band_measurements_and_markers() {
+    let ntwk = Network::new("files/ntwk1.s2p").unwrap();
+
+    let s21 = ntwk.measure(2, 1, Quantity::Decibel, 2.0e9, 4.0e9).unwrap();
+    let marker = ntwk
+        .marker(1, 1, Quantity::Decibel, 2.45e9, Interpolation::Linear)
+        .unwrap();
+
+    assert!((2.0e9..=4.0e9).contains(&s21.min.frequency));
+    assert!(s21.peak_to_peak() >= 0.0);
+    assert_eq!(marker.frequency, 2.45e9);
+}
+
+#[test]
+fn limit_line_specifications() {
+    let ntwk = Network::new("files/ntwk1.s2p").unwrap();
+
+    let spec = Spec::from_str("filter.spec", "# GHz\nS21 dB > 1.0 -1.5 2.0 -1.5\n")
+        .unwrap()
+        .limit(
+            LimitLine::upper(1, 1, Quantity::Decibel, [(0.8e9, -15.0), (2.2e9, -15.0)]).unwrap(),
+        );
+    let report = spec.evaluate(&ntwk).unwrap();
+
+    assert_eq!(report.results.len(), 2);
+    assert!(report.worst().is_some());
+    assert!(report.to_string().contains("S11 dB <"));
+}
+
+#[test]
+fn network_sets() {
+    let set = NetworkSet::from_directory("files/test_plot_dir").unwrap();
+
+    let mean = set.mean();
+    let median = set.percentile(50.0).unwrap();
+    let s21 = set.statistics(2, 1, Quantity::Decibel).unwrap();
+    let p90 = set
+        .quantity_percentile(2, 1, Quantity::Decibel, 90.0)
+        .unwrap();
+
+    assert_eq!(set.len(), 3);
+    assert_eq!(mean.f, median.f);
+    assert!(s21[0].min <= s21[0].mean && s21[0].mean <= s21[0].max);
+    assert!(p90[0].value <= s21[0].max);
+}
+
+#[test]
+fn parameter_sweeps() {
+    let sweep = ParameterSweep::new(
+        "temperature",
+        vec![
+            (
+                25.0,
+                Network::new("files/LFCN-2352+_Plus25degC.s2p").unwrap(),
+            ),
+            (
+                125.0,
+                Network::new("files/LFCN-2352+_Plus125degC.s2p").unwrap(),
+            ),
+        ],
+    )
+    .unwrap();
+
+    let warm = sweep
+        .at(85.0, Interpolation::Linear, Extrapolation::Error)
+        .unwrap();
+
+    assert_eq!(warm.rank, 2);
+    assert_eq!(warm.f.len(), sweep.frequencies().len());
+}
+
+#[test]
+fn dc_and_band_extrapolation() {
+    let ntwk = Network::new("files/ntwk1.s2p").unwrap();
+    let step = 0.1e9;
+    let count = (ntwk.f[ntwk.f.len() - 1] / step).round() as usize;
+
+    let from_dc = ntwk
+        .resample(
+            (0..=count).map(|index| index as f64 * step),
+            Interpolation::MagnitudePhase,
+            Extrapolation::Dc,
+        )
+        .unwrap();
+    let extended = from_dc
+        .resample(
+            (0..=2 * count).map(|index| index as f64 * step),
+            Interpolation::Linear,
+            Extrapolation::Zero,
+        )
+        .unwrap();
+
+    assert_eq!(extended.f[0], 0.0);
+    assert_eq!(extended.try_s_ri_at(0, 2, 1).unwrap().im, 0.0);
+    assert_eq!(extended.try_s_ri_at(2 * count, 2, 1).unwrap().re, 0.0);
+}
+
+#[test]
+fn cascade_with_aligned_grids() {
+    let net1 = Network::new("files/ntwk1.s2p").unwrap();
+    let net2 = Network::new("files/ntwk2.s2p")
+        .unwrap()
+        .resample(
+            [2.0e9, 4.25e9, 6.0e9],
+            Interpolation::Linear,
+            Extrapolation::Error,
+        )
+        .unwrap();
+
+    let cascaded = net1
+        .cascade_aligned(&net2, FrequencyAlignment::Union(Interpolation::Linear))
+        .unwrap();
+    let (aligned1, aligned2) = net1
+        .align_frequencies(&net2, FrequencyAlignment::Intersection)
+        .unwrap();
+
+    assert_eq!(cascaded.f.len(), 42);
+    assert_eq!(aligned1.f, vec![2.0e9, 6.0e9]);
+    assert_eq!(aligned2.f, aligned1.f);
+}
+
+#[test]
+fn fallible_cascade() {
+    let net1 = Network::new("files/ntwk1.s2p").unwrap();
+    let three_port = Network::new("files/hfss_18.2.s3p").unwrap();
+
+    let error = net1.try_cascade(&three_port).unwrap_err();
+
+    assert!(matches!(
+        error,
+        TouchstoneError::InvalidNetworkRank { rank: 3 }
+    ));
+}
+
+#[test]
+fn transfer_parameters() {
+    let ntwk = Network::new("files/Agilent_E5071B.s4p").unwrap();
+    let t = ntwk.to_t(PortGrouping::Interleaved).unwrap();
+    let s = SMatrix::try_from_t_matrix(&t[0], PortGrouping::Interleaved).unwrap();
+    let rebuilt = Network::from_t(
+        "rebuilt.s4p",
+        &ntwk.f,
+        &t,
+        ntwk.z0,
+        PortGrouping::Interleaved,
+    )
+    .unwrap();
+
+    assert_eq!(s.rank, 4);
+    assert_eq!(rebuilt.f, ntwk.f);
+}
+
+#[test]
+fn series_and_parallel_connections() {
+    let amplifier = Network::new("files/ntwk1.s2p").unwrap();
+    let feedback = Network::new("files/ntwk2.s2p").unwrap();
+
+    let shunt_feedback = amplifier.connect_parallel(&feedback).unwrap();
+    let series_feedback = amplifier.connect_series(&feedback).unwrap();
+
+    assert_eq!(shunt_feedback.f, amplifier.f);
+    assert_eq!(series_feedback.rank, 2);
+}
+
+#[test]
+fn port_extension() {
+    let ntwk = Network::new("files/ntwk1.s2p").unwrap();
+    let fixture_loss = LossModel::SquareRootFrequency {
+        db: 0.2,
+        reference_frequency: 1.0e9,
+    };
+
+    let extended = ntwk.extend_port(2, 50.0e-12, LossModel::Lossless).unwrap();
+    let deembedded = ntwk
+        .remove_port_extension(1, 35.0e-12, fixture_loss)
+        .unwrap();
+
+    assert_eq!(extended.f, ntwk.f);
+    assert_eq!(
+        deembedded.try_s_ri_at(0, 2, 2).unwrap(),
+        ntwk.try_s_ri_at(0, 2, 2).unwrap()
+    );
+}
@@ -0,0 +1,297 @@
+use touchstone::{
+    Complex, Extrapolation, Interpolation, Network, NetworkBuilder, ParameterMatrix,
+    ReferenceImpedance, SMatrix, TouchstoneError, WaveDefinition,
+};
+
+mod common;
+
+use common::{assert_complex_close, assert_s_matrix_close, c, s_matrix};
+
+fn one_port_impedance(z: Complex) -> ParameterMatrix {
+    ParameterMatrix {
+        rank: 1,
+        data: vec![vec![z]],
+    }
+}
+
+fn two_port() -> SMatrix {
+    s_matrix(vec![
+        vec![c(0.12, -0.05), c(0.70, 0.20)],
+        vec![c(0.68, 0.25), c(-0.08, 0.11)],
+    ])
+}
+
+#[test]
+fn real_reference_matches_scalar_conversions_for_both_definitions() {
+    let s = two_port();
+    let reference = [c(50.0, 0.0), c(50.0, 0.0)];
+
+    for definition in [WaveDefinition::Power, WaveDefinition::Pseudo] {
+        let z = s
+            .to_z_matrix_with_reference(&reference, definition)
+            .unwrap();
+        let y = s
+            .to_y_matrix_with_reference(&reference, definition)
+            .unwrap();
+        let scalar_z = s.to_z_matrix(50.0).unwrap();
+        let scalar_y = s.to_y_matrix(50.0).unwrap();
+
+        for row in 1..=2 {
+            for column in 1..=2 {
+                assert_complex_close(
+                    z.get(row, column).unwrap(),
+                    scalar_z.get(row, column).unwrap(),
+                );
+                assert_complex_close(
+                    y.get(row, column).unwrap(),
+                    scalar_y.get(row, column).unwrap(),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn complex_reference_round_trips_through_z_and_y() {
+    let s = two_port();
+    let reference = [c(50.0, 15.0), c(25.0, -40.0)];
+
+    for definition in [WaveDefinition::Power, WaveDefinition::Pseudo] {
+        let z = s
+            .to_z_matrix_with_reference(&reference, definition)
+            .unwrap();
+        let y = s
+            .to_y_matrix_with_reference(&reference, definition)
+            .unwrap();
+
+        assert_s_matrix_close(
+            &SMatrix::try_from_z_matrix_with_reference(&z, &reference, definition).unwrap(),
+            &s,
+        );
+        assert_s_matrix_close(
+            &SMatrix::try_from_y_matrix_with_reference(&y, &reference, definition).unwrap(),
+            &s,
+        );
+    }
+}
+
+#[test]
+fn power_and_pseudo_waves_differ_for_complex_reference() {
+    let reference = [c(50.0, 25.0)];
+    let load = one_port_impedance(c(50.0, -25.0));
+
+    let power = SMatrix::try_from_z_matrix_with_reference(&load, &reference, WaveDefinition::Power)
+        .unwrap();
+    let pseudo =
+        SMatrix::try_from_z_matrix_with_reference(&load, &reference, WaveDefinition::Pseudo)
+            .unwrap();
+
+    // A conjugate-matched load has no reflected power wave.
+    assert_complex_close(power.get(1, 1).unwrap(), c(0.0, 0.0));
+    assert!(
+        pseudo
+            .get(1, 1)
+            .unwrap()
+            .re
+            .hypot(pseudo.get(1, 1).unwrap().im)
+            > 0.1
+    );
+}
+
+#[test]
+fn renormalize_handles_ideal_through_without_z_representation() {
+    let through = s_matrix(vec![
+        vec![c(0.0, 0.0), c(1.0, 0.0)],
+        vec![c(1.0, 0.0), c(0.0, 0.0)],
+    ]);
+    let from = [c(50.0, 0.0), c(50.0, 0.0)];
+    let to = [c(75.0, 0.0), c(50.0, 0.0)];
+
+    let renormalized = through
+        .renormalize(&from, &to, WaveDefinition::Power)
+        .unwrap();
+    let back = renormalized
+        .renormalize(&to, &from, WaveDefinition::Power)
+        .unwrap();
+
+    // Port 1 now sees the 50 ohm port 2 reference through the ideal through.
+    assert_complex_close(renormalized.get(1, 1).unwrap(), c(-0.2, 0.0));
+    assert_s_matrix_close(&back, &through);
+}
+
+#[test]
+fn network_renormalize_updates_reference_metadata_and_data() {
+    let network =
+        Network::from_str("load.s1p", "# GHz S RI R 50\n1.0 0.2 0.0\n2.0 0.2 0.0\n").unwrap();
+
+    let renormalized = network
+        .renormalize(ReferenceImpedance::Common(75.0), WaveDefinition::Power)
+        .unwrap();
+
+    assert_eq!(renormalized.z0, 75.0);
+    assert_eq!(
+        renormalized.reference_impedance(),
+        ReferenceImpedance::Common(75.0)
+    );
+    assert_complex_close(renormalized.try_s_ri_at(1, 1, 1).unwrap(), c(0.0, 0.0));
+    assert_eq!(renormalized.f, network.f);
+}
+
+#[test]
+fn frequency_dependent_reference_is_resolved_per_point() {
+    let network = NetworkBuilder::new("port.s1p", 1)
+        .point(1.0e9, s_matrix(vec![vec![c(0.0, 0.0)]]))
+        .point(2.0e9, s_matrix(vec![vec![c(0.0, 0.0)]]))
+        .build()
+        .unwrap();
+    let reference =
+        ReferenceImpedance::FrequencyDependent(vec![vec![c(40.0, 5.0)], vec![c(60.0, -5.0)]]);
+
+    let renormalized = network
+        .renormalize(reference.clone(), WaveDefinition::Pseudo)
+        .unwrap();
+
+    assert_eq!(renormalized.reference_impedance(), reference);
+    assert_eq!(
+        renormalized.reference_impedance_at(1).unwrap(),
+        vec![c(60.0, -5.0)]
+    );
+
+    // A 50 ohm matched load referenced to 60 - j5 ohms, converted back to Z, is still 50 ohms.
+    let z = renormalized
+        .z_matrix_at_with_definition(1, WaveDefinition::Pseudo)
+        .unwrap();
+    assert_complex_close(z.get(1, 1).unwrap(), c(50.0, 0.0));
+
+    let resampled = renormalized
+        .resample([1.5e9], Interpolation::Linear, Extrapolation::Error)
+        .unwrap();
+    assert_eq!(
+        resampled.reference_impedance(),
+        ReferenceImpedance::FrequencyDependent(vec![vec![c(50.0, 0.0)]])
+    );
+}
+
+#[test]
+fn frequency_dependent_reference_rejects_wrong_point_count() {
+    let network =
+        Network::from_str("load.s1p", "# GHz S RI R 50\n1.0 0.2 0.0\n2.0 0.2 0.0\n").unwrap();
+
+    let error = network
+        .renormalize(
+            ReferenceImpedance::FrequencyDependent(vec![vec![c(50.0, 0.0)]]),
+            WaveDefinition::Power,
+        )
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        TouchstoneError::ReferenceImpedanceFrequencyCountMismatch {
+            expected: 2,
+            actual: 1
+        }
+    ));
+}
+
+#[test]
+fn complex_reference_rejects_non_positive_real_part() {
+    let error = two_port()
+        .to_z_matrix_with_reference(&[c(50.0, 0.0), c(0.0, 50.0)], WaveDefinition::Power)
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        TouchstoneError::InvalidComplexReferenceImpedance { port: 2, .. }
+    ));
+}
+
+#[test]
+fn scalar_conversions_reject_complex_reference() {
+    let mut network = Network::from_str("load.s1p", "# GHz S RI R 50\n1.0 0.2 0.0\n").unwrap();
+    network.reference_impedance = ReferenceImpedance::PerPortComplex(vec![c(50.0, 10.0)]);
+
+    assert!(matches!(
+        network.z_matrix_at(0).unwrap_err(),
+        TouchstoneError::UnsupportedComplexReferenceImpedance
+    ));
+    assert!(network
+        .z_matrix_at_with_definition(0, WaveDefinition::Power)
+        .is_ok());
+}
+
+#[test]
+fn writer_rejects_reactive_reference_and_writes_real_per_port_values() {
+    let mut network = Network::from_str(
+        "pair.s2p",
+        "# GHz S RI R 50\n1.0 0.1 0.0 0.9 0.0 0.9 0.0 0.1 0.0\n",
+    )
+    .unwrap();
+
+    network.reference_impedance =
+        ReferenceImpedance::PerPortComplex(vec![c(50.0, 10.0), c(50.0, 0.0)]);
+    let error = network.to_touchstone_string().unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+
+    network.reference_impedance =
+        ReferenceImpedance::PerPortComplex(vec![c(45.0, 0.0), c(55.0, 0.0)]);
+    let serialized = network.to_touchstone_string().unwrap();
+    assert!(serialized.contains("[Reference] 45 55"));
+}
+
+#[test]
+fn empty_and_ragged_references_are_rejected() {
+    let mut network = Network::from_str(
+        "pair.s2p",
+        "# GHz S RI R 50\n1.0 0.1 0.0 0.9 0.0 0.9 0.0 0.1 0.0\n",
+    )
+    .unwrap();
+
+    assert!(matches!(
+        network
+            .renormalize(
+                ReferenceImpedance::PerPortComplex(vec![]),
+                WaveDefinition::Power
+            )
+            .unwrap_err(),
+        TouchstoneError::InvalidReferenceImpedanceCount {
+            ports: 2,
+            actual: 0
+        }
+    ));
+    assert!(matches!(
+        network
+            .renormalize(
+                ReferenceImpedance::FrequencyDependent(vec![vec![c(50.0, 0.0)]]),
+                WaveDefinition::Power
+            )
+            .unwrap_err(),
+        TouchstoneError::InvalidReferenceImpedanceCount {
+            ports: 2,
+            actual: 1
+        }
+    ));
+
+    network.reference_impedance = ReferenceImpedance::PerPortComplex(vec![]);
+    let error = network.to_touchstone_string().unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(matches!(
+        network.subnetwork(&[1]).unwrap_err(),
+        TouchstoneError::InvalidReferenceImpedanceCount {
+            ports: 2,
+            actual: 0
+        }
+    ));
+
+    network.reference_impedance = ReferenceImpedance::Common(50.0);
+    network.f.clear();
+    network.s.clear();
+    assert!(matches!(
+        network
+            .renormalize(
+                ReferenceImpedance::FrequencyDependent(vec![]),
+                WaveDefinition::Power
+            )
+            .unwrap_err(),
+        TouchstoneError::EmptyReferenceImpedance
+    ));
+}
@@ -0,0 +1,106 @@
+use touchstone::{Complex, Network, ReferenceImpedance, TouchstoneError};
+
+mod common;
+
+use common::assert_close;
+
+// Port 1: S11 = 0.5 (VSWR 3); port 2: S22 = j0.2.
+const MISMATCHED_TWO_PORT: &str = "\
+# GHz S RI R 50
+1.0 0.5 0.0 0.8 0.0 0.8 0.0 0.0 0.2
+2.0 0.0 0.0 1.0 0.0 1.0 0.0 -1.0 0.0
+";
+
+#[test]
+fn vswr_and_losses_match_closed_forms() {
+    let network = Network::from_str("dut.s2p", MISMATCHED_TWO_PORT).unwrap();
+
+    let vswr = network.vswr(1).unwrap();
+    let return_loss = network.return_loss(1).unwrap();
+    let mismatch_loss = network.mismatch_loss(2).unwrap();
+
+    assert_eq!(vswr[0].frequency, 1.0e9);
+    assert_close(vswr[0].vswr, 3.0);
+    assert_close(vswr[1].vswr, 1.0);
+    assert_close(return_loss[0].loss_db, 20.0 * 2.0_f64.log10());
+    assert_eq!(return_loss[1].loss_db, f64::INFINITY);
+    assert_close(mismatch_loss[0].loss_db, -10.0 * 0.96_f64.log10());
+    assert_eq!(mismatch_loss[1].loss_db, f64::INFINITY);
+}
+
+#[test]
+fn return_loss_is_negated_s_db() {
+    let network = Network::new("files/Agilent_E5071B.s4p").unwrap();
+
+    let return_loss = network.return_loss(3).unwrap();
+
+    assert_eq!(return_loss.len(), network.f.len());
+    for (loss, s_db) in return_loss.iter().zip(network.s_db(3, 3)) {
+        assert_eq!(loss.frequency, s_db.frequency);
+        assert_close(loss.loss_db, -s_db.s_db.decibel());
+    }
+}
+
+#[test]
+fn input_impedance_and_admittance_use_port_reference() {
+    let mut network = Network::from_str("dut.s2p", MISMATCHED_TWO_PORT).unwrap();
+
+    let z_in = network.input_impedance(1).unwrap();
+    assert_close(z_in[0].impedance.re, 150.0);
+    assert_close(z_in[0].impedance.im, 0.0);
+    assert_close(z_in[1].impedance.re, 50.0);
+
+    network.reference_impedance = ReferenceImpedance::PerPort(vec![50.0, 75.0]);
+    let z_in = network.input_impedance(2).unwrap();
+    let y_in = network.input_admittance(2).unwrap();
+
+    // Z = 75 (1 + j0.2) / (1 − j0.2)
+    let expected = Complex { re: 75.0, im: 0.0 } * Complex { re: 1.0, im: 0.2 }
+        / Complex { re: 1.0, im: -0.2 };
+    assert_close(z_in[0].impedance.re, expected.re);
+    assert_close(z_in[0].impedance.im, expected.im);
+    assert_close(
+        y_in[0].admittance.re,
+        (Complex { re: 1.0, im: 0.0 } / expected).re,
+    );
+    assert_close(
+        y_in[0].admittance.im,
+        (Complex { re: 1.0, im: 0.0 } / expected).im,
+    );
+    // S22 = −1 is a short circuit.
+    assert_close(z_in[1].impedance.re, 0.0);
+    assert_close(z_in[1].impedance.im, 0.0);
+}
+
+#[test]
+fn input_impedance_uses_power_waves_for_complex_reference() {
+    let mut network = Network::from_str("load.s1p", "# GHz S RI R 50\n1.0 0.0 0.0\n").unwrap();
+    let z0 = Complex { re: 50.0, im: 10.0 };
+    network.reference_impedance = ReferenceImpedance::PerPortComplex(vec![z0]);
+
+    let z_in = network.input_impedance(1).unwrap();
+
+    // A power-wave match is the conjugate of the reference impedance.
+    assert_close(z_in[0].impedance.re, 50.0);
+    assert_close(z_in[0].impedance.im, -10.0);
+}
+
+#[test]
+fn port_metrics_validate_port_and_parameter() {
+    let network = Network::from_str("dut.s2p", MISMATCHED_TWO_PORT).unwrap();
+    let admittance =
+        Network::from_str("uploaded.s1p", "# Hz Y RI R 50\n1000000000 0.02 0.0\n").unwrap();
+
+    assert!(matches!(
+        network.vswr(3).unwrap_err(),
+        TouchstoneError::InvalidPort { port: 3, rank: 2 }
+    ));
+    assert!(matches!(
+        network.input_impedance(0).unwrap_err(),
+        TouchstoneError::InvalidPort { port: 0, rank: 2 }
+    ));
+    assert!(matches!(
+        admittance.return_loss(1).unwrap_err(),
+        TouchstoneError::UnsupportedNetworkParameter { .. }
+    ));
+}
@@ -0,0 +1,143 @@
+use touchstone::{LimitKind, LimitLine, Network, Quantity, Spec, TouchstoneError};
+
+mod common;
+
+use common::assert_close;
+
+// |S21| = −1, −2, −1 dB and |S11| = −20, −10, −20 dB at 1, 1.5, 2 GHz.
+const FILTER: &str = "\
+# GHz S DB R 50
+1.0 -20.0 0.0 -1.0 -90.0 -1.0 -90.0 -20.0 0.0
+1.5 -10.0 0.0 -2.0 -135.0 -2.0 -135.0 -10.0 0.0
+2.0 -20.0 0.0 -1.0 -180.0 -1.0 -180.0 -20.0 0.0
+";
+
+#[test]
+fn report_gives_worst_margin_per_limit() {
+    let network = Network::from_str("filter.s2p", FILTER).unwrap();
+    let spec = Spec::new()
+        .limit(LimitLine::lower(2, 1, Quantity::Decibel, [(1.0e9, -1.5), (2.0e9, -1.5)]).unwrap())
+        .limit(LimitLine::upper(1, 1, Quantity::Decibel, [(0.8e9, -15.0), (2.2e9, -15.0)]).unwrap())
+        .limit(LimitLine::upper(2, 1, Quantity::Decibel, [(1.0e9, 0.0), (2.0e9, 0.0)]).unwrap());
+
+    let report = spec.evaluate(&network).unwrap();
+
+    assert_eq!(report.network_name, "filter.s2p");
+    assert_eq!(report.results.len(), 3);
+    let s21 = &report.results[0];
+    assert!(!s21.passed());
+    assert_close(s21.worst_margin, -0.5);
+    assert_eq!(s21.worst_frequency, 1.5e9);
+    assert_close(s21.worst_value, -2.0);
+    assert_eq!(s21.points_checked, 3);
+    assert_close(report.results[1].worst_margin, -5.0);
+    assert!(report.results[2].passed());
+    assert_close(report.results[2].worst_margin, 1.0);
+    assert!(!report.passed());
+    assert_eq!(report.worst().unwrap().limit, spec.limits[1]);
+}
+
+#[test]
+fn sloped_limits_interpolate_between_vertices() {
+    let network = Network::from_str("filter.s2p", FILTER).unwrap();
+    // Lower limit rising from −3 dB at 1 GHz to −1 dB at 2 GHz: −2 dB at 1.5 GHz.
+    let limit = LimitLine::lower(2, 1, Quantity::Decibel, [(1.0e9, -3.0), (2.0e9, -1.0)]).unwrap();
+
+    assert_eq!(limit.value_at(0.5e9), None);
+    assert_close(limit.value_at(1.5e9).unwrap(), -2.0);
+    assert_eq!(limit.value_at(2.0e9), Some(-1.0));
+
+    let report = Spec::new().limit(limit).evaluate(&network).unwrap();
+    assert!(report.passed());
+    assert_close(report.results[0].worst_margin, 0.0);
+}
+
+#[test]
+fn text_specification_matches_code() {
+    let text = "\
+! Production limits
+# GHz
+S21 dB > 1.0 -1.5 2.0 -1.5   ! passband insertion loss
+S11 dB upper 0.8 -15 2.2 -15
+
+# MHz
+S21 delay < 1000 1e-9 2000 1e-9
+S2,1 phase lower 1000 -360 2000 -360
+";
+
+    let spec = Spec::from_str("filter.spec", text).unwrap();
+
+    assert_eq!(
+        spec.limits,
+        vec![
+            LimitLine::lower(2, 1, Quantity::Decibel, [(1.0e9, -1.5), (2.0e9, -1.5)]).unwrap(),
+            LimitLine::upper(1, 1, Quantity::Decibel, [(0.8e9, -15.0), (2.2e9, -15.0)]).unwrap(),
+            LimitLine::upper(2, 1, Quantity::GroupDelay, [(1.0e9, 1e-9), (2.0e9, 1e-9)]).unwrap(),
+            LimitLine::new(
+                2,
+                1,
+                Quantity::Phase,
+                LimitKind::Lower,
+                [(1.0e9, -360.0), (2.0e9, -360.0)],
+            )
+            .unwrap(),
+        ]
+    );
+    let report = spec
+        .evaluate(&Network::from_str("filter.s2p", FILTER).unwrap())
+        .unwrap();
+    let lines = report.to_string();
+    assert!(lines.starts_with("FAIL filter.s2p"));
+    assert!(lines.contains("FAIL S21 dB > 1000000000 -1.5 2000000000 -1.5: worst margin"));
+}
+
+#[test]
+fn limits_roundtrip_through_display() {
+    let limit =
+        LimitLine::upper(12, 3, Quantity::Magnitude, [(1.0e9, 0.5), (2.0e9, 0.25)]).unwrap();
+
+    let spec = Spec::from_str("roundtrip.spec", &limit.to_string()).unwrap();
+
+    assert_eq!(spec.limits, vec![limit]);
+}
+
+#[test]
+fn malformed_specifications_report_line() {
+    for (text, line_number) in [
+        ("# GHz\nS21 dB > 1.0 -1.5 2.0\n", 2),
+        ("S21 loss > 1 -1 2 -1\n", 1),
+        ("! ok\nX21 dB > 1 -1 2 -1\n", 2),
+        ("S21 dB > 2 -1 1 -1\n", 1),
+        ("# furlongs\n", 1),
+        ("S21 dB > 1 abc 2 -1\n", 1),
+    ] {
+        let error = Spec::from_str("bad.spec", text).unwrap_err();
+
+        let context = error.context().unwrap();
+        assert_eq!(context.source_name, "bad.spec");
+        assert_eq!(context.line_number, Some(line_number), "{text}");
+    }
+}
+
+#[test]
+fn evaluation_requires_data_in_limit_span_and_valid_ports() {
+    let network = Network::from_str("filter.s2p", FILTER).unwrap();
+    let outside = Spec::new().limit(
+        LimitLine::upper(1, 1, Quantity::Decibel, [(3.0e9, -10.0), (4.0e9, -10.0)]).unwrap(),
+    );
+    let wrong_port = Spec::new()
+        .limit(LimitLine::upper(3, 1, Quantity::Decibel, [(1.0e9, 0.0), (2.0e9, 0.0)]).unwrap());
+
+    assert!(matches!(
+        outside.evaluate(&network).unwrap_err(),
+        TouchstoneError::InvalidFrequencyBand { start, stop } if start == 3.0e9 && stop == 4.0e9
+    ));
+    assert!(matches!(
+        wrong_port.evaluate(&network).unwrap_err(),
+        TouchstoneError::InvalidPortIndex { .. }
+    ));
+    assert!(matches!(
+        LimitLine::upper(1, 1, Quantity::Decibel, [(1.0e9, 0.0)]).unwrap_err(),
+        TouchstoneError::InvalidLimitLine { .. }
+    ));
+}
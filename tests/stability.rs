@@ -0,0 +1,92 @@
+use touchstone::{Network, TouchstoneError};
+
+mod common;
+
+use common::{assert_close, REAL_AMPLIFIER};
+
+#[test]
+fn stability_factors_match_hand_calculation() {
+    let network = Network::from_str("amp.s2p", REAL_AMPLIFIER).unwrap();
+
+    let factors = network.stability().unwrap();
+
+    assert_eq!(factors.len(), 1);
+    let point = factors[0];
+    // Δ = 0.5·0.4 − 0.1·2 = 0
+    assert_eq!(point.frequency, 1.0e9);
+    assert_close(point.delta, 0.0);
+    assert_close(point.k, (1.0 - 0.25 - 0.16) / (2.0 * 0.2));
+    assert_close(point.mu, (1.0 - 0.25) / (0.4 + 0.2));
+    assert_close(point.mu_prime, (1.0 - 0.16) / (0.5 + 0.2));
+    assert!(point.is_unconditionally_stable());
+}
+
+#[test]
+fn mu_agrees_with_k_delta_test_for_measured_devices() {
+    for path in ["files/fet.s2p", "files/ADL8100 De_Embedded_S_params.s2p"] {
+        let network = Network::new(path).unwrap();
+
+        let factors = network.stability().unwrap();
+
+        assert_eq!(factors.len(), network.f.len());
+        for point in factors {
+            assert_eq!(
+                point.mu > 1.0,
+                point.k > 1.0 && point.delta < 1.0,
+                "{path}: {point:?}"
+            );
+            assert_eq!(point.mu > 1.0, point.mu_prime > 1.0, "{path}: {point:?}");
+        }
+    }
+}
+
+#[test]
+fn potentially_unstable_frequencies_list_points_with_mu_at_most_one() {
+    let network = Network::new("files/fet.s2p").unwrap();
+
+    let unstable = network.potentially_unstable_frequencies().unwrap();
+
+    let expected: Vec<f64> = network
+        .stability()
+        .unwrap()
+        .into_iter()
+        .filter(|point| point.mu <= 1.0)
+        .map(|point| point.frequency)
+        .collect();
+    assert_eq!(unstable, expected);
+    assert!(!unstable.is_empty());
+}
+
+#[test]
+fn unilateral_network_reports_infinite_k() {
+    let network = Network::from_str(
+        "iso.s2p",
+        "# GHz S RI R 50\n1.0 0.5 0.0 2.0 0.0 0.0 0.0 0.4 0.0\n",
+    )
+    .unwrap();
+
+    let point = network.stability().unwrap()[0];
+
+    // Δ = 0.5·0.4 = 0.2, so |S22 − Δ·S11*| = 0.3.
+    assert_eq!(point.k, f64::INFINITY);
+    assert_close(point.mu, (1.0 - 0.25) / 0.3);
+    assert!(network
+        .potentially_unstable_frequencies()
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn stability_requires_two_port_network() {
+    let one_port = Network::new("files/hfss_oneport.s1p").unwrap();
+    let three_port = Network::new("files/hfss_18.2.s3p").unwrap();
+
+    assert!(matches!(
+        one_port.stability().unwrap_err(),
+        TouchstoneError::InvalidNetworkRank { rank: 1 }
+    ));
+    assert!(matches!(
+        three_port.potentially_unstable_frequencies().unwrap_err(),
+        TouchstoneError::InvalidNetworkRank { rank: 3 }
+    ));
+}
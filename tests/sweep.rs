@@ -0,0 +1,136 @@
+use touchstone::{Extrapolation, Interpolation, Network, ParameterSweep, TouchstoneError};
+
+mod common;
+
+use common::assert_close;
+
+fn one_port(name: &str, re: f64, im: f64) -> Network {
+    Network::from_str(
+        name,
+        &format!("# GHz S RI R 50\n1.0 {re} {im}\n2.0 {im} {re}\n"),
+    )
+    .unwrap()
+}
+
+fn bias_sweep() -> ParameterSweep {
+    ParameterSweep::new(
+        "bias",
+        vec![
+            (3.0, one_port("high.s1p", 0.6, 0.2)),
+            (1.0, one_port("low.s1p", 0.2, 0.0)),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn conditions_are_sorted_by_value() {
+    let sweep = bias_sweep();
+
+    assert_eq!(sweep.parameter(), "bias");
+    assert_eq!(sweep.values(), &[1.0, 3.0]);
+    assert_eq!(sweep.networks()[0].name, "low.s1p");
+    assert_eq!(sweep.frequencies(), &[1.0e9, 2.0e9]);
+}
+
+#[test]
+fn linear_interpolates_each_frequency_point() {
+    let sweep = bias_sweep();
+
+    let network = sweep
+        .at(1.5, Interpolation::Linear, Extrapolation::Error)
+        .unwrap();
+
+    assert_eq!(network.f, vec![1.0e9, 2.0e9]);
+    assert_close(network.try_s_ri_at(0, 1, 1).unwrap().re, 0.3);
+    assert_close(network.try_s_ri_at(0, 1, 1).unwrap().im, 0.05);
+    assert_close(network.try_s_ri_at(1, 1, 1).unwrap().re, 0.05);
+    assert_close(network.try_s_ri_at(1, 1, 1).unwrap().im, 0.3);
+    assert_eq!(network.comments.last().unwrap(), "! bias = 1.5");
+}
+
+#[test]
+fn nearest_and_clamp_select_whole_conditions() {
+    let sweep = bias_sweep();
+
+    let nearest = sweep
+        .at(2.0, Interpolation::Nearest, Extrapolation::Error)
+        .unwrap();
+    assert_close(nearest.try_s_ri_at(0, 1, 1).unwrap().re, 0.2);
+
+    let clamped = sweep
+        .at(5.0, Interpolation::Linear, Extrapolation::Clamp)
+        .unwrap();
+    assert_close(clamped.try_s_ri_at(0, 1, 1).unwrap().re, 0.6);
+
+    assert!(matches!(
+        sweep.at(0.5, Interpolation::Linear, Extrapolation::Error),
+        Err(TouchstoneError::ParameterOutOfRange { min, max, .. }) if min == 1.0 && max == 3.0
+    ));
+}
+
+#[test]
+fn new_rejects_duplicate_values_and_incompatible_networks() {
+    assert!(matches!(
+        ParameterSweep::new(
+            "bias",
+            vec![
+                (1.0, one_port("a.s1p", 0.1, 0.0)),
+                (1.0, one_port("b.s1p", 0.2, 0.0)),
+            ],
+        ),
+        Err(TouchstoneError::InvalidSweepValue { value, .. }) if value == 1.0
+    ));
+    assert!(matches!(
+        ParameterSweep::new(
+            "temperature",
+            vec![
+                (25.0, Network::new("files/ntwk1.s2p").unwrap()),
+                (125.0, one_port("b.s1p", 0.2, 0.0)),
+            ],
+        ),
+        Err(TouchstoneError::IncompatibleNetwork { .. })
+    ));
+}
+
+#[test]
+fn temperature_sweep_interpolates_between_measured_files() {
+    let cold = Network::new("files/LFCN-2352+_Plus25degC.s2p").unwrap();
+    let hot = Network::new("files/LFCN-2352+_Plus125degC.s2p").unwrap();
+    let sweep = ParameterSweep::new(
+        "temperature",
+        vec![(25.0, cold.clone()), (125.0, hot.clone())],
+    )
+    .unwrap();
+
+    let warm = sweep
+        .at(75.0, Interpolation::Linear, Extrapolation::Error)
+        .unwrap();
+
+    let last = cold.f.len() - 1;
+    let expected =
+        (cold.try_s_ri_at(last, 2, 1).unwrap() + hot.try_s_ri_at(last, 2, 1).unwrap()) / 2.0;
+    let actual = warm.try_s_ri_at(last, 2, 1).unwrap();
+    assert_close(actual.re, expected.re);
+    assert_close(actual.im, expected.im);
+}
+
+#[test]
+fn linear_and_fill_extrapolation_follow_the_parameter_axis() {
+    let sweep = bias_sweep();
+
+    let linear = sweep
+        .at(4.0, Interpolation::Linear, Extrapolation::Linear)
+        .unwrap();
+    let zero = sweep
+        .at(0.0, Interpolation::Linear, Extrapolation::Zero)
+        .unwrap();
+
+    assert_close(linear.try_s_ri_at(0, 1, 1).unwrap().re, 0.8);
+    assert_close(linear.try_s_ri_at(0, 1, 1).unwrap().im, 0.3);
+    assert_close(zero.try_s_ri_at(1, 1, 1).unwrap().re, 0.0);
+    assert!(matches!(
+        sweep.at(4.0, Interpolation::Linear, Extrapolation::Dc),
+        Err(TouchstoneError::ParameterOutOfRange { .. })
+    ));
+}
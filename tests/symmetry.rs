@@ -0,0 +1,142 @@
+use touchstone::{Network, TouchstoneError};
+
+mod common;
+
+use common::{assert_close, assert_complex_close};
+
+// S21 = 0.9∠−30°, S12 = 0.7∠−30°, S11 = 0.1, S22 = 0.3: neither reciprocal nor symmetric.
+const ASYMMETRIC_TWO_PORT: &str = "\
+# GHz S MA R 50
+1.0 0.1 0.0 0.9 -30.0 0.7 -30.0 0.3 0.0
+2.0 0.2 45.0 0.8 90.0 0.8 90.0 0.2 45.0
+";
+
+#[test]
+fn reciprocity_error_is_largest_transmission_difference() {
+    let network = Network::from_str("dut.s2p", ASYMMETRIC_TWO_PORT).unwrap();
+
+    let errors = network.reciprocity_error().unwrap();
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].frequency, 1.0e9);
+    assert_close(errors[0].deviation, 0.2);
+    assert_close(errors[1].deviation, 0.0);
+    assert!(network.is_reciprocal(0.2 + 1.0e-9).unwrap());
+    assert!(!network.is_reciprocal(0.1).unwrap());
+}
+
+#[test]
+fn symmetry_error_compares_mirrored_ports() {
+    let network = Network::from_str("dut.s2p", ASYMMETRIC_TWO_PORT).unwrap();
+
+    let errors = network.symmetry_error().unwrap();
+
+    assert_close(errors[0].deviation, 0.2);
+    assert_close(errors[1].deviation, 0.0);
+    assert!(!network.is_symmetric(0.1).unwrap());
+}
+
+#[test]
+fn make_reciprocal_averages_transmission_terms() {
+    let network = Network::from_str("dut.s2p", ASYMMETRIC_TWO_PORT).unwrap();
+
+    let reciprocal = network.make_reciprocal().unwrap();
+
+    let average =
+        (network.try_s_ri_at(0, 2, 1).unwrap() + network.try_s_ri_at(0, 1, 2).unwrap()) / 2.0;
+    assert_complex_close(reciprocal.try_s_ri_at(0, 2, 1).unwrap(), average);
+    assert_complex_close(reciprocal.try_s_ri_at(0, 1, 2).unwrap(), average);
+    assert_complex_close(
+        reciprocal.try_s_ri_at(0, 2, 2).unwrap(),
+        network.try_s_ri_at(0, 2, 2).unwrap(),
+    );
+    assert!(reciprocal.is_reciprocal(0.0).unwrap());
+    assert_eq!(reciprocal.f, network.f);
+    assert!(reciprocal
+        .comments
+        .last()
+        .unwrap()
+        .starts_with("! Made reciprocal"));
+}
+
+#[test]
+fn make_symmetric_averages_mirrored_and_transposed_terms() {
+    let network = Network::from_str("dut.s2p", ASYMMETRIC_TWO_PORT).unwrap();
+
+    let symmetric = network.make_symmetric().unwrap();
+
+    let reflection =
+        (network.try_s_ri_at(0, 1, 1).unwrap() + network.try_s_ri_at(0, 2, 2).unwrap()) / 2.0;
+    let transmission =
+        (network.try_s_ri_at(0, 2, 1).unwrap() + network.try_s_ri_at(0, 1, 2).unwrap()) / 2.0;
+    assert_complex_close(symmetric.try_s_ri_at(0, 1, 1).unwrap(), reflection);
+    assert_complex_close(symmetric.try_s_ri_at(0, 2, 2).unwrap(), reflection);
+    assert_complex_close(symmetric.try_s_ri_at(0, 2, 1).unwrap(), transmission);
+    assert_complex_close(symmetric.try_s_ri_at(0, 1, 2).unwrap(), transmission);
+    assert!(!network.is_symmetric(0.1).unwrap());
+    assert!(symmetric.is_symmetric(1.0e-12).unwrap());
+    assert!(symmetric.is_reciprocal(1.0e-12).unwrap());
+    assert!(symmetric
+        .comments
+        .last()
+        .unwrap()
+        .starts_with("! Made symmetric"));
+}
+
+#[test]
+fn make_symmetric_handles_multiports() {
+    let network = Network::new("files/Agilent_E5071B.s4p").unwrap();
+
+    let symmetric = network.make_symmetric().unwrap();
+
+    let expected = (network.try_s_ri_at(0, 2, 1).unwrap()
+        + network.try_s_ri_at(0, 1, 2).unwrap()
+        + network.try_s_ri_at(0, 3, 4).unwrap()
+        + network.try_s_ri_at(0, 4, 3).unwrap())
+        / 4.0;
+    assert_complex_close(symmetric.try_s_ri_at(0, 2, 1).unwrap(), expected);
+    assert_complex_close(symmetric.try_s_ri_at(0, 4, 3).unwrap(), expected);
+    assert!(symmetric.is_symmetric(1.0e-12).unwrap());
+    assert!(symmetric.is_reciprocal(1.0e-12).unwrap());
+}
+
+#[test]
+fn measured_multiports_are_nearly_reciprocal() {
+    for path in ["files/Agilent_E5071B.s4p", "files/hfss_18.2.s3p"] {
+        let network = Network::new(path).unwrap();
+
+        let errors = network.reciprocity_error().unwrap();
+        let reciprocal = network.make_reciprocal().unwrap();
+
+        assert_eq!(errors.len(), network.f.len(), "{path}");
+        assert!(network.is_reciprocal(1.0e-2).unwrap(), "{path}");
+        assert!(reciprocal.is_reciprocal(0.0).unwrap(), "{path}");
+    }
+}
+
+#[test]
+fn one_port_is_reciprocal_and_symmetric() {
+    let network = Network::new("files/hfss_oneport.s1p").unwrap();
+
+    assert!(network.is_reciprocal(0.0).unwrap());
+    assert!(network.is_symmetric(0.0).unwrap());
+}
+
+#[test]
+fn reciprocity_requires_s_parameter_data() {
+    let network =
+        Network::from_str("uploaded.s1p", "# Hz Y RI R 50\n1000000000 0.02 0.0\n").unwrap();
+
+    assert!(matches!(
+        network.reciprocity_error().unwrap_err(),
+        TouchstoneError::UnsupportedNetworkParameter { .. }
+    ));
+    assert!(matches!(
+        network.make_reciprocal().unwrap_err(),
+        TouchstoneError::UnsupportedNetworkParameter { .. }
+    ));
+    assert!(matches!(
+        network.make_symmetric().unwrap_err(),
+        TouchstoneError::UnsupportedNetworkParameter { .. }
+    ));
+}
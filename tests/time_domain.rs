@@ -0,0 +1,298 @@
+use touchstone::{
+    Complex, DcExtrapolation, GateMode, Network, NetworkBuilder, SMatrix, TimeDomainMode,
+    TimeDomainOptions, TimeGate, TimePoint, TouchstoneError, Window,
+};
+
+mod common;
+
+use common::{delay_line, DELAY_S};
+
+/// 1-port with a frequency-flat reflection coefficient from 0.1 to 10 GHz.
+fn flat_load(gamma: f64) -> Network {
+    let mut builder = NetworkBuilder::new("load.s1p", 1);
+    for index in 1..=100 {
+        builder.push_point(
+            index as f64 * 1.0e8,
+            SMatrix {
+                rank: 1,
+                data: vec![vec![Complex { re: gamma, im: 0.0 }]],
+            },
+        );
+    }
+    builder.build().unwrap()
+}
+
+fn delayed(amplitude: f64, frequency: f64, delay_s: f64) -> Complex {
+    let angle = -2.0 * std::f64::consts::PI * frequency * delay_s;
+    Complex {
+        re: amplitude * angle.cos(),
+        im: amplitude * angle.sin(),
+    }
+}
+
+/// 1-port with a 0.3 connector reflection at 0.5 ns and a 0.1 DUT reflection at 3 ns.
+fn two_reflections(frequencies: impl IntoIterator<Item = f64>) -> Network {
+    let mut builder = NetworkBuilder::new("echo.s1p", 1);
+    for frequency in frequencies {
+        let connector = delayed(0.3, frequency, 0.5e-9);
+        let dut = delayed(0.1, frequency, 3.0e-9);
+        builder.push_point(
+            frequency,
+            SMatrix {
+                rank: 1,
+                data: vec![vec![connector + dut]],
+            },
+        );
+    }
+    builder.build().unwrap()
+}
+
+fn assert_mid_band_close(gated: &Network, expected: impl Fn(f64) -> Complex) {
+    let count = gated.f.len();
+    for point_index in count / 4..3 * count / 4 {
+        let actual = gated.try_s_ri_at(point_index, 1, 1).unwrap();
+        let expected = expected(gated.f[point_index]);
+        let error = (actual.re - expected.re).hypot(actual.im - expected.im);
+        assert!(
+            error < 2.0e-3,
+            "point {point_index}: {actual:?} vs {expected:?}"
+        );
+    }
+}
+
+fn peak(points: &[TimePoint]) -> TimePoint {
+    *points
+        .iter()
+        .max_by(|a, b| a.value.abs().total_cmp(&b.value.abs()))
+        .unwrap()
+}
+
+#[test]
+fn low_pass_impulse_peaks_at_line_delay_with_unit_height() {
+    let network = delay_line();
+
+    for window in [
+        Window::Rectangular,
+        Window::Hann,
+        Window::Kaiser { beta: 6.0 },
+    ] {
+        let options = TimeDomainOptions::new()
+            .window(window)
+            .time_span(0.0, 2.0 * DELAY_S)
+            .points(201);
+        let impulse = network.impulse_response(2, 1, &options).unwrap();
+
+        let peak = peak(&impulse);
+        assert!(
+            (peak.time - DELAY_S).abs() < 1.0e-15,
+            "{window:?}: {peak:?}"
+        );
+        assert!((peak.value - 1.0).abs() < 1.0e-9, "{window:?}: {peak:?}");
+    }
+}
+
+#[test]
+fn band_pass_impulse_returns_envelope_at_line_delay() {
+    let network = delay_line();
+    let options = TimeDomainOptions::new()
+        .mode(TimeDomainMode::BandPass)
+        .time_span(0.0, 2.0 * DELAY_S)
+        .points(201);
+
+    let impulse = network.impulse_response(2, 1, &options).unwrap();
+
+    let peak = peak(&impulse);
+    assert!((peak.time - DELAY_S).abs() < 1.0e-15);
+    assert!((peak.value - 1.0).abs() < 1.0e-9);
+    assert!(impulse.iter().all(|point| point.value >= 0.0));
+}
+
+#[test]
+fn step_response_settles_at_dc_value_after_delay() {
+    let network = delay_line();
+    let options = TimeDomainOptions::new()
+        .time_span(-2.0e-9, 4.0e-9)
+        .points(601);
+
+    let step = network.step_response(2, 1, &options).unwrap();
+
+    let before = step.iter().find(|point| point.time >= 0.0).unwrap();
+    let after = step.iter().find(|point| point.time >= 3.0e-9).unwrap();
+    assert!(before.value.abs() < 1.0e-3, "{before:?}");
+    assert!((after.value - 1.0).abs() < 1.0e-3, "{after:?}");
+}
+
+#[test]
+fn tdr_impedance_of_flat_reflection_uses_reference_impedance() {
+    let load = flat_load(0.2);
+    let options = TimeDomainOptions::new()
+        .time_span(1.0e-9, 4.0e-9)
+        .points(31);
+
+    let profile = load.tdr_impedance(1, &options).unwrap();
+
+    for point in profile {
+        assert!((point.value - 75.0).abs() < 0.01, "{point:?}");
+    }
+}
+
+#[test]
+fn tdr_impedance_of_open_and_short_stays_in_range() {
+    let options = TimeDomainOptions::new()
+        .time_span(1.0e-9, 4.0e-9)
+        .points(31);
+
+    let open = flat_load(1.0).tdr_impedance(1, &options).unwrap();
+    let short = flat_load(-1.0).tdr_impedance(1, &options).unwrap();
+
+    for point in open {
+        assert!(point.value > 1.0e3, "{point:?}");
+    }
+    for point in short {
+        assert!((0.0..1.0).contains(&point.value), "{point:?}");
+    }
+}
+
+#[test]
+fn dc_value_override_sets_step_final_value() {
+    let load = flat_load(0.2);
+    let options = TimeDomainOptions::new()
+        .dc_extrapolation(DcExtrapolation::Value(-1.0))
+        .time_span(4.0e-9, 4.0e-9 + 1.0e-12)
+        .points(2);
+
+    let linear = load
+        .step_response(1, 1, &options.dc_extrapolation(DcExtrapolation::Linear))
+        .unwrap();
+    let short = load.step_response(1, 1, &options).unwrap();
+
+    assert!((linear[0].value - 0.2).abs() < 1.0e-3);
+    assert!(short[0].value < linear[0].value);
+}
+
+#[test]
+fn default_time_axis_spans_one_alias_free_period() {
+    let network = delay_line();
+
+    let impulse = network
+        .impulse_response(1, 1, &TimeDomainOptions::new())
+        .unwrap();
+
+    // 100 points up to 10 GHz give a 0.1 GHz harmonic spacing and a 10 ns period.
+    assert_eq!(impulse.len(), 400);
+    assert!((impulse[0].time + 5.0e-9).abs() < 1.0e-18);
+    assert!((impulse[1].time - impulse[0].time - 25.0e-12).abs() < 1.0e-18);
+    assert!(impulse.iter().all(|point| point.value.abs() < 1.0e-12));
+}
+
+#[test]
+fn time_domain_errors_are_structured() {
+    let network = delay_line();
+    let band_pass = TimeDomainOptions::new().mode(TimeDomainMode::BandPass);
+
+    assert!(matches!(
+        network.step_response(2, 1, &band_pass).unwrap_err(),
+        TouchstoneError::UnsupportedTimeDomainMode { .. }
+    ));
+    assert!(matches!(
+        network.tdr_impedance(1, &band_pass).unwrap_err(),
+        TouchstoneError::UnsupportedTimeDomainMode { .. }
+    ));
+    assert!(matches!(
+        network
+            .impulse_response(
+                1,
+                1,
+                &TimeDomainOptions::new().window(Window::Kaiser { beta: -1.0 })
+            )
+            .unwrap_err(),
+        TouchstoneError::InvalidTimeDomainSetting { .. }
+    ));
+    assert!(matches!(
+        network
+            .impulse_response(1, 1, &TimeDomainOptions::new().time_span(1.0, 0.0))
+            .unwrap_err(),
+        TouchstoneError::InvalidTimeDomainSetting { .. }
+    ));
+    assert!(matches!(
+        network
+            .impulse_response(3, 1, &TimeDomainOptions::new())
+            .unwrap_err(),
+        TouchstoneError::InvalidPortIndex { .. }
+    ));
+
+    let single = Network::from_str("one.s1p", "# GHz S RI R 50\n1.0 0.2 0.0\n").unwrap();
+    assert!(matches!(
+        single
+            .impulse_response(1, 1, &TimeDomainOptions::new())
+            .unwrap_err(),
+        TouchstoneError::InsufficientFrequencyPoints {
+            required: 2,
+            actual: 1,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn band_pass_gate_keeps_only_the_gated_reflection() {
+    let network = two_reflections((0..=180).map(|index| 1.0e9 + index as f64 * 5.0e7));
+
+    let gated = network
+        .time_gate(&TimeGate::new(3.0e-9, 1.5e-9).shape(Window::Rectangular))
+        .unwrap();
+
+    assert_eq!(gated.f, network.f);
+    assert_eq!(gated.rank, 1);
+    assert_mid_band_close(&gated, |frequency| delayed(0.1, frequency, 3.0e-9));
+    assert!(gated.comments.last().unwrap().starts_with("! Time gated"));
+}
+
+#[test]
+fn notch_gate_removes_connector_reflection() {
+    let network = two_reflections((0..=180).map(|index| 1.0e9 + index as f64 * 5.0e7));
+
+    let gated = network
+        .time_gate(
+            &TimeGate::new(0.5e-9, 1.5e-9)
+                .mode(GateMode::Notch)
+                .shape(Window::Rectangular),
+        )
+        .unwrap();
+
+    assert_mid_band_close(&gated, |frequency| delayed(0.1, frequency, 3.0e-9));
+}
+
+#[test]
+fn gate_spanning_every_response_leaves_data_unchanged() {
+    // Non-uniform grid: gated data are mapped back onto the original frequencies.
+    let network = two_reflections([1.0e9, 1.1e9, 1.3e9, 1.6e9, 2.0e9, 2.5e9, 3.1e9, 3.8e9]);
+
+    let gated = network
+        .time_gate(&TimeGate::new(0.0, 1.0).shape(Window::Rectangular))
+        .unwrap();
+
+    assert_eq!(gated.f, network.f);
+    for point_index in 0..network.f.len() {
+        let actual = gated.try_s_ri_at(point_index, 1, 1).unwrap();
+        let expected = network.try_s_ri_at(point_index, 1, 1).unwrap();
+        assert!((actual.re - expected.re).abs() < 1.0e-12, "{actual:?}");
+        assert!((actual.im - expected.im).abs() < 1.0e-12, "{actual:?}");
+    }
+}
+
+#[test]
+fn time_gate_rejects_invalid_settings() {
+    let network = two_reflections([1.0e9, 2.0e9, 3.0e9]);
+
+    assert!(matches!(
+        network.time_gate(&TimeGate::new(1.0e-9, 0.0)).unwrap_err(),
+        TouchstoneError::InvalidTimeDomainSetting { .. }
+    ));
+    assert!(matches!(
+        network
+            .time_gate(&TimeGate::new(f64::NAN, 1.0e-9))
+            .unwrap_err(),
+        TouchstoneError::InvalidTimeDomainSetting { .. }
+    ));
+}
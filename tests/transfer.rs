@@ -0,0 +1,129 @@
+use touchstone::{Complex, Network, PortGrouping, SMatrix, TMatrix, TouchstoneError};
+
+mod common;
+
+use common::{assert_complex_close, assert_complex_close_within};
+
+fn multiply(left: &TMatrix, right: &TMatrix) -> TMatrix {
+    let rank = left.rank;
+    let data = (0..rank)
+        .map(|row| {
+            (0..rank)
+                .map(|column| {
+                    (0..rank).fold(Complex { re: 0.0, im: 0.0 }, |sum, inner| {
+                        sum + left.data[row][inner] * right.data[inner][column]
+                    })
+                })
+                .collect()
+        })
+        .collect();
+    TMatrix { rank, data }
+}
+
+#[test]
+fn t_matrix_product_matches_cascade() {
+    let net1 = Network::new("files/ntwk1.s2p").unwrap();
+    let net2 = Network::new("files/ntwk2.s2p").unwrap();
+
+    let t1 = net1.to_t(PortGrouping::Sequential).unwrap();
+    let t2 = net2.to_t(PortGrouping::Sequential).unwrap();
+    let chained = t1
+        .iter()
+        .zip(&t2)
+        .map(|(first, second)| multiply(first, second))
+        .collect::<Vec<_>>();
+    let from_t = Network::from_t(
+        "chained.s2p",
+        &net1.f,
+        &chained,
+        net1.z0,
+        PortGrouping::Sequential,
+    )
+    .unwrap();
+    let cascaded = net1.cascade(&net2);
+
+    assert_eq!(from_t.f, cascaded.f);
+    for point_index in [0, cascaded.f.len() / 2, cascaded.f.len() - 1] {
+        for (to_port, from_port) in [(1, 1), (1, 2), (2, 1), (2, 2)] {
+            assert_complex_close(
+                from_t.try_s_ri_at(point_index, to_port, from_port).unwrap(),
+                cascaded
+                    .try_s_ri_at(point_index, to_port, from_port)
+                    .unwrap(),
+            );
+        }
+    }
+}
+
+#[test]
+fn four_port_groupings_round_trip() {
+    let network = Network::new("files/Agilent_E5071B.s4p").unwrap();
+    let s = network.s_matrix_at(0).unwrap();
+
+    for grouping in [PortGrouping::Sequential, PortGrouping::Interleaved] {
+        let t = s.to_t_matrix(grouping).unwrap();
+        let round_trip = SMatrix::try_from_t_matrix(&t, grouping).unwrap();
+        for to_port in 1..=4 {
+            for from_port in 1..=4 {
+                // The round trip inverts an ill-conditioned transmission block.
+                assert_complex_close_within(
+                    round_trip.get(to_port, from_port).unwrap(),
+                    s.get(to_port, from_port).unwrap(),
+                    1.0e-9,
+                );
+            }
+        }
+    }
+
+    // Interleaved grouping is sequential grouping with ports 2 and 3 swapped.
+    let interleaved = s.to_t_matrix(PortGrouping::Interleaved).unwrap();
+    let swapped = network
+        .renumber(&[2, 3], &[3, 2])
+        .unwrap()
+        .s_matrix_at(0)
+        .unwrap()
+        .to_t_matrix(PortGrouping::Sequential)
+        .unwrap();
+    for row in 1..=4 {
+        for column in 1..=4 {
+            assert_complex_close(
+                interleaved.get(row, column).unwrap(),
+                swapped.get(row, column).unwrap(),
+            );
+        }
+    }
+}
+
+#[test]
+fn odd_ranks_and_missing_transmission_are_rejected() {
+    let one_port = Network::new("files/hfss_oneport.s1p").unwrap();
+    assert!(matches!(
+        one_port.to_t(PortGrouping::Sequential),
+        Err(TouchstoneError::InvalidNetworkRank { rank: 1 })
+    ));
+
+    let zero = Complex { re: 0.0, im: 0.0 };
+    let open = SMatrix {
+        rank: 2,
+        data: vec![vec![Complex { re: 1.0, im: 0.0 }, zero], vec![zero, zero]],
+    };
+    assert!(matches!(
+        open.to_t_matrix(PortGrouping::Sequential),
+        Err(TouchstoneError::SingularMatrix { .. })
+    ));
+}
+
+#[test]
+fn invalid_inputs_are_rejected() {
+    let impedance =
+        Network::from_str("resistor.s2p", "# GHz Z RI R 50\n1.0 50 0 0 0 0 0 50 0\n").unwrap();
+    assert!(matches!(
+        impedance.to_t(PortGrouping::Sequential),
+        Err(TouchstoneError::UnsupportedNetworkParameter { parameter }) if parameter == "Z"
+    ));
+
+    assert!(matches!(
+        Network::from_t("empty.s2p", &[], &[], 50.0, PortGrouping::Sequential),
+        Err(TouchstoneError::EmptyNetworkData)
+    ));
+}